@@ -1,4 +1,5 @@
 pub const MAX_VAULT_ROLES: u16 = u16::MAX;
+pub const VAULT_LAYOUT_VERSION: u8 = 1;
 
 /// Seed prefixes for various PDAs used in the program
 pub const SEED_PREFIX: &[u8] = b"vaultx";
@@ -7,4 +8,25 @@ pub const SEED_VAULT_CONFIG: &[u8] = b"vault_config";
 pub const SEED_FOUNDER_TRANSACTION: &[u8] = b"founder_transaction";
 pub const SEED_MEMBER_TRANSACTION: &[u8] = b"member_transaction";
 pub const SEED_FUND: &[u8] = b"fund";
+pub const SEED_FOUNDER_AUTHORITY: &[u8] = b"founder_authority";
 pub const SEED_EPHEMERAL_SIGNER: &[u8] = b"ephemeral_signer";
+pub const SEED_VAULT_METADATA: &[u8] = b"vault_metadata";
+pub const SEED_COMMITTEE: &[u8] = b"committee";
+pub const SEED_COMMITTEE_TRANSACTION: &[u8] = b"committee_transaction";
+pub const SEED_INVITATION: &[u8] = b"invitation";
+pub const SEED_RECOVERY: &[u8] = b"recovery";
+pub const SEED_VETO_COUNCIL: &[u8] = b"veto_council";
+pub const SEED_MEMBER_RATE_LIMIT: &[u8] = b"member_rate_limit";
+pub const SEED_BATCH: &[u8] = b"batch";
+pub const SEED_BATCH_TRANSACTION: &[u8] = b"batch_transaction";
+pub const SEED_TRANSACTION_BUFFER: &[u8] = b"transaction_buffer";
+
+/// Maximum size of a transaction message uploaded through a buffer
+pub const MAX_TRANSACTION_BUFFER_SIZE: usize = 4000;
+
+/// Length limits for the vault metadata fields
+pub const MAX_METADATA_NAME_LENGTH: usize = 64;
+pub const MAX_METADATA_DESCRIPTION_LENGTH: usize = 512;
+pub const MAX_METADATA_URI_LENGTH: usize = 200;
+pub const MAX_METADATA_TAGS: usize = 16;
+pub const MAX_METADATA_TAG_LENGTH: usize = 32;
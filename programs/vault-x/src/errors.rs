@@ -47,4 +47,72 @@ pub enum VaultError {
     AdminCannotBeFounder,
     #[msg("Administrator cannot be a member")]
     AdminCannotBeMember,
+    #[msg("Vault metadata exceeds the allowed length")]
+    InvalidMetadata,
+    #[msg("Invalid committee threshold")]
+    InvalidCommitteeThreshold,
+    #[msg("Committee member does not exist")]
+    CommitteeMemberNotFound,
+    #[msg("Invalid founder vote weight")]
+    InvalidFounderWeight,
+    #[msg("Time lock has not elapsed since the transaction was approved")]
+    TimeLockNotElapsed,
+    #[msg("Transaction expiry is in the past or exceeds the vault's maximum")]
+    InvalidTransactionExpiry,
+    #[msg("Invitation has expired")]
+    InvitationExpired,
+    #[msg("Invalid guardian threshold")]
+    InvalidGuardianThreshold,
+    #[msg("Invalid inactivity period")]
+    InvalidInactivityPeriod,
+    #[msg("Guardian does not exist")]
+    GuardianNotFound,
+    #[msg("Vault founders have not been inactive for long enough")]
+    FoundersStillActive,
+    #[msg("A recovery is already pending")]
+    RecoveryAlreadyProposed,
+    #[msg("No recovery is pending")]
+    RecoveryNotProposed,
+    #[msg("Guardian has already approved the recovery")]
+    RecoveryAlreadyApproved,
+    #[msg("Recovery has not reached the guardian threshold")]
+    RecoveryNotApproved,
+    #[msg("Time lock has elapsed, the transaction can no longer be vetoed")]
+    VetoWindowElapsed,
+    #[msg("Not enough guardians signed the veto")]
+    VetoThresholdNotReached,
+    #[msg("Transactions replacing the veto council can't be vetoed")]
+    VetoCouncilReplacement,
+    #[msg("Invalid member threshold")]
+    InvalidMemberThreshold,
+    #[msg("Member voting is disabled for this vault")]
+    MemberVotingDisabled,
+    #[msg("Cooldown has not elapsed since the transaction was last executed")]
+    CooldownNotElapsed,
+    #[msg("Member voting is enabled, member transactions must be approved")]
+    MemberVotingEnabled,
+    #[msg("Invalid rate limit")]
+    InvalidRateLimit,
+    #[msg("Member has reached its transaction rate limit")]
+    RateLimitExceeded,
+    #[msg("Batch has no transactions")]
+    EmptyBatch,
+    #[msg("Invalid transaction buffer size")]
+    InvalidTransactionBufferSize,
+    #[msg("Transaction buffer does not match its hash")]
+    InvalidTransactionBufferHash,
+    #[msg("Address lookup table does not match the transaction message")]
+    InvalidAddressLookupTable,
+    #[msg("Invalid number of instructions to execute")]
+    InvalidInstructionCount,
+    #[msg("Execution changed the owner of the fund or of its token accounts")]
+    FundIntegrityViolated,
+    #[msg("Vault uses a legacy layout and must be migrated")]
+    VaultNotMigrated,
+    #[msg("Vault already uses the current layout")]
+    VaultAlreadyMigrated,
+    #[msg("Account is not a founder or member transaction")]
+    InvalidTransactionAccount,
+    #[msg("Transaction does not use the legacy layout")]
+    TransactionNotLegacy,
 }
@@ -0,0 +1,384 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+/// Kind of transaction an event refers to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum TransactionKind {
+    Founder,
+    Member,
+    Committee,
+    Batch,
+}
+
+/// Data returned by an invoked instruction, see `get_return_data`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InstructionReturnData {
+    /// Index of the instruction among the instructions executed in the call
+    pub instruction_index: u16,
+    /// Program that set the return data
+    pub program_id: Pubkey,
+    pub data: Vec<u8>,
+}
+
+/// What an execution produced
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ExecutionReceipt {
+    /// Fund balance before and after the execution, none if the message does not reference the fund
+    pub fund_lamports_before: Option<u64>,
+    pub fund_lamports_after: Option<u64>,
+    /// Data returned by the instructions that set any
+    pub return_data: Vec<InstructionReturnData>,
+}
+
+/// Vote cast on a transaction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum TransactionVote {
+    Approve,
+    Reject,
+    Cancel,
+}
+
+/// Vault setting changed by an update instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum VaultUpdate {
+    FounderWeight {
+        founder: Pubkey,
+        weight: u16,
+    },
+    MemberThreshold {
+        member_threshold: u16,
+    },
+    TimeLock {
+        time_lock_seconds: u32,
+    },
+    MaxExpiry {
+        max_expiry_seconds: u32,
+        max_expiry_slots: u64,
+    },
+    MemberRateLimit {
+        member: Pubkey,
+        max_transactions: u32,
+        window_seconds: u32,
+    },
+    RentCollector {
+        rent_collector: Pubkey,
+    },
+    Compacted,
+    Migrated {
+        version: u8,
+    },
+    Metadata,
+    VetoCouncil {
+        guardians: Vec<Pubkey>,
+        guardian_threshold: u16,
+    },
+    Recovery {
+        guardians: Vec<Pubkey>,
+        guardian_threshold: u16,
+        inactivity_period_seconds: u32,
+        time_lock_seconds: u32,
+    },
+}
+
+// Every vault event carries the key that signed the instruction as `actor`, and the
+// vault's last transaction index at the time of the event as `transaction_index`.
+// Transaction events carry the index of the transaction itself instead.
+
+/// Emitted when the global vault configuration is initialized or updated
+#[event]
+pub struct VaultConfigUpdatedEvent {
+    pub vault_config: Pubkey,
+    pub actor: Pubkey,
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub creation_fee: u64,
+}
+
+#[event]
+pub struct VaultCreatedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub founders: Vec<Pubkey>,
+    pub founder_weights: Vec<u16>,
+    pub founder_threshold: u16,
+}
+
+#[event]
+pub struct MembersAddedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub members: Vec<Pubkey>,
+}
+
+#[event]
+pub struct MembersRemovedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub members: Vec<Pubkey>,
+}
+
+#[event]
+pub struct FounderAddedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub founder: Pubkey,
+    pub weight: u16,
+}
+
+#[event]
+pub struct FounderRemovedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub founder: Pubkey,
+}
+
+#[event]
+pub struct FounderThresholdChangedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub founder_threshold: u16,
+}
+
+/// Emitted when any other vault setting changes
+#[event]
+pub struct VaultUpdatedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub update: VaultUpdate,
+}
+
+#[event]
+pub struct InvitationCreatedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub invitee: Pubkey,
+    pub role: VaultRole,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct InvitationRevokedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub invitee: Pubkey,
+}
+
+#[event]
+pub struct RecoveryProposedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub new_founders: Vec<Pubkey>,
+    pub new_founder_threshold: u16,
+}
+
+#[event]
+pub struct RecoveryApprovedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub threshold_reached: bool,
+}
+
+#[event]
+pub struct RecoveryExecutedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub founders: Vec<Pubkey>,
+    pub founder_threshold: u16,
+}
+
+#[event]
+pub struct RecoveryCancelledEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+}
+
+#[event]
+pub struct CommitteeCreatedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub committee: Pubkey,
+    pub committee_index: u32,
+}
+
+#[event]
+pub struct CommitteeDissolvedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub committee: Pubkey,
+}
+
+#[event]
+pub struct CommitteeFundSweptEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub committee: Pubkey,
+}
+
+#[event]
+pub struct TransactionBufferCreatedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub transaction_buffer: Pubkey,
+    pub buffer_index: u8,
+    pub final_buffer_size: u16,
+}
+
+/// Emitted when a chunk is appended to a transaction buffer, with the size uploaded so far
+#[event]
+pub struct TransactionBufferExtendedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub transaction_buffer: Pubkey,
+    pub buffer_size: u16,
+}
+
+#[event]
+pub struct TransactionBufferClosedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub transaction_buffer: Pubkey,
+}
+
+#[event]
+pub struct TransactionCreatedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub kind: TransactionKind,
+    pub transaction: Pubkey,
+}
+
+/// Emitted when a member transaction is moved into a new founder transaction
+#[event]
+pub struct TransactionEscalatedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub transaction: Pubkey,
+    pub member_transaction_index: u32,
+    pub member_transaction: Pubkey,
+}
+
+#[event]
+pub struct BatchTransactionAddedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub batch: Pubkey,
+    pub batch_transaction_index: u32,
+    pub batch_transaction: Pubkey,
+}
+
+#[event]
+pub struct TransactionVoteCastEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub kind: TransactionKind,
+    pub transaction: Pubkey,
+    pub vote: TransactionVote,
+}
+
+/// Emitted when a vote, an expiry, a veto or a partial execution moves a transaction to a new status.
+/// Votes emit it next to `TransactionVoteCastEvent`, only when they reach a threshold.
+#[event]
+pub struct TransactionStatusChangedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub kind: TransactionKind,
+    pub transaction: Pubkey,
+    pub status: VaultTransactionStatus,
+}
+
+/// Emitted when a batch is activated, or a vote or a veto moves it to a new status.
+/// Votes emit it only when they reach a threshold.
+#[event]
+pub struct BatchStatusChangedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub batch: Pubkey,
+    pub status: VaultBatchStatus,
+}
+
+#[event]
+pub struct TransactionExecutedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub kind: TransactionKind,
+    pub transaction: Pubkey,
+}
+
+#[event]
+pub struct BatchTransactionExecutedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub batch: Pubkey,
+    pub batch_transaction_index: u32,
+    pub batch_transaction: Pubkey,
+}
+
+/// Emitted by every execution, partial or not, with what it produced.
+/// Messages executed without a transaction report no transaction.
+#[event]
+pub struct ExecutionReceiptEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub kind: TransactionKind,
+    pub transaction: Option<Pubkey>,
+    pub receipt: ExecutionReceipt,
+}
+
+/// Emitted when a member executes a message without creating a transaction
+#[event]
+pub struct MessageExecutedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+}
+
+/// Emitted when a transaction is closed. Closing is permissionless, so the actor is the creator
+/// the rent is refunded to.
+#[event]
+pub struct TransactionClosedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub kind: TransactionKind,
+    pub transaction: Pubkey,
+}
+
+/// Emitted when a batch transaction is closed, with the batch creator refunded as the actor
+#[event]
+pub struct BatchTransactionClosedEvent {
+    pub vault: Pubkey,
+    pub actor: Pubkey,
+    pub transaction_index: u32,
+    pub batch: Pubkey,
+    pub batch_transaction_index: u32,
+    pub batch_transaction: Pubkey,
+}
@@ -0,0 +1,107 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to create a committee in the vault
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: VaultCreateCommitteeArgs)]
+pub struct VaultCreateCommittee<'info> {
+    #[account(
+        init,
+        payer = founder,
+        space = VaultCommittee::size(&args.members),
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_COMMITTEE,
+            &vault.committee_index.checked_add(1).unwrap().to_le_bytes(),
+        ],
+        bump
+    )]
+    pub committee: Account<'info, VaultCommittee>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = vault.is_founder(&founder.key()) @ VaultError::FounderNotFound
+    )]
+    pub founder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Arguments required to create a committee
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultCreateCommitteeArgs {
+    pub members: Vec<Pubkey>,
+    pub threshold: u16,
+}
+
+/// Creates a committee with its own members, threshold and fund
+pub fn create(ctx: Context<VaultCreateCommittee>, args: VaultCreateCommitteeArgs) -> Result<()> {
+    let VaultCreateCommitteeArgs {
+        mut members,
+        threshold,
+    } = args;
+
+    let VaultCreateCommittee {
+        vault,
+        committee,
+        founder,
+        ..
+    } = ctx.accounts;
+
+    // Sort and deduplicate members for binary search
+    members.sort();
+    members.dedup();
+
+    // Committee members must be members of the vault
+    require!(
+        members.iter().all(|member| vault.is_member(member)),
+        VaultError::MemberNotFound
+    );
+
+    // Increment committee index for this vault
+    let committee_index = vault.committee_index.checked_add(1).unwrap();
+    vault.committee_index = committee_index;
+
+    // Fetch the committee fund bump, the fund is scoped to the committee instead of the vault
+    let committee_key = committee.key();
+    let (_, fund_bump) =
+        Pubkey::find_program_address(&[SEED_PREFIX, committee_key.as_ref(), SEED_FUND], &id());
+
+    committee.set_inner(VaultCommittee {
+        vault: vault.key(),
+        creator: founder.key(),
+        committee_index,
+        bump: ctx.bumps.committee,
+        fund_bump,
+        members,
+        threshold,
+        transaction_index: 0,
+    });
+
+    committee.validate()?;
+
+    emit_cpi!(CommitteeCreatedEvent {
+        vault: vault.key(),
+        actor: founder.key(),
+        transaction_index: vault.transaction_index,
+        committee: committee_key,
+        committee_index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,180 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Accounts required to dissolve a committee.
+/// Token accounts owned by the committee fund are passed in `remaining_accounts`
+/// as `[committee_token_account, fund_token_account]` pairs.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultDissolveCommittee<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_COMMITTEE,
+            &committee.committee_index.to_le_bytes(),
+        ],
+        bump = committee.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        has_one = creator @ VaultError::InvalidAccount,
+        close = creator
+    )]
+    pub committee: Account<'info, VaultCommittee>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(constraint = vault.is_founder(&founder.key()) @ VaultError::FounderNotFound)]
+    pub founder: Signer<'info>,
+
+    /// The founder who created the committee and receives its rent back
+    #[account(mut)]
+    pub creator: SystemAccount<'info>,
+
+    /// The committee's fund, swept into the vault fund so no assets are stranded
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, committee.key().as_ref(), SEED_FUND],
+        bump = committee.fund_bump
+    )]
+    pub committee_fund: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, vault.key().as_ref(), SEED_FUND],
+        bump
+    )]
+    pub fund: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+/// Dissolves a committee, its pending transactions can no longer be voted on or executed.
+/// The tokens and lamports held by the committee fund are moved to the vault fund,
+/// token accounts left out can still be recovered with `sweep_committee_fund`.
+pub fn dissolve<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VaultDissolveCommittee<'info>>,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let committee_key = ctx.accounts.committee.key();
+    let committee_fund = &ctx.accounts.committee_fund;
+    let fund = &ctx.accounts.fund;
+    let fund_seeds: &[&[u8]] = &[
+        SEED_PREFIX,
+        committee_key.as_ref(),
+        SEED_FUND,
+        &[ctx.accounts.committee.fund_bump],
+    ];
+
+    sweep_committee_fund(
+        ctx.remaining_accounts,
+        committee_fund,
+        fund,
+        &ctx.accounts.system_program,
+        ctx.accounts.token_program.as_ref(),
+        fund_seeds,
+    )?;
+
+    emit_cpi!(CommitteeDissolvedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.founder.key(),
+        transaction_index: vault.transaction_index,
+        committee: committee_key,
+    });
+
+    Ok(())
+}
+
+/// Moves the tokens and lamports of a committee fund into the vault fund.
+/// Token accounts are passed as `[committee_token_account, fund_token_account]` pairs and closed once emptied.
+pub(crate) fn sweep_committee_fund<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    committee_fund: &SystemAccount<'info>,
+    fund: &SystemAccount<'info>,
+    system_program: &Program<'info, System>,
+    token_program: Option<&Program<'info, Token>>,
+    fund_seeds: &[&[u8]],
+) -> Result<()> {
+    let token_account_pairs = remaining_accounts.chunks_exact(2);
+    require!(
+        token_account_pairs.remainder().is_empty(),
+        VaultError::InvalidNumberOfAccounts
+    );
+
+    for account_infos in token_account_pairs {
+        let token_program = token_program.ok_or(VaultError::MissingAccount)?;
+        let committee_token_account = Account::<TokenAccount>::try_from(&account_infos[0])?;
+        let fund_token_account = Account::<TokenAccount>::try_from(&account_infos[1])?;
+
+        require_keys_eq!(
+            committee_token_account.owner,
+            committee_fund.key(),
+            VaultError::InvalidAccount
+        );
+        require_keys_eq!(
+            fund_token_account.owner,
+            fund.key(),
+            VaultError::InvalidAccount
+        );
+        require_keys_eq!(
+            committee_token_account.mint,
+            fund_token_account.mint,
+            VaultError::InvalidAccount
+        );
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: committee_token_account.to_account_info(),
+                    to: fund_token_account.to_account_info(),
+                    authority: committee_fund.to_account_info(),
+                },
+                &[fund_seeds],
+            ),
+            committee_token_account.amount,
+        )?;
+
+        token::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::CloseAccount {
+                account: committee_token_account.to_account_info(),
+                destination: committee_fund.to_account_info(),
+                authority: committee_fund.to_account_info(),
+            },
+            &[fund_seeds],
+        ))?;
+    }
+
+    // Swept last, so it includes the rent of the closed token accounts
+    let fund_lamports = committee_fund.lamports();
+    if fund_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: committee_fund.to_account_info(),
+                    to: fund.to_account_info(),
+                },
+                &[fund_seeds],
+            ),
+            fund_lamports,
+        )?;
+    }
+
+    Ok(())
+}
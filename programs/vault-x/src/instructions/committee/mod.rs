@@ -0,0 +1,7 @@
+pub mod create;
+pub mod dissolve;
+pub mod sweep;
+
+pub use create::*;
+pub use dissolve::*;
+pub use sweep::*;
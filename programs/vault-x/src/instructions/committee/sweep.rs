@@ -0,0 +1,100 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+
+use super::sweep_committee_fund;
+
+/// Accounts required to sweep the fund of a dissolved committee.
+/// Token accounts owned by the committee fund are passed in `remaining_accounts`
+/// as `[committee_token_account, fund_token_account]` pairs.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: VaultSweepCommitteeFundArgs)]
+pub struct VaultSweepCommitteeFund<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated,
+        constraint = args.committee_index >= 1 && args.committee_index <= vault.committee_index @ VaultError::InvalidAccount
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(constraint = vault.is_founder(&founder.key()) @ VaultError::FounderNotFound)]
+    pub founder: Signer<'info>,
+
+    /// CHECK: The committee, which must have been dissolved so its transactions can no longer use the fund
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_COMMITTEE,
+            &args.committee_index.to_le_bytes(),
+        ],
+        bump,
+        constraint = committee.data_is_empty() @ VaultError::InvalidAccount
+    )]
+    pub committee: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, committee.key().as_ref(), SEED_FUND],
+        bump
+    )]
+    pub committee_fund: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, vault.key().as_ref(), SEED_FUND],
+        bump
+    )]
+    pub fund: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+/// Arguments required to sweep the fund of a dissolved committee
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultSweepCommitteeFundArgs {
+    pub committee_index: u32,
+}
+
+/// Moves what a dissolved committee's fund received after, or was left out of, its dissolution into the vault fund.
+/// No account can list every token account owned by the fund, so they can be swept in as many calls as needed.
+pub fn sweep<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VaultSweepCommitteeFund<'info>>,
+    _args: VaultSweepCommitteeFundArgs,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let committee_key = ctx.accounts.committee.key();
+    let fund_seeds: &[&[u8]] = &[
+        SEED_PREFIX,
+        committee_key.as_ref(),
+        SEED_FUND,
+        &[ctx.bumps.committee_fund],
+    ];
+
+    sweep_committee_fund(
+        ctx.remaining_accounts,
+        &ctx.accounts.committee_fund,
+        &ctx.accounts.fund,
+        &ctx.accounts.system_program,
+        ctx.accounts.token_program.as_ref(),
+        fund_seeds,
+    )?;
+
+    emit_cpi!(CommitteeFundSweptEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.founder.key(),
+        transaction_index: vault.transaction_index,
+        committee: committee_key,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for an invitee to accept an invitation
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultAcceptInvitation<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_INVITATION,
+            invitee.key().as_ref(),
+        ],
+        bump = invitation.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        has_one = invitee @ VaultError::Unauthorized,
+        has_one = inviter @ VaultError::InvalidAccount,
+        close = inviter
+    )]
+    pub invitation: Account<'info, VaultInvitation>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The invitee, also paying for additional storage if the vault needs to expand
+    #[account(mut)]
+    pub invitee: Signer<'info>,
+
+    /// The inviter, who receives the invitation's rent back
+    #[account(mut)]
+    pub inviter: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accepts an invitation, inserting the invitee into the vault with the invited role
+pub fn accept(ctx: Context<VaultAcceptInvitation>) -> Result<()> {
+    let VaultAcceptInvitation {
+        vault,
+        invitation,
+        invitee,
+        inviter,
+        system_program,
+        ..
+    } = ctx.accounts;
+
+    require!(
+        !invitation.is_expired(Clock::get()?.unix_timestamp),
+        VaultError::InvitationExpired
+    );
+
+    // The inviter must still hold the authority it invited with
+    let role = invitation.role.clone();
+    match role {
+        VaultRole::Founder => {
+            require_keys_eq!(inviter.key(), vault.administrator, VaultError::Unauthorized)
+        }
+        VaultRole::Member => require!(
+            vault.is_founder(&inviter.key()),
+            VaultError::FounderNotFound
+        ),
+    }
+
+    match role {
+        VaultRole::Founder => {
+            vault.insert_founder(invitee.key(), invitation.founder_weight)?;
+
+            // Ensure the number of founders doesn't exceed the limit
+            require!(
+                vault.founders.len() <= usize::from(MAX_VAULT_ROLES),
+                VaultError::InvalidRoleCount
+            );
+        }
+        VaultRole::Member => {
+            match vault.members.binary_search(invitee.key) {
+                Ok(_) => return err!(VaultError::MemberAlreadyExists),
+                Err(member_index) => vault.members.insert(member_index, invitee.key()),
+            };
+
+            // Ensure the number of members doesn't exceed the limit
+            require!(
+                vault.members.len() <= usize::from(MAX_VAULT_ROLES),
+                VaultError::InvalidRoleCount
+            );
+        }
+    }
+
+    // Reallocate if necessary
+    Vault::realloc_if_needed(
+        vault.to_account_info(),
+        &vault.founders,
+        &vault.members,
+        Some(invitee.to_account_info()),
+        Some(system_program.to_account_info()),
+    )?;
+
+    vault.stale_transaction_index = vault.transaction_index;
+    vault.validate()?;
+
+    match role {
+        VaultRole::Founder => emit_cpi!(FounderAddedEvent {
+            vault: vault.key(),
+            actor: invitee.key(),
+            transaction_index: vault.transaction_index,
+            founder: invitee.key(),
+            weight: invitation.founder_weight,
+        }),
+        VaultRole::Member => emit_cpi!(MembersAddedEvent {
+            vault: vault.key(),
+            actor: invitee.key(),
+            transaction_index: vault.transaction_index,
+            members: vec![invitee.key()],
+        }),
+    }
+
+    Ok(())
+}
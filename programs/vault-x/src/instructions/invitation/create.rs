@@ -0,0 +1,122 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to invite a key to join the vault
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: VaultCreateInvitationArgs)]
+pub struct VaultCreateInvitation<'info> {
+    #[account(
+        init,
+        payer = inviter,
+        space = VaultInvitation::size(),
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_INVITATION,
+            args.invitee.as_ref(),
+        ],
+        bump
+    )]
+    pub invitation: Account<'info, VaultInvitation>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// A founder inviting a member, or the administrator inviting a founder
+    #[account(mut)]
+    pub inviter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Arguments required to invite a key to join the vault
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultCreateInvitationArgs {
+    pub invitee: Pubkey,
+    pub role: VaultRole,
+    /// Vote weight granted if invited as a founder, defaults to 1
+    pub founder_weight: Option<u16>,
+    pub expires_at: i64,
+}
+
+/// Creates an invitation that the invitee has to accept to join the vault
+pub fn create(ctx: Context<VaultCreateInvitation>, args: VaultCreateInvitationArgs) -> Result<()> {
+    let VaultCreateInvitationArgs {
+        invitee,
+        role,
+        founder_weight,
+        expires_at,
+    } = args;
+
+    let VaultCreateInvitation {
+        vault,
+        invitation,
+        inviter,
+        ..
+    } = ctx.accounts;
+
+    // Same authorities as adding a role directly
+    match role {
+        VaultRole::Founder => {
+            require_keys_eq!(inviter.key(), vault.administrator, VaultError::Unauthorized);
+            require!(
+                invitee.ne(&vault.administrator),
+                VaultError::AdminCannotBeFounder
+            );
+            require!(
+                !vault.is_founder(&invitee),
+                VaultError::FounderAlreadyExists
+            );
+        }
+        VaultRole::Member => {
+            require!(
+                vault.is_founder(&inviter.key()),
+                VaultError::FounderNotFound
+            );
+            require!(
+                invitee.ne(&vault.administrator),
+                VaultError::AdminCannotBeMember
+            );
+            require!(!vault.is_member(&invitee), VaultError::MemberAlreadyExists);
+        }
+    }
+
+    let founder_weight = founder_weight.unwrap_or(1);
+    require!(founder_weight > 0, VaultError::InvalidFounderWeight);
+
+    require!(
+        expires_at > Clock::get()?.unix_timestamp,
+        VaultError::InvitationExpired
+    );
+
+    emit_cpi!(InvitationCreatedEvent {
+        vault: vault.key(),
+        actor: inviter.key(),
+        transaction_index: vault.transaction_index,
+        invitee,
+        role: role.clone(),
+        expires_at,
+    });
+
+    invitation.set_inner(VaultInvitation {
+        vault: vault.key(),
+        inviter: inviter.key(),
+        invitee,
+        role,
+        founder_weight,
+        expires_at,
+        bump: ctx.bumps.invitation,
+    });
+
+    Ok(())
+}
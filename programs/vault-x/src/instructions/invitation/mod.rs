@@ -0,0 +1,7 @@
+pub mod accept;
+pub mod create;
+pub mod revoke;
+
+pub use accept::*;
+pub use create::*;
+pub use revoke::*;
@@ -0,0 +1,63 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to revoke an invitation
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultRevokeInvitation<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_INVITATION,
+            invitation.invitee.as_ref(),
+        ],
+        bump = invitation.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        has_one = inviter @ VaultError::InvalidAccount,
+        close = inviter
+    )]
+    pub invitation: Account<'info, VaultInvitation>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The inviter, who receives the invitation's rent back
+    #[account(mut)]
+    pub inviter: SystemAccount<'info>,
+
+    /// The inviter or the invitee can revoke a pending invitation, anyone can revoke an expired one
+    pub authority: Signer<'info>,
+}
+
+/// Revokes an unclaimed invitation, refunding the rent to the inviter
+pub fn revoke(ctx: Context<VaultRevokeInvitation>) -> Result<()> {
+    let invitation = &ctx.accounts.invitation;
+    let authority = ctx.accounts.authority.key();
+
+    require!(
+        authority.eq(&invitation.inviter)
+            || authority.eq(&invitation.invitee)
+            || invitation.is_expired(Clock::get()?.unix_timestamp),
+        VaultError::Unauthorized
+    );
+
+    emit_cpi!(InvitationRevokedEvent {
+        vault: ctx.accounts.vault.key(),
+        actor: authority,
+        transaction_index: ctx.accounts.vault.transaction_index,
+        invitee: invitation.invitee,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,13 @@
+pub mod committee;
+pub mod invitation;
+pub mod recovery;
 pub mod transaction;
 pub mod vault;
 pub mod vault_config;
 
+pub use committee::*;
+pub use invitation::*;
+pub use recovery::*;
 pub use transaction::*;
 pub use vault::*;
 pub use vault_config::*;
@@ -0,0 +1,70 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for a guardian to approve a pending recovery
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultApproveRecovery<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, vault.key().as_ref(), SEED_RECOVERY],
+        bump = vault_recovery.bump,
+        has_one = vault @ VaultError::InvalidAccount,
+        constraint = vault_recovery.is_guardian(&guardian.key()) @ VaultError::GuardianNotFound
+    )]
+    pub vault_recovery: Account<'info, VaultRecovery>,
+
+    pub guardian: Signer<'info>,
+}
+
+/// Approves a pending recovery, starting its time lock once the guardian threshold is reached
+pub fn approve(ctx: Context<VaultApproveRecovery>) -> Result<()> {
+    let guardian = &ctx.accounts.guardian;
+    let vault = &ctx.accounts.vault;
+    let vault_recovery = &mut ctx.accounts.vault_recovery;
+    let guardian_threshold = usize::from(vault_recovery.guardian_threshold);
+
+    let proposal = vault_recovery
+        .proposal
+        .as_mut()
+        .ok_or(VaultError::RecoveryNotProposed)?;
+
+    // Any founder activity since the proposal shows the founders still hold their keys
+    require!(
+        vault.last_activity_at <= proposal.proposed_at,
+        VaultError::FoundersStillActive
+    );
+
+    match proposal.approved.binary_search(&guardian.key()) {
+        Ok(_) => return err!(VaultError::RecoveryAlreadyApproved),
+        Err(guardian_index) => proposal.approved.insert(guardian_index, guardian.key()),
+    }
+
+    let threshold_reached =
+        proposal.approved_at == 0 && proposal.approved.len() >= guardian_threshold;
+    if threshold_reached {
+        proposal.approved_at = Clock::get()?.unix_timestamp;
+        msg!("Recovery approved");
+    }
+
+    emit_cpi!(RecoveryApprovedEvent {
+        vault: vault.key(),
+        actor: guardian.key(),
+        transaction_index: vault.transaction_index,
+        threshold_reached,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for a founder to cancel a pending recovery
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultCancelRecovery<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, vault.key().as_ref(), SEED_RECOVERY],
+        bump = vault_recovery.bump,
+        has_one = vault @ VaultError::InvalidAccount,
+        realloc = VaultRecovery::size(&vault_recovery.guardians, None),
+        realloc::payer = proposer,
+        realloc::zero = false
+    )]
+    pub vault_recovery: Account<'info, VaultRecovery>,
+
+    #[account(constraint = vault.is_founder(&founder.key()) @ VaultError::FounderNotFound)]
+    pub founder: Signer<'info>,
+
+    /// The guardian who proposed the recovery, receiving the proposal's rent
+    #[account(
+        mut,
+        constraint = vault_recovery.proposal.as_ref().is_some_and(|proposal| proposal.proposer.eq(&proposer.key())) @ VaultError::InvalidAccount
+    )]
+    pub proposer: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Cancels a pending recovery, proving the founders still hold their keys
+pub fn cancel(ctx: Context<VaultCancelRecovery>) -> Result<()> {
+    let vault_recovery = &mut ctx.accounts.vault_recovery;
+
+    require!(
+        vault_recovery.proposal.take().is_some(),
+        VaultError::RecoveryNotProposed
+    );
+
+    let vault = &mut ctx.accounts.vault;
+    vault.record_founder_activity()?;
+
+    emit_cpi!(RecoveryCancelledEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.founder.key(),
+        transaction_index: vault.transaction_index,
+    });
+
+    Ok(())
+}
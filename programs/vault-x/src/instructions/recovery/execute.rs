@@ -0,0 +1,102 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to replace the founders of the vault with an approved recovery
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultExecuteRecovery<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, vault.key().as_ref(), SEED_RECOVERY],
+        bump = vault_recovery.bump,
+        has_one = vault @ VaultError::InvalidAccount,
+        constraint = vault_recovery.is_guardian(&guardian.key()) @ VaultError::GuardianNotFound,
+        realloc = VaultRecovery::size(&vault_recovery.guardians, None),
+        realloc::payer = proposer,
+        realloc::zero = false
+    )]
+    pub vault_recovery: Account<'info, VaultRecovery>,
+
+    /// The account used to pay for additional storage if the vault needs to expand
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The guardian who proposed the recovery, receiving the proposal's rent
+    #[account(
+        mut,
+        constraint = vault_recovery.proposal.as_ref().is_some_and(|proposal| proposal.proposer.eq(&proposer.key())) @ VaultError::InvalidAccount
+    )]
+    pub proposer: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replaces the founders of the vault once the recovery time lock has elapsed
+pub fn execute(ctx: Context<VaultExecuteRecovery>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let vault_recovery = &mut ctx.accounts.vault_recovery;
+    let now = Clock::get()?.unix_timestamp;
+
+    let proposal = vault_recovery
+        .proposal
+        .take()
+        .ok_or(VaultError::RecoveryNotProposed)?;
+
+    require!(proposal.approved_at > 0, VaultError::RecoveryNotApproved);
+    require!(
+        now >= proposal
+            .approved_at
+            .saturating_add(i64::from(vault_recovery.time_lock_seconds)),
+        VaultError::TimeLockNotElapsed
+    );
+
+    // Founders can still prevent the recovery by voting or executing until it is executed
+    require!(
+        vault.last_activity_at <= proposal.proposed_at,
+        VaultError::FoundersStillActive
+    );
+
+    // Reallocate if necessary
+    Vault::realloc_if_needed(
+        vault.to_account_info(),
+        &proposal.new_founders,
+        &vault.members,
+        Some(ctx.accounts.guardian.to_account_info()),
+        Some(ctx.accounts.system_program.to_account_info()),
+    )?;
+
+    vault.founders = proposal.new_founders;
+    vault.founder_weights = proposal.new_founder_weights;
+    vault.founder_threshold = proposal.new_founder_threshold;
+
+    // The new founders start with a full inactivity period
+    vault.last_activity_at = now;
+
+    vault.stale_transaction_index = vault.transaction_index;
+    vault.validate()?;
+
+    msg!("Vault recovered");
+
+    emit_cpi!(RecoveryExecutedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.guardian.key(),
+        transaction_index: vault.transaction_index,
+        founders: vault.founders.clone(),
+        founder_threshold: vault.founder_threshold,
+    });
+
+    Ok(())
+}
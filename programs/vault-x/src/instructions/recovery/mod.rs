@@ -0,0 +1,11 @@
+pub mod approve;
+pub mod cancel;
+pub mod execute;
+pub mod propose;
+pub mod set;
+
+pub use approve::*;
+pub use cancel::*;
+pub use execute::*;
+pub use propose::*;
+pub use set::*;
@@ -0,0 +1,132 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for a guardian to propose a new founder set
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: VaultProposeRecoveryArgs)]
+pub struct VaultProposeRecovery<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, vault.key().as_ref(), SEED_RECOVERY],
+        bump = vault_recovery.bump,
+        has_one = vault @ VaultError::InvalidAccount,
+        constraint = vault_recovery.is_guardian(&guardian.key()) @ VaultError::GuardianNotFound,
+        realloc = VaultRecovery::size(&vault_recovery.guardians, None)
+            + VaultRecoveryProposal::size(args.new_founders.len(), vault_recovery.guardians.len()),
+        realloc::payer = guardian,
+        realloc::zero = false
+    )]
+    pub vault_recovery: Account<'info, VaultRecovery>,
+
+    /// Pays for the proposal storage, refunded once the recovery is executed or cancelled
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Arguments required to propose a new founder set
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultProposeRecoveryArgs {
+    pub new_founders: Vec<Pubkey>,
+    /// Vote weight of each new founder, in the same order. Defaults to 1 for every founder
+    pub new_founder_weights: Option<Vec<u16>>,
+    pub new_founder_threshold: u16,
+}
+
+/// Proposes to replace the founders of a vault whose founders have been inactive for too long
+pub fn propose(ctx: Context<VaultProposeRecovery>, args: VaultProposeRecoveryArgs) -> Result<()> {
+    let VaultProposeRecoveryArgs {
+        new_founders,
+        new_founder_weights,
+        new_founder_threshold,
+    } = args;
+
+    let VaultProposeRecovery {
+        vault,
+        vault_recovery,
+        guardian,
+        ..
+    } = ctx.accounts;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        vault_recovery.proposal.is_none(),
+        VaultError::RecoveryAlreadyProposed
+    );
+    require!(
+        vault_recovery.is_recovery_available(vault.last_activity_at, now),
+        VaultError::FoundersStillActive
+    );
+
+    // Pair each founder with its vote weight
+    let new_founder_weights = new_founder_weights.unwrap_or_else(|| vec![1; new_founders.len()]);
+    require!(
+        new_founder_weights.len() == new_founders.len()
+            && new_founder_weights.iter().all(|weight| *weight > 0),
+        VaultError::InvalidFounderWeight
+    );
+
+    let mut founders_with_weights: Vec<(Pubkey, u16)> =
+        new_founders.into_iter().zip(new_founder_weights).collect();
+
+    // Sort and deduplicate founders for binary search
+    founders_with_weights.sort_by_key(|(founder, _)| *founder);
+    founders_with_weights.dedup_by_key(|(founder, _)| *founder);
+
+    let (new_founders, new_founder_weights): (Vec<Pubkey>, Vec<u16>) =
+        founders_with_weights.into_iter().unzip();
+
+    // Same rules as the founders of a new vault
+    require!(
+        !vault.check_for_admin_presence(&new_founders),
+        VaultError::AdminCannotBeFounder
+    );
+    vault.role_array_validate(&new_founders)?;
+
+    let total_founder_weight: u64 = new_founder_weights.iter().copied().map(u64::from).sum();
+    if new_founder_threshold < 1 || u64::from(new_founder_threshold) > total_founder_weight {
+        return err!(VaultError::InvalidFounderThreshold);
+    }
+
+    // The proposer approves its own proposal
+    let approved_at = if vault_recovery.guardian_threshold <= 1 {
+        now
+    } else {
+        0
+    };
+
+    emit_cpi!(RecoveryProposedEvent {
+        vault: vault.key(),
+        actor: guardian.key(),
+        transaction_index: vault.transaction_index,
+        new_founders: new_founders.clone(),
+        new_founder_threshold,
+    });
+
+    vault_recovery.proposal = Some(VaultRecoveryProposal {
+        proposer: guardian.key(),
+        new_founders,
+        new_founder_weights,
+        new_founder_threshold,
+        approved: vec![guardian.key()],
+        proposed_at: now,
+        approved_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to register or replace the recovery guardians of the vault
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultSetRecovery<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        has_one = administrator @ VaultError::Unauthorized,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: The recovery PDA is created or reallocated in the instruction
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, vault.key().as_ref(), SEED_RECOVERY],
+        bump
+    )]
+    pub vault_recovery: UncheckedAccount<'info>,
+
+    /// Pays for additional storage, and receives the excess rent if the recovery shrinks
+    #[account(mut)]
+    pub administrator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Arguments required to register or replace the recovery guardians
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultSetRecoveryArgs {
+    pub guardians: Vec<Pubkey>,
+    pub guardian_threshold: u16,
+    /// Time without any founder vote or execution after which guardians can propose a recovery
+    pub inactivity_period_seconds: u32,
+    /// Delay between the guardian threshold being reached and the recovery execution
+    pub time_lock_seconds: u32,
+}
+
+/// Registers the recovery guardians of the vault, discarding any pending recovery
+pub fn set(ctx: Context<VaultSetRecovery>, args: VaultSetRecoveryArgs) -> Result<()> {
+    let VaultSetRecoveryArgs {
+        mut guardians,
+        guardian_threshold,
+        inactivity_period_seconds,
+        time_lock_seconds,
+    } = args;
+
+    // Sort and deduplicate guardians for binary search
+    guardians.sort();
+    guardians.dedup();
+
+    let vault = &ctx.accounts.vault;
+
+    VaultRecovery {
+        vault: vault.key(),
+        bump: 0,
+        guardians: guardians.clone(),
+        guardian_threshold,
+        inactivity_period_seconds,
+        time_lock_seconds,
+        proposal: None,
+    }
+    .write(
+        &ctx.accounts.vault_recovery.to_account_info(),
+        &ctx.accounts.administrator.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit_cpi!(VaultUpdatedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.administrator.key(),
+        transaction_index: vault.transaction_index,
+        update: VaultUpdate::Recovery {
+            guardians,
+            guardian_threshold,
+            inactivity_period_seconds,
+            time_lock_seconds,
+        },
+    });
+
+    Ok(())
+}
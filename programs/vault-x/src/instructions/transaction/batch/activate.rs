@@ -0,0 +1,58 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for the creator of a draft batch to open it for voting
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultActivateBatch<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_BATCH,
+            &batch.transaction_index.to_le_bytes(),
+        ],
+        bump = batch.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        has_one = creator @ VaultError::Unauthorized,
+        constraint = batch.is_transaction_valid(&VaultBatchStatus::Draft, &vault) @ VaultError::InvalidTransactionStatus
+    )]
+    pub batch: Account<'info, VaultBatch>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = vault.is_founder(&creator.key()) @ VaultError::FounderNotFound
+    )]
+    pub creator: Signer<'info>,
+}
+
+/// Moves a draft batch to the "Active" state, after which no more transactions can be added
+pub fn activate(ctx: Context<VaultActivateBatch>) -> Result<()> {
+    let batch = &mut ctx.accounts.batch;
+
+    require!(batch.size > 0, VaultError::EmptyBatch);
+
+    batch.status = VaultBatchStatus::Active;
+
+    emit_cpi!(BatchStatusChangedEvent {
+        vault: ctx.accounts.vault.key(),
+        actor: ctx.accounts.creator.key(),
+        transaction_index: batch.transaction_index,
+        batch: batch.key(),
+        status: batch.status.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for the creator of a draft batch to add a transaction to it
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: VaultAddBatchTransactionArgs)]
+pub struct VaultAddBatchTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_BATCH,
+            &batch.transaction_index.to_le_bytes(),
+        ],
+        bump = batch.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        has_one = creator @ VaultError::Unauthorized,
+        constraint = batch.is_transaction_valid(&VaultBatchStatus::Draft, &vault) @ VaultError::InvalidTransactionStatus
+    )]
+    pub batch: Account<'info, VaultBatch>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = VaultBatchTransaction::size(args.ephemeral_signers, &args.transaction_message)?,
+        seeds = [
+            SEED_PREFIX,
+            batch.key().as_ref(),
+            SEED_BATCH_TRANSACTION,
+            &batch.size.checked_add(1).unwrap().to_le_bytes(),
+        ],
+        bump
+    )]
+    pub transaction: Account<'info, VaultBatchTransaction>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = vault.is_founder(&creator.key()) @ VaultError::FounderNotFound
+    )]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Adds a transaction message at the end of a draft batch
+pub fn add_transaction(
+    ctx: Context<VaultAddBatchTransaction>,
+    args: VaultAddBatchTransactionArgs,
+) -> Result<()> {
+    let VaultAddBatchTransactionArgs {
+        transaction_message,
+        ephemeral_signers,
+    } = args;
+
+    let VaultAddBatchTransaction {
+        vault,
+        batch,
+        transaction,
+        creator,
+        ..
+    } = ctx.accounts;
+
+    // Validate and deserialize the transaction message
+    let transaction_message = match VaultTransactionMessage::try_from_bytes(&transaction_message) {
+        Ok(transaction_message) => transaction_message,
+        Err(_) => return err!(VaultError::InvalidVaultTransactionMessage),
+    };
+    transaction_message.is_valid()?;
+
+    // Generate ephemeral signer bumps
+    let transaction_key = transaction.key();
+    let ephemeral_signer_bumps: Vec<u8> = (0..ephemeral_signers)
+        .map(|ephemeral_signer_index| {
+            let (_, ephemeral_signer_bump) = Pubkey::find_program_address(
+                &[
+                    SEED_PREFIX,
+                    transaction_key.as_ref(),
+                    SEED_EPHEMERAL_SIGNER,
+                    &ephemeral_signer_index.to_le_bytes(),
+                ],
+                &id(),
+            );
+
+            ephemeral_signer_bump
+        })
+        .collect();
+
+    let index = batch.size.checked_add(1).unwrap();
+    batch.size = index;
+
+    transaction.set_inner(VaultBatchTransaction {
+        batch: batch.key(),
+        index,
+        bump: ctx.bumps.transaction,
+        status: VaultBatchTransactionStatus::Pending,
+        ephemeral_signer_bumps,
+        message: transaction_message,
+    });
+
+    emit_cpi!(BatchTransactionAddedEvent {
+        vault: vault.key(),
+        actor: creator.key(),
+        transaction_index: batch.transaction_index,
+        batch: batch.key(),
+        batch_transaction_index: index,
+        batch_transaction: transaction_key,
+    });
+
+    Ok(())
+}
+
+/// Arguments required to add a transaction to a batch
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultAddBatchTransactionArgs {
+    pub ephemeral_signers: u8,
+    pub transaction_message: Vec<u8>,
+}
@@ -0,0 +1,78 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for a founder to approve a batch
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultApproveBatch<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_BATCH,
+            &batch.transaction_index.to_le_bytes(),
+        ],
+        bump = batch.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        constraint = batch.is_transaction_valid(&VaultBatchStatus::Active, &vault) @ VaultError::InvalidTransactionStatus
+    )]
+    pub batch: Account<'info, VaultBatch>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = vault.is_founder(&founder.key()) @ VaultError::FounderNotFound
+    )]
+    pub founder: Signer<'info>,
+}
+
+/// Approves a batch, moving it to the "Approved" state if enough approvals are collected
+pub fn approve(ctx: Context<VaultApproveBatch>) -> Result<()> {
+    let founder = &ctx.accounts.founder;
+    let batch = &mut ctx.accounts.batch;
+    let vault: &Vault = &ctx.accounts.vault;
+
+    let status = batch.status.clone();
+    batch.approve(founder.key(), vault)?;
+
+    // Start the time lock once the batch is approved
+    if batch.status.eq(&VaultBatchStatus::Approved) {
+        batch.start_time_lock(vault, Clock::get()?.unix_timestamp);
+    }
+
+    ctx.accounts.vault.record_founder_activity()?;
+
+    emit_cpi!(TransactionVoteCastEvent {
+        vault: ctx.accounts.vault.key(),
+        actor: founder.key(),
+        transaction_index: batch.transaction_index,
+        kind: TransactionKind::Batch,
+        transaction: batch.key(),
+        vote: TransactionVote::Approve,
+    });
+
+    if batch.status.ne(&status) {
+        emit_cpi!(BatchStatusChangedEvent {
+            vault: ctx.accounts.vault.key(),
+            actor: founder.key(),
+            transaction_index: batch.transaction_index,
+            batch: batch.key(),
+            status: batch.status.clone(),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for a founder to cancel a batch
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultCancelBatch<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_BATCH,
+            &batch.transaction_index.to_le_bytes(),
+        ],
+        bump = batch.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        constraint = batch.is_transaction_valid(&VaultBatchStatus::Approved, &vault) @ VaultError::InvalidTransactionStatus
+    )]
+    pub batch: Account<'info, VaultBatch>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = vault.is_founder(&founder.key()) @ VaultError::FounderNotFound
+    )]
+    pub founder: Signer<'info>,
+}
+
+/// Cancels an approved batch if enough cancellations are collected, leaving its remaining transactions unexecuted
+pub fn cancel(ctx: Context<VaultCancelBatch>) -> Result<()> {
+    let founder = &ctx.accounts.founder;
+    let batch = &mut ctx.accounts.batch;
+    let vault: &Vault = &ctx.accounts.vault;
+
+    let status = batch.status.clone();
+    batch.cancel(founder.key(), vault)?;
+
+    ctx.accounts.vault.record_founder_activity()?;
+
+    emit_cpi!(TransactionVoteCastEvent {
+        vault: ctx.accounts.vault.key(),
+        actor: founder.key(),
+        transaction_index: batch.transaction_index,
+        kind: TransactionKind::Batch,
+        transaction: batch.key(),
+        vote: TransactionVote::Cancel,
+    });
+
+    if batch.status.ne(&status) {
+        emit_cpi!(BatchStatusChangedEvent {
+            vault: ctx.accounts.vault.key(),
+            actor: founder.key(),
+            transaction_index: batch.transaction_index,
+            batch: batch.key(),
+            status: batch.status.clone(),
+        });
+    }
+
+    Ok(())
+}
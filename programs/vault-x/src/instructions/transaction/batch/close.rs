@@ -0,0 +1,54 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to close a batch
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultCloseBatch<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_BATCH,
+            &batch.transaction_index.to_le_bytes(),
+        ],
+        bump = batch.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        has_one = creator @ VaultError::InvalidAccount,
+        constraint = batch.is_closable(&vault) @ VaultError::InvalidTransactionStatus,
+        close = creator
+    )]
+    pub batch: Account<'info, VaultBatch>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The creator of the batch, who paid for its rent and receives it back
+    #[account(mut)]
+    pub creator: SystemAccount<'info>,
+}
+
+/// Closes a finished batch once all its transactions are closed, refunding the rent to its creator
+pub fn close(ctx: Context<VaultCloseBatch>) -> Result<()> {
+    let batch = &ctx.accounts.batch;
+
+    emit_cpi!(TransactionClosedEvent {
+        vault: ctx.accounts.vault.key(),
+        actor: ctx.accounts.creator.key(),
+        transaction_index: batch.transaction_index,
+        kind: TransactionKind::Batch,
+        transaction: batch.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to close a transaction of a batch
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultCloseBatchTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_BATCH,
+            &batch.transaction_index.to_le_bytes(),
+        ],
+        bump = batch.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        has_one = creator @ VaultError::InvalidAccount
+    )]
+    pub batch: Account<'info, VaultBatch>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            batch.key().as_ref(),
+            SEED_BATCH_TRANSACTION,
+            &transaction.index.to_le_bytes(),
+        ],
+        bump = transaction.bump,
+        constraint = (
+            transaction.status.eq(&VaultBatchTransactionStatus::Executed) || batch.is_finished(&vault)
+        ) @ VaultError::InvalidTransactionStatus,
+        close = creator
+    )]
+    pub transaction: Account<'info, VaultBatchTransaction>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The creator of the batch, who paid for its transactions and receives their rent back
+    #[account(mut)]
+    pub creator: SystemAccount<'info>,
+}
+
+/// Closes an executed transaction of a batch, or any of its transactions once the batch is finished,
+/// refunding the rent to the batch creator
+pub fn close_transaction(ctx: Context<VaultCloseBatchTransaction>) -> Result<()> {
+    let batch = &mut ctx.accounts.batch;
+    let transaction = &ctx.accounts.transaction;
+
+    batch.closed_count = batch.closed_count.checked_add(1).unwrap();
+
+    emit_cpi!(BatchTransactionClosedEvent {
+        vault: ctx.accounts.vault.key(),
+        actor: ctx.accounts.creator.key(),
+        transaction_index: batch.transaction_index,
+        batch: batch.key(),
+        batch_transaction_index: transaction.index,
+        batch_transaction: transaction.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to create a batch
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultCreateBatch<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = VaultBatch::size(vault.founders.len()),
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_BATCH,
+            &vault.transaction_index.checked_add(1).unwrap().to_le_bytes(),
+        ],
+        bump
+    )]
+    pub batch: Account<'info, VaultBatch>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = vault.is_founder(&creator.key()) @ VaultError::FounderNotFound
+    )]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates an empty draft batch, taking the vault's next transaction index
+pub fn create(ctx: Context<VaultCreateBatch>) -> Result<()> {
+    let VaultCreateBatch {
+        vault,
+        batch,
+        creator,
+        ..
+    } = ctx.accounts;
+
+    let vault_key = vault.key();
+
+    // Fetch fund bump to store in the batch
+    let (_, fund_bump) =
+        Pubkey::find_program_address(&[SEED_PREFIX, vault_key.as_ref(), SEED_FUND], &id());
+
+    // Increment transaction index for this vault
+    let transaction_index = vault.transaction_index.checked_add(1).unwrap();
+    vault.transaction_index = transaction_index;
+    vault.record_founder_activity()?;
+
+    batch.set_inner(VaultBatch {
+        creator: creator.key(),
+        vault: vault_key,
+        transaction_index,
+        status: VaultBatchStatus::Draft,
+        bump: ctx.bumps.batch,
+        fund_bump,
+        size: 0,
+        executed_index: 0,
+        closed_count: 0,
+        approved: Vec::new(),
+        rejected: Vec::new(),
+        cancelled: Vec::new(),
+        approved_at: 0,
+        executable_at: 0,
+    });
+
+    emit_cpi!(TransactionCreatedEvent {
+        vault: vault_key,
+        actor: creator.key(),
+        transaction_index,
+        kind: TransactionKind::Batch,
+        transaction: batch.key(),
+    });
+
+    Ok(())
+}
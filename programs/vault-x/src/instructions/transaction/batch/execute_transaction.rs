@@ -0,0 +1,148 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for a founder to execute the next transaction of a batch
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultExecuteBatchTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_BATCH,
+            &batch.transaction_index.to_le_bytes(),
+        ],
+        bump = batch.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        constraint = batch.is_transaction_valid(&VaultBatchStatus::Approved, &vault) @ VaultError::InvalidTransactionStatus
+    )]
+    pub batch: Account<'info, VaultBatch>,
+
+    /// The next transaction of the batch, transactions are executed in the order they were added
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            batch.key().as_ref(),
+            SEED_BATCH_TRANSACTION,
+            &batch.executed_index.checked_add(1).unwrap().to_le_bytes(),
+        ],
+        bump = transaction.bump
+    )]
+    pub transaction: Account<'info, VaultBatchTransaction>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = vault.is_founder(&founder.key()) @ VaultError::FounderNotFound
+    )]
+    pub founder: Signer<'info>,
+}
+
+/// Executes the next transaction of an approved batch, moving the batch to the "Executed" state after the last one
+pub fn execute_transaction(ctx: Context<VaultExecuteBatchTransaction>) -> Result<()> {
+    let batch = &mut ctx.accounts.batch;
+    let transaction = &mut ctx.accounts.transaction;
+    let vault = &ctx.accounts.vault;
+    let vault_key = vault.key();
+
+    // Founders can still cancel the batch until the time lock elapses.
+    require!(
+        batch.is_time_lock_elapsed(Clock::get()?.unix_timestamp),
+        VaultError::TimeLockNotElapsed
+    );
+
+    let fund_bump = batch.fund_bump;
+    let fund_seeds = &[SEED_PREFIX, vault_key.as_ref(), SEED_FUND, &[fund_bump]];
+    let fund_key = Pubkey::create_program_address(fund_seeds, &id()).unwrap();
+
+    // Generate keys and seeds for ephemeral signers.
+    let transaction_key = transaction.key();
+    let (mut ephemeral_signer_keys, mut ephemeral_signer_seeds) =
+        fetch_ephemeral_keys(&transaction.ephemeral_signer_bumps, &transaction_key);
+
+    // Batches are approved by the founders, so they also sign as the founder authority.
+    let (founder_authority_key, founder_authority_seeds) = fetch_founder_authority(&vault_key);
+    ephemeral_signer_keys.push(founder_authority_key);
+    ephemeral_signer_seeds.push(founder_authority_seeds);
+
+    let transaction_message = &transaction.message;
+
+    // Validate and sanitize instructions and account infos.
+    // The address lookup tables come first in the remaining accounts, followed by the message accounts.
+    let transaction_account_infos = transaction_message.validate_message_account_infos(
+        ctx.remaining_accounts,
+        &fund_key,
+        &ephemeral_signer_keys,
+    )?;
+
+    // Protect specific accounts from writable access during execution.
+    let protected_accounts = &[vault_key, batch.key(), transaction_key];
+
+    let instructions_with_ordered_account_infos = transaction_message
+        .fetch_instructions_with_ordered_account_infos(
+            transaction_account_infos,
+            protected_accounts,
+        )?;
+
+    // Execute each instruction with the appropriate signers.
+    let receipt = invoke_transaction_instructions(
+        &instructions_with_ordered_account_infos,
+        &ephemeral_signer_seeds,
+        fund_seeds,
+        &fund_key,
+    )?;
+
+    // Mark the transaction as executed and move the cursor forward.
+    transaction.status = VaultBatchTransactionStatus::Executed;
+    batch.executed_index = transaction.index;
+
+    ctx.accounts.vault.record_founder_activity()?;
+
+    emit_cpi!(BatchTransactionExecutedEvent {
+        vault: vault_key,
+        actor: ctx.accounts.founder.key(),
+        transaction_index: batch.transaction_index,
+        batch: batch.key(),
+        batch_transaction_index: transaction.index,
+        batch_transaction: transaction_key,
+    });
+
+    emit_cpi!(ExecutionReceiptEvent {
+        vault: vault_key,
+        actor: ctx.accounts.founder.key(),
+        transaction_index: batch.transaction_index,
+        kind: TransactionKind::Batch,
+        transaction: Some(transaction_key),
+        receipt,
+    });
+
+    // Mark the batch as executed once its last transaction is.
+    if batch.executed_index == batch.size {
+        batch.status = VaultBatchStatus::Executed;
+
+        emit_cpi!(TransactionExecutedEvent {
+            vault: vault_key,
+            actor: ctx.accounts.founder.key(),
+            transaction_index: batch.transaction_index,
+            kind: TransactionKind::Batch,
+            transaction: batch.key(),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+pub mod activate;
+pub mod add_transaction;
+pub mod approve;
+pub mod cancel;
+pub mod close;
+pub mod close_transaction;
+pub mod create;
+pub mod execute_transaction;
+pub mod reject;
+pub mod veto;
+
+pub use activate::*;
+pub use add_transaction::*;
+pub use approve::*;
+pub use cancel::*;
+pub use close::*;
+pub use close_transaction::*;
+pub use create::*;
+pub use execute_transaction::*;
+pub use reject::*;
+pub use veto::*;
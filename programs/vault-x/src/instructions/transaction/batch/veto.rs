@@ -0,0 +1,72 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for guardians to veto a batch.
+/// Guardians co-signing the veto are passed in `remaining_accounts` as signers.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultVetoBatch<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_BATCH,
+            &batch.transaction_index.to_le_bytes(),
+        ],
+        bump = batch.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        constraint = batch.status.eq(&VaultBatchStatus::Approved) @ VaultError::InvalidTransactionStatus
+    )]
+    pub batch: Account<'info, VaultBatch>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [SEED_PREFIX, vault.key().as_ref(), SEED_VETO_COUNCIL],
+        bump = veto_council.bump,
+        has_one = vault @ VaultError::InvalidAccount,
+        constraint = veto_council.is_guardian(&guardian.key()) @ VaultError::GuardianNotFound
+    )]
+    pub veto_council: Account<'info, VaultVetoCouncil>,
+
+    pub guardian: Signer<'info>,
+}
+
+/// Vetoes an approved batch whose time lock has not elapsed, once the council's guardian threshold has signed.
+/// Batches are never exempt, founders replace the council through a founder transaction.
+pub fn veto(ctx: Context<VaultVetoBatch>) -> Result<()> {
+    let batch = &mut ctx.accounts.batch;
+    let vault = &ctx.accounts.vault;
+
+    require!(
+        !batch.is_time_lock_elapsed(Clock::get()?.unix_timestamp),
+        VaultError::VetoWindowElapsed
+    );
+    ctx.accounts
+        .veto_council
+        .check_veto_signers(&ctx.accounts.guardian.key(), ctx.remaining_accounts)?;
+
+    batch.status = VaultBatchStatus::Vetoed;
+    msg!("Batch vetoed");
+
+    emit_cpi!(BatchStatusChangedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.guardian.key(),
+        transaction_index: batch.transaction_index,
+        batch: batch.key(),
+        status: batch.status.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for the creator of a transaction buffer to discard it
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultCloseTransactionBuffer<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            creator.key().as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes(),
+        ],
+        bump = transaction_buffer.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        has_one = creator @ VaultError::Unauthorized,
+        close = creator
+    )]
+    pub transaction_buffer: Account<'info, VaultTransactionBuffer>,
+
+    /// The vault may no longer list the creator, who can still recover the rent
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+/// Closes a transaction buffer without creating a transaction, refunding the rent to its creator
+pub fn close(ctx: Context<VaultCloseTransactionBuffer>) -> Result<()> {
+    emit_cpi!(TransactionBufferClosedEvent {
+        vault: ctx.accounts.vault.key(),
+        actor: ctx.accounts.creator.key(),
+        transaction_index: ctx.accounts.vault.transaction_index,
+        transaction_buffer: ctx.accounts.transaction_buffer.key(),
+    });
+
+    Ok(())
+}
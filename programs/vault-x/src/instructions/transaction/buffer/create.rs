@@ -0,0 +1,97 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to start uploading a transaction message into a buffer
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: VaultCreateTransactionBufferArgs)]
+pub struct VaultCreateTransactionBuffer<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = VaultTransactionBuffer::size(args.final_buffer_size),
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            creator.key().as_ref(),
+            &args.buffer_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub transaction_buffer: Account<'info, VaultTransactionBuffer>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Founders and members can upload the messages of the transactions they create
+    #[account(
+        mut,
+        constraint = (vault.is_founder(&creator.key()) || vault.is_member(&creator.key())) @ VaultError::Unauthorized
+    )]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Arguments required to create a transaction buffer
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultCreateTransactionBufferArgs {
+    pub buffer_index: u8,
+    /// SHA-256 hash of the complete message
+    pub final_buffer_hash: [u8; 32],
+    pub final_buffer_size: u16,
+    /// First chunk of the message
+    pub buffer: Vec<u8>,
+}
+
+/// Creates a transaction buffer sized for the complete message and writes its first chunk
+pub fn create(
+    ctx: Context<VaultCreateTransactionBuffer>,
+    args: VaultCreateTransactionBufferArgs,
+) -> Result<()> {
+    let VaultCreateTransactionBufferArgs {
+        buffer_index,
+        final_buffer_hash,
+        final_buffer_size,
+        buffer,
+    } = args;
+
+    let VaultCreateTransactionBuffer {
+        transaction_buffer,
+        vault,
+        creator,
+        ..
+    } = ctx.accounts;
+
+    transaction_buffer.set_inner(VaultTransactionBuffer {
+        vault: vault.key(),
+        creator: creator.key(),
+        buffer_index,
+        bump: ctx.bumps.transaction_buffer,
+        final_buffer_hash,
+        final_buffer_size,
+        buffer,
+    });
+    transaction_buffer.validate_size()?;
+
+    emit_cpi!(TransactionBufferCreatedEvent {
+        vault: vault.key(),
+        actor: creator.key(),
+        transaction_index: vault.transaction_index,
+        transaction_buffer: transaction_buffer.key(),
+        buffer_index,
+        final_buffer_size,
+    });
+
+    Ok(())
+}
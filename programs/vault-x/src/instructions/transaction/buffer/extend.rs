@@ -0,0 +1,64 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to append a chunk to a transaction buffer
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultExtendTransactionBuffer<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            creator.key().as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes(),
+        ],
+        bump = transaction_buffer.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        has_one = creator @ VaultError::Unauthorized
+    )]
+    pub transaction_buffer: Account<'info, VaultTransactionBuffer>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub creator: Signer<'info>,
+}
+
+/// Arguments required to extend a transaction buffer
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultExtendTransactionBufferArgs {
+    /// Next chunk of the message
+    pub buffer: Vec<u8>,
+}
+
+/// Appends a chunk to a transaction buffer, up to the announced final size
+pub fn extend(
+    ctx: Context<VaultExtendTransactionBuffer>,
+    args: VaultExtendTransactionBufferArgs,
+) -> Result<()> {
+    let transaction_buffer = &mut ctx.accounts.transaction_buffer;
+
+    transaction_buffer.buffer.extend_from_slice(&args.buffer);
+    transaction_buffer.validate_size()?;
+
+    emit_cpi!(TransactionBufferExtendedEvent {
+        vault: ctx.accounts.vault.key(),
+        actor: ctx.accounts.creator.key(),
+        transaction_index: ctx.accounts.vault.transaction_index,
+        transaction_buffer: transaction_buffer.key(),
+        buffer_size: u16::try_from(transaction_buffer.buffer.len()).unwrap(),
+    });
+
+    Ok(())
+}
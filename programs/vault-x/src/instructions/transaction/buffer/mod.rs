@@ -0,0 +1,7 @@
+pub mod close;
+pub mod create;
+pub mod extend;
+
+pub use close::*;
+pub use create::*;
+pub use extend::*;
@@ -0,0 +1,122 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, Discriminator};
+
+/// Accounts required to close a transaction created before the vault was migrated
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultCloseLegacyTransaction<'info> {
+    /// CHECK: A legacy founder or member transaction of the vault, which no longer deserializes
+    /// with the current layout. It is checked in the instruction.
+    #[account(
+        mut,
+        constraint = transaction.owner.eq(&id()) @ VaultError::InvalidAccount
+    )]
+    pub transaction: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The creator of the transaction, who paid for its rent and receives it back
+    #[account(mut)]
+    pub creator: SystemAccount<'info>,
+}
+
+/// Closes a founder or member transaction created before the vault was migrated, refunding the rent
+/// to its creator. Legacy transactions can't be voted on or executed anymore, whatever their status.
+pub fn close_legacy_transaction(ctx: Context<VaultCloseLegacyTransaction>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let vault_key = vault.key();
+    let transaction_info = ctx.accounts.transaction.to_account_info();
+    let creator_info = ctx.accounts.creator.to_account_info();
+
+    // The discriminator tells founder and member transactions apart
+    let (creator, transaction_vault, transaction_index, bump, seed, kind) = {
+        let transaction_data = transaction_info.try_borrow_data()?;
+        let discriminator = transaction_data
+            .get(..8)
+            .ok_or(VaultError::InvalidTransactionAccount)?;
+        let mut data = &transaction_data[8..];
+
+        if discriminator == VaultFounderTransaction::DISCRIMINATOR {
+            let transaction = VaultFounderTransactionLegacy::deserialize(&mut data)?;
+            (
+                transaction.creator,
+                transaction.vault,
+                transaction.transaction_index,
+                transaction.bump,
+                SEED_FOUNDER_TRANSACTION,
+                TransactionKind::Founder,
+            )
+        } else if discriminator == VaultMemberTransaction::DISCRIMINATOR {
+            let transaction = VaultMemberTransactionLegacy::deserialize(&mut data)?;
+            (
+                transaction.creator,
+                transaction.vault,
+                transaction.transaction_index,
+                transaction.bump,
+                SEED_MEMBER_TRANSACTION,
+                TransactionKind::Member,
+            )
+        } else {
+            return err!(VaultError::InvalidTransactionAccount);
+        }
+    };
+
+    require_keys_eq!(
+        transaction_vault,
+        vault_key,
+        VaultError::InvalidInstructionAccount
+    );
+
+    // Only the transactions created before the migration use the legacy layout
+    require!(
+        transaction_index <= vault.legacy_transaction_index,
+        VaultError::TransactionNotLegacy
+    );
+
+    let transaction_key = Pubkey::create_program_address(
+        &[
+            SEED_PREFIX,
+            vault_key.as_ref(),
+            seed,
+            &transaction_index.to_le_bytes(),
+            &[bump],
+        ],
+        &id(),
+    )
+    .map_err(|_| VaultError::InvalidAccount)?;
+    require_keys_eq!(
+        transaction_key,
+        transaction_info.key(),
+        VaultError::InvalidAccount
+    );
+    require_keys_eq!(creator, creator_info.key(), VaultError::InvalidAccount);
+
+    // Close the account the way Anchor's `close` constraint does
+    let lamports = transaction_info.lamports();
+    **creator_info.try_borrow_mut_lamports()? =
+        creator_info.lamports().checked_add(lamports).unwrap();
+    **transaction_info.try_borrow_mut_lamports()? = 0;
+    transaction_info.assign(&system_program::ID);
+    transaction_info.realloc(0, false)?;
+
+    emit_cpi!(TransactionClosedEvent {
+        vault: vault_key,
+        actor: creator,
+        transaction_index,
+        kind,
+        transaction: transaction_key,
+    });
+
+    Ok(())
+}
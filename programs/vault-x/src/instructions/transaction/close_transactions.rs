@@ -0,0 +1,113 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// Accounts required to close many transactions at once.
+/// The transactions are passed in `remaining_accounts` as `[transaction, creator]` pairs.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultCloseTransactions<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+/// Closes a batch of founder and member transactions, refunding the rent to their creators.
+/// Founder transactions must be closable, member transactions must be closable or signed off by their creator.
+pub fn close_transactions<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VaultCloseTransactions<'info>>,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let vault_key = vault.key();
+    let transaction_account_infos = ctx.remaining_accounts;
+
+    let transaction_pairs = transaction_account_infos.chunks_exact(2);
+
+    require!(
+        !transaction_account_infos.is_empty() && transaction_pairs.remainder().is_empty(),
+        VaultError::InvalidNumberOfAccounts
+    );
+
+    for account_infos in transaction_pairs {
+        let transaction_info = &account_infos[0];
+        let creator_info = &account_infos[1];
+
+        require!(
+            transaction_info.owner.eq(&id()) && transaction_info.is_writable,
+            VaultError::InvalidAccount
+        );
+        require!(creator_info.is_writable, VaultError::InvalidAccount);
+
+        // The discriminator tells founder and member transactions apart
+        let discriminator = transaction_info
+            .try_borrow_data()?
+            .get(..8)
+            .map(|discriminator| discriminator == VaultFounderTransaction::DISCRIMINATOR);
+
+        let (creator, transaction_index, kind) = if discriminator == Some(true) {
+            let transaction = Account::<VaultFounderTransaction>::try_from(transaction_info)?;
+            require_keys_eq!(
+                transaction.vault,
+                vault_key,
+                VaultError::InvalidInstructionAccount
+            );
+            require!(
+                transaction.is_closable(vault),
+                VaultError::InvalidTransactionStatus
+            );
+            require_keys_eq!(
+                transaction.creator,
+                creator_info.key(),
+                VaultError::InvalidAccount
+            );
+            let closed = (
+                transaction.creator,
+                transaction.transaction_index,
+                TransactionKind::Founder,
+            );
+            transaction.close(creator_info.clone())?;
+            closed
+        } else {
+            let transaction = Account::<VaultMemberTransaction>::try_from(transaction_info)?;
+            require_keys_eq!(
+                transaction.vault,
+                vault_key,
+                VaultError::InvalidInstructionAccount
+            );
+            require!(
+                transaction.is_closable(vault) || creator_info.is_signer,
+                VaultError::InvalidTransactionStatus
+            );
+            require_keys_eq!(
+                transaction.creator,
+                creator_info.key(),
+                VaultError::InvalidAccount
+            );
+            let closed = (
+                transaction.creator,
+                transaction.transaction_index,
+                TransactionKind::Member,
+            );
+            transaction.close(creator_info.clone())?;
+            closed
+        };
+
+        emit_cpi!(TransactionClosedEvent {
+            vault: vault_key,
+            actor: creator,
+            transaction_index,
+            kind,
+            transaction: transaction_info.key(),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to close a committee transaction
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultCommitteeCloseTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            committee.key().as_ref(),
+            SEED_COMMITTEE_TRANSACTION,
+            &transaction.transaction_index.to_le_bytes(),
+        ],
+        bump = transaction.bump,
+        has_one = committee @ VaultError::InvalidInstructionAccount,
+        has_one = creator @ VaultError::InvalidAccount,
+        constraint = transaction.is_closable() || committee.data_is_empty() @ VaultError::InvalidTransactionStatus,
+        close = creator
+    )]
+    pub transaction: Account<'info, VaultCommitteeTransaction>,
+
+    /// CHECK: Only used for the transaction seeds, pending transactions of a dissolved committee can be closed too
+    pub committee: UncheckedAccount<'info>,
+
+    /// The creator of the transaction, who paid for its rent and receives it back
+    #[account(mut)]
+    pub creator: SystemAccount<'info>,
+}
+
+/// Closes a terminal committee transaction, or any transaction of a dissolved committee, refunding the rent to its creator
+pub fn close(ctx: Context<VaultCommitteeCloseTransaction>) -> Result<()> {
+    let transaction = &ctx.accounts.transaction;
+
+    emit_cpi!(TransactionClosedEvent {
+        vault: transaction.vault,
+        actor: ctx.accounts.creator.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Committee,
+        transaction: transaction.key(),
+    });
+
+    Ok(())
+}
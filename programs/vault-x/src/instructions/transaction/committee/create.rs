@@ -0,0 +1,140 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to create a committee transaction
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: VaultCommitteeCreateTransactionArgs)]
+pub struct VaultCommitteeCreateTransaction<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = VaultCommitteeTransaction::size(args.ephemeral_signers, &args.transaction_message, committee.members.len())?,
+        seeds = [
+            SEED_PREFIX,
+            committee.key().as_ref(),
+            SEED_COMMITTEE_TRANSACTION,
+            &committee.transaction_index.checked_add(1).unwrap().to_le_bytes(),
+        ],
+        bump
+    )]
+    pub transaction: Account<'info, VaultCommitteeTransaction>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_COMMITTEE,
+            &committee.committee_index.to_le_bytes(),
+        ],
+        bump = committee.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+    )]
+    pub committee: Account<'info, VaultCommittee>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = committee.is_member(&creator.key()) @ VaultError::CommitteeMemberNotFound,
+        constraint = vault.is_member(&creator.key()) @ VaultError::MemberNotFound
+    )]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a committee transaction in the Vault
+pub fn create(
+    ctx: Context<VaultCommitteeCreateTransaction>,
+    args: VaultCommitteeCreateTransactionArgs,
+) -> Result<()> {
+    let VaultCommitteeCreateTransactionArgs {
+        transaction_message,
+        ephemeral_signers,
+    } = args;
+
+    let VaultCommitteeCreateTransaction {
+        vault,
+        committee,
+        transaction,
+        creator,
+        ..
+    } = ctx.accounts;
+
+    // Validate and deserialize the transaction message
+    let transaction_message = match VaultTransactionMessage::try_from_bytes(&transaction_message) {
+        Ok(transaction_message) => transaction_message,
+        Err(_) => return err!(VaultError::InvalidVaultTransactionMessage),
+    };
+    transaction_message.is_valid()?;
+
+    // Retrieve keys for committee and transaction
+    let committee_key = committee.key();
+    let transaction_key = transaction.key();
+
+    // Generate ephemeral signer bumps
+    let ephemeral_signer_bumps: Vec<u8> = (0..ephemeral_signers)
+        .map(|ephemeral_signer_index| {
+            let (_, ephemeral_signer_bump) = Pubkey::find_program_address(
+                &[
+                    SEED_PREFIX,
+                    transaction_key.as_ref(),
+                    SEED_EPHEMERAL_SIGNER,
+                    &ephemeral_signer_index.to_le_bytes(),
+                ],
+                &id(),
+            );
+
+            ephemeral_signer_bump
+        })
+        .collect();
+
+    // Increment transaction index for this committee
+    let transaction_index = committee.transaction_index.checked_add(1).unwrap();
+    committee.transaction_index = transaction_index;
+
+    // Set the transaction fields
+    transaction.set_inner(VaultCommitteeTransaction {
+        creator: creator.key(),
+        vault: vault.key(),
+        committee: committee_key,
+        transaction_index,
+        status: VaultTransactionStatus::Active,
+        bump: ctx.bumps.transaction,
+        fund_bump: committee.fund_bump,
+        ephemeral_signer_bumps,
+        message: transaction_message,
+        approved: Vec::new(),
+        rejected: Vec::new(),
+        cancelled: Vec::new(),
+    });
+
+    emit_cpi!(TransactionCreatedEvent {
+        vault: vault.key(),
+        actor: creator.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Committee,
+        transaction: transaction.key(),
+    });
+
+    Ok(())
+}
+
+/// Arguments required to create a committee transaction
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultCommitteeCreateTransactionArgs {
+    pub ephemeral_signers: u8,
+    pub transaction_message: Vec<u8>,
+}
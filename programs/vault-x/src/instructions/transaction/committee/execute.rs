@@ -0,0 +1,118 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for a committee member to execute a transaction
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultCommitteeExecuteTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            committee.key().as_ref(),
+            SEED_COMMITTEE_TRANSACTION,
+            &transaction.transaction_index.to_le_bytes(),
+        ],
+        bump = transaction.bump,
+        has_one = committee @ VaultError::InvalidInstructionAccount,
+        constraint = transaction.status.eq(&VaultTransactionStatus::Approved) @ VaultError::InvalidTransactionStatus
+    )]
+    pub transaction: Account<'info, VaultCommitteeTransaction>,
+
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_COMMITTEE,
+            &committee.committee_index.to_le_bytes(),
+        ],
+        bump = committee.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+    )]
+    pub committee: Account<'info, VaultCommittee>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = committee.is_member(&member.key()) @ VaultError::CommitteeMemberNotFound,
+        constraint = vault.is_member(&member.key()) @ VaultError::MemberNotFound
+    )]
+    pub member: Signer<'info>,
+}
+
+/// Executes an approved committee transaction, signing with the committee's fund
+pub fn execute(ctx: Context<VaultCommitteeExecuteTransaction>) -> Result<()> {
+    let transaction = &mut ctx.accounts.transaction;
+    let vault_key = ctx.accounts.vault.key();
+    let committee_key = ctx.accounts.committee.key();
+
+    let fund_bump = transaction.fund_bump;
+    let fund_seeds = &[SEED_PREFIX, committee_key.as_ref(), SEED_FUND, &[fund_bump]];
+    let fund_key = Pubkey::create_program_address(fund_seeds, &id()).unwrap();
+
+    // Generate keys and seeds for ephemeral signers
+    let transaction_key = transaction.key();
+    let (ephemeral_signer_keys, ephemeral_signer_seeds) =
+        fetch_ephemeral_keys(&transaction.ephemeral_signer_bumps, &transaction.key());
+
+    let transaction_message = &transaction.message;
+
+    // Validate and sanitize instructions and account infos
+    // The address lookup tables come first in the remaining accounts, followed by the message accounts
+    let transaction_account_infos = transaction_message.validate_message_account_infos(
+        ctx.remaining_accounts,
+        &fund_key,
+        &ephemeral_signer_keys,
+    )?;
+
+    // Protect specific accounts from writable access during execution
+    let protected_accounts = &[vault_key, committee_key, transaction_key];
+
+    let instructions_with_ordered_account_infos = transaction_message
+        .fetch_instructions_with_ordered_account_infos(
+            transaction_account_infos,
+            protected_accounts,
+        )?;
+
+    // Execute each instruction with the appropriate signers
+    let receipt = invoke_transaction_instructions(
+        &instructions_with_ordered_account_infos,
+        &ephemeral_signer_seeds,
+        fund_seeds,
+        &fund_key,
+    )?;
+
+    // Mark the transaction as executed
+    transaction.status = VaultTransactionStatus::Executed;
+
+    emit_cpi!(ExecutionReceiptEvent {
+        vault: vault_key,
+        actor: ctx.accounts.member.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Committee,
+        transaction: Some(transaction.key()),
+        receipt,
+    });
+
+    emit_cpi!(TransactionExecutedEvent {
+        vault: vault_key,
+        actor: ctx.accounts.member.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Committee,
+        transaction: transaction.key(),
+    });
+
+    Ok(())
+}
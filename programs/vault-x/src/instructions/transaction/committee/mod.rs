@@ -0,0 +1,13 @@
+pub mod approve;
+pub mod cancel;
+pub mod close;
+pub mod create;
+pub mod execute;
+pub mod reject;
+
+pub use approve::*;
+pub use cancel::*;
+pub use close::*;
+pub use create::*;
+pub use execute::*;
+pub use reject::*;
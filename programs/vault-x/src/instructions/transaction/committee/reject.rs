@@ -0,0 +1,84 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for a committee member to reject a transaction
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultCommitteeRejectTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            committee.key().as_ref(),
+            SEED_COMMITTEE_TRANSACTION,
+            &transaction.transaction_index.to_le_bytes(),
+        ],
+        bump = transaction.bump,
+        has_one = committee @ VaultError::InvalidInstructionAccount,
+        constraint = transaction.status.eq(&VaultTransactionStatus::Active) @ VaultError::InvalidTransactionStatus
+    )]
+    pub transaction: Account<'info, VaultCommitteeTransaction>,
+
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_COMMITTEE,
+            &committee.committee_index.to_le_bytes(),
+        ],
+        bump = committee.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+    )]
+    pub committee: Account<'info, VaultCommittee>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = committee.is_member(&member.key()) @ VaultError::CommitteeMemberNotFound,
+        constraint = vault.is_member(&member.key()) @ VaultError::MemberNotFound
+    )]
+    pub member: Signer<'info>,
+}
+
+/// Rejects a committee transaction, moving it to the "Rejected" state if enough rejections are collected
+pub fn reject(ctx: Context<VaultCommitteeRejectTransaction>) -> Result<()> {
+    let member = &ctx.accounts.member;
+    let transaction = &mut ctx.accounts.transaction;
+    let committee: &VaultCommittee = &ctx.accounts.committee;
+
+    let status = transaction.status.clone();
+    transaction.reject(member.key(), committee)?;
+
+    emit_cpi!(TransactionVoteCastEvent {
+        vault: ctx.accounts.vault.key(),
+        actor: member.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Committee,
+        transaction: transaction.key(),
+        vote: TransactionVote::Reject,
+    });
+
+    if transaction.status.ne(&status) {
+        emit_cpi!(TransactionStatusChangedEvent {
+            vault: ctx.accounts.vault.key(),
+            actor: member.key(),
+            transaction_index: transaction.transaction_index,
+            kind: TransactionKind::Committee,
+            transaction: transaction.key(),
+            status: transaction.status.clone(),
+        });
+    }
+
+    Ok(())
+}
@@ -1,10 +1,12 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::id;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 /// Accounts required for a founder to approve a transaction
+#[event_cpi]
 #[derive(Accounts)]
 pub struct VaultFounderApproveTransaction<'info> {
     #[account(
@@ -22,9 +24,11 @@ pub struct VaultFounderApproveTransaction<'info> {
     pub transaction: Account<'info, VaultFounderTransaction>,
 
     #[account(
+        mut,
         seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
         bump = vault.bump,
-        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
     )]
     pub vault: Account<'info, Vault>,
 
@@ -39,27 +43,36 @@ pub struct VaultFounderApproveTransaction<'info> {
 pub fn approve(ctx: Context<VaultFounderApproveTransaction>) -> Result<()> {
     let founder = &ctx.accounts.founder;
     let transaction = &mut ctx.accounts.transaction;
-    let vault = &ctx.accounts.vault;
+    let vault: &Vault = &ctx.accounts.vault;
+
+    let status = transaction.status.clone();
+    transaction.approve(founder.key(), vault)?;
 
-    // Remove founder from the rejected list if previously rejected
-    if let Ok(rejected_index) = transaction.rejected.binary_search(founder.key) {
-        transaction.rejected.remove(rejected_index);
+    // Start the time lock once the transaction is approved
+    if transaction.status.eq(&VaultTransactionStatus::Approved) {
+        transaction.start_time_lock(vault, Clock::get()?.unix_timestamp);
     }
 
-    // Insert founder into the approved list, or return an error if already approved
-    match transaction.approved.binary_search(founder.key) {
-        Ok(_) => return err!(VaultError::AlreadyApproved),
-        Err(index) => transaction.approved.insert(index, founder.key()),
-    };
+    ctx.accounts.vault.record_founder_activity()?;
+
+    emit_cpi!(TransactionVoteCastEvent {
+        vault: ctx.accounts.vault.key(),
+        actor: founder.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Founder,
+        transaction: transaction.key(),
+        vote: TransactionVote::Approve,
+    });
 
-    // Move transaction to the "Approved" state if approval threshold is reached
-    if transaction
-        .approved
-        .len()
-        .ge(&vault.founder_threshold.into())
-    {
-        msg!("Approval threshold reached");
-        transaction.status = VaultTransactionStatus::Approved;
+    if transaction.status.ne(&status) {
+        emit_cpi!(TransactionStatusChangedEvent {
+            vault: ctx.accounts.vault.key(),
+            actor: founder.key(),
+            transaction_index: transaction.transaction_index,
+            kind: TransactionKind::Founder,
+            transaction: transaction.key(),
+            status: transaction.status.clone(),
+        });
     }
 
     Ok(())
@@ -1,10 +1,12 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::id;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 /// Accounts required for a founder to cancel a transaction
+#[event_cpi]
 #[derive(Accounts)]
 pub struct VaultFounderCancelTransaction<'info> {
     #[account(
@@ -17,14 +19,17 @@ pub struct VaultFounderCancelTransaction<'info> {
         ],
         bump = transaction.bump,
         has_one = vault @ VaultError::InvalidInstructionAccount,
-        constraint = transaction.is_transaction_valid(&VaultTransactionStatus::Approved, &vault) @ VaultError::InvalidTransactionStatus
+        constraint = transaction.is_transaction_valid(&VaultTransactionStatus::Approved, &vault)
+            || transaction.is_transaction_valid(&VaultTransactionStatus::ExecutingPartial, &vault) @ VaultError::InvalidTransactionStatus
     )]
     pub transaction: Account<'info, VaultFounderTransaction>,
 
     #[account(
+        mut,
         seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
         bump = vault.bump,
-        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
     )]
     pub vault: Account<'info, Vault>,
 
@@ -35,26 +40,37 @@ pub struct VaultFounderCancelTransaction<'info> {
     pub founder: Signer<'info>,
 }
 
-/// Cancels a founder transaction if enough cancellations are collected
+/// Cancels a founder transaction if enough cancellations are collected.
+/// A partially executed transaction whose next instruction keeps failing is abandoned this way,
+/// leaving the instructions already executed in place.
 pub fn cancel(ctx: Context<VaultFounderCancelTransaction>) -> Result<()> {
     let founder = &ctx.accounts.founder;
     let transaction = &mut ctx.accounts.transaction;
-    let vault = &ctx.accounts.vault;
+    let vault: &Vault = &ctx.accounts.vault;
 
-    // Insert founder into the cancelled list, or return an error if already cancelled
-    match transaction.cancelled.binary_search(founder.key) {
-        Ok(_) => return err!(VaultError::AlreadyCancelled),
-        Err(cancelled_index) => transaction.cancelled.insert(cancelled_index, founder.key()),
-    }
+    let status = transaction.status.clone();
+    transaction.cancel(founder.key(), vault)?;
+
+    ctx.accounts.vault.record_founder_activity()?;
+
+    emit_cpi!(TransactionVoteCastEvent {
+        vault: ctx.accounts.vault.key(),
+        actor: founder.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Founder,
+        transaction: transaction.key(),
+        vote: TransactionVote::Cancel,
+    });
 
-    // Move transaction to the "Cancelled" state if cancellation threshold is reached
-    if transaction
-        .cancelled
-        .len()
-        .ge(&vault.founder_threshold.into())
-    {
-        msg!("Cancel threshold reached");
-        transaction.status = VaultTransactionStatus::Cancelled;
+    if transaction.status.ne(&status) {
+        emit_cpi!(TransactionStatusChangedEvent {
+            vault: ctx.accounts.vault.key(),
+            actor: founder.key(),
+            transaction_index: transaction.transaction_index,
+            kind: TransactionKind::Founder,
+            transaction: transaction.key(),
+            status: transaction.status.clone(),
+        });
     }
 
     Ok(())
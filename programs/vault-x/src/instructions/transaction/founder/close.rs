@@ -0,0 +1,54 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to close a founder transaction
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultFounderCloseTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_FOUNDER_TRANSACTION,
+            &transaction.transaction_index.to_le_bytes(),
+        ],
+        bump = transaction.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        has_one = creator @ VaultError::InvalidAccount,
+        constraint = transaction.is_closable(&vault) @ VaultError::InvalidTransactionStatus,
+        close = creator
+    )]
+    pub transaction: Account<'info, VaultFounderTransaction>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The creator of the transaction, who paid for its rent and receives it back
+    #[account(mut)]
+    pub creator: SystemAccount<'info>,
+}
+
+/// Closes an executed, rejected, cancelled or stale founder transaction, refunding the rent to its creator
+pub fn close(ctx: Context<VaultFounderCloseTransaction>) -> Result<()> {
+    let transaction = &ctx.accounts.transaction;
+
+    emit_cpi!(TransactionClosedEvent {
+        vault: ctx.accounts.vault.key(),
+        actor: ctx.accounts.creator.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Founder,
+        transaction: transaction.key(),
+    });
+
+    Ok(())
+}
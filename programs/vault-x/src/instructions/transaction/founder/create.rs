@@ -1,17 +1,25 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::id;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 /// Accounts required to create a founder transaction
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: VaultFounderCreateTransactionArgs)]
 pub struct VaultFounderCreateTransaction<'info> {
     #[account(
         init,
         payer = creator,
-        space = VaultFounderTransaction::size(args.ephemeral_signers, &args.transaction_message, vault.founders.len())?,
+        space = VaultFounderTransaction::size(
+            args.ephemeral_signers,
+            transaction_buffer
+                .as_ref()
+                .map_or(&args.transaction_message, |transaction_buffer| &transaction_buffer.buffer),
+            vault.founders.len(),
+        )?,
         seeds = [
             SEED_PREFIX,
             vault.key().as_ref(),
@@ -26,7 +34,8 @@ pub struct VaultFounderCreateTransaction<'info> {
         mut,
         seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
         bump = vault.bump,
-        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
     )]
     pub vault: Account<'info, Vault>,
 
@@ -36,6 +45,23 @@ pub struct VaultFounderCreateTransaction<'info> {
     )]
     pub creator: Signer<'info>,
 
+    /// Buffer holding the transaction message when it is too large to pass inline, closed once used
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            creator.key().as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes(),
+        ],
+        bump = transaction_buffer.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        has_one = creator @ VaultError::Unauthorized,
+        close = creator
+    )]
+    pub transaction_buffer: Option<Account<'info, VaultTransactionBuffer>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -47,23 +73,33 @@ pub fn create(
     let VaultFounderCreateTransactionArgs {
         transaction_message,
         ephemeral_signers,
+        expiry,
     } = args;
 
     let VaultFounderCreateTransaction {
         vault,
         transaction,
         creator,
+        transaction_buffer,
         ..
     } = ctx.accounts;
 
+    // Take the message from the buffer when one is provided
+    let transaction_message = VaultTransactionBuffer::resolve_message(
+        transaction_buffer.as_deref(),
+        transaction_message,
+    )?;
+
     // Validate and deserialize the transaction message
-    let transaction_message =
-        match VaultTransactionMessage::deserialize(&mut transaction_message.as_slice()) {
-            Ok(transaction_message) => transaction_message,
-            Err(_) => return err!(VaultError::InvalidVaultTransactionMessage),
-        };
+    let transaction_message = match VaultTransactionMessage::try_from_bytes(&transaction_message) {
+        Ok(transaction_message) => transaction_message,
+        Err(_) => return err!(VaultError::InvalidVaultTransactionMessage),
+    };
     transaction_message.is_valid()?;
 
+    // Validate the expiry against the vault's maximum transaction lifetime
+    let expiry = vault.resolve_transaction_expiry(expiry, &Clock::get()?)?;
+
     // Retrieve keys for vault and transaction
     let vault_key = vault.key();
     let transaction_key = transaction.key();
@@ -92,6 +128,7 @@ pub fn create(
     // Increment transaction index for this vault
     let transaction_index = vault.transaction_index.checked_add(1).unwrap();
     vault.transaction_index = transaction_index;
+    vault.record_founder_activity()?;
 
     // Set the transaction fields
     transaction.set_inner(VaultFounderTransaction {
@@ -106,8 +143,30 @@ pub fn create(
         approved: Vec::new(),
         rejected: Vec::new(),
         cancelled: Vec::new(),
+        approved_at: 0,
+        executable_at: 0,
+        expiry,
+        escalated_from: None,
+        executed_instructions: 0,
     });
 
+    emit_cpi!(TransactionCreatedEvent {
+        vault: vault.key(),
+        actor: creator.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Founder,
+        transaction: transaction.key(),
+    });
+
+    if let Some(transaction_buffer) = transaction_buffer {
+        emit_cpi!(TransactionBufferClosedEvent {
+            vault: vault.key(),
+            actor: creator.key(),
+            transaction_index: transaction.transaction_index,
+            transaction_buffer: transaction_buffer.key(),
+        });
+    }
+
     Ok(())
 }
 
@@ -115,5 +174,8 @@ pub fn create(
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct VaultFounderCreateTransactionArgs {
     pub ephemeral_signers: u8,
+    /// Serialized transaction message, left empty when the message is uploaded to a buffer
     pub transaction_message: Vec<u8>,
+    /// Optional unix timestamp or slot after which the transaction is invalid
+    pub expiry: Option<VaultTransactionExpiry>,
 }
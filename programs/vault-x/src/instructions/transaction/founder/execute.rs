@@ -1,12 +1,13 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::id;
 use crate::state::*;
 use crate::utils::*;
 use anchor_lang::prelude::*;
-use solana_program::program::invoke_signed;
 
 /// Accounts required for a founder to execute a transaction.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct VaultFounderExecuteTransaction<'info> {
     #[account(
@@ -19,14 +20,19 @@ pub struct VaultFounderExecuteTransaction<'info> {
         ],
         bump = transaction.bump,
         has_one = vault @ VaultError::InvalidInstructionAccount,
-        constraint = transaction.is_transaction_valid(&VaultTransactionStatus::Approved, &vault) @ VaultError::InvalidTransactionStatus
+        constraint = (
+            transaction.is_transaction_valid(&VaultTransactionStatus::Approved, &vault)
+                || transaction.is_transaction_valid(&VaultTransactionStatus::ExecutingPartial, &vault)
+        ) @ VaultError::InvalidTransactionStatus
     )]
     pub transaction: Account<'info, VaultFounderTransaction>,
 
     #[account(
+        mut,
         seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
         bump = vault.bump,
-        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
     )]
     pub vault: Account<'info, Vault>,
 
@@ -37,34 +43,75 @@ pub struct VaultFounderExecuteTransaction<'info> {
     pub founder: Signer<'info>,
 }
 
+/// Arguments required to execute part of a founder transaction
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultFounderExecutePartialArgs {
+    /// Maximum number of instructions to execute in this call
+    pub instruction_count: u16,
+}
+
 /// Executes an approved founder transaction by invoking the associated instructions.
+/// A partially executed transaction resumes from its next instruction.
 pub fn execute(ctx: Context<VaultFounderExecuteTransaction>) -> Result<()> {
+    execute_instructions(ctx, None)
+}
+
+/// Executes the next instructions of an approved founder transaction, for transactions too
+/// compute heavy to execute at once. The transaction is executed after its last instruction.
+pub fn execute_partial(
+    ctx: Context<VaultFounderExecuteTransaction>,
+    args: VaultFounderExecutePartialArgs,
+) -> Result<()> {
+    require!(
+        args.instruction_count > 0,
+        VaultError::InvalidInstructionCount
+    );
+
+    execute_instructions(ctx, Some(args.instruction_count))
+}
+
+/// Executes the instructions from the transaction's cursor, all the remaining ones if no count is given.
+fn execute_instructions(
+    ctx: Context<VaultFounderExecuteTransaction>,
+    instruction_count: Option<u16>,
+) -> Result<()> {
     let transaction = &mut ctx.accounts.transaction;
     let vault = &ctx.accounts.vault;
     let vault_key = vault.key();
 
+    // Founders can still cancel the transaction until the time lock elapses.
+    require!(
+        transaction.is_time_lock_elapsed(Clock::get()?.unix_timestamp),
+        VaultError::TimeLockNotElapsed
+    );
+
     let fund_bump = transaction.fund_bump;
     let fund_seeds = &[SEED_PREFIX, vault_key.as_ref(), SEED_FUND, &[fund_bump]];
     let fund_key = Pubkey::create_program_address(fund_seeds, &id()).unwrap();
 
     // Generate keys and seeds for ephemeral signers.
     let transaction_key = transaction.key();
-    let (ephemeral_signer_keys, ephemeral_signer_seeds) =
+    let (mut ephemeral_signer_keys, mut ephemeral_signer_seeds) =
         fetch_ephemeral_keys(&transaction.ephemeral_signer_bumps, &transaction.key());
 
-    // Remaining accounts for the transaction.
-    let transaction_account_infos = ctx.remaining_accounts;
+    // Founder transactions also sign as the founder authority, signed the same way as ephemeral signers.
+    let (founder_authority_key, founder_authority_seeds) = fetch_founder_authority(&vault_key);
+    ephemeral_signer_keys.push(founder_authority_key);
+    ephemeral_signer_seeds.push(founder_authority_seeds);
+
     let transaction_message = &transaction.message;
 
     // Validate and sanitize instructions and account infos.
-    transaction_message.validate_message_account_infos(
-        transaction_account_infos,
+    // The address lookup tables come first in the remaining accounts, followed by the message accounts.
+    let transaction_account_infos = transaction_message.validate_message_account_infos(
+        ctx.remaining_accounts,
         &fund_key,
         &ephemeral_signer_keys,
     )?;
 
     // Protect specific accounts from writable access during execution.
-    let protected_accounts = &[vault_key, transaction_key];
+    let protected_accounts =
+        &VaultFounderTransaction::protected_accounts(&vault_key, &transaction_key);
 
     let instructions_with_ordered_account_infos = transaction_message
         .fetch_instructions_with_ordered_account_infos(
@@ -72,31 +119,69 @@ pub fn execute(ctx: Context<VaultFounderExecuteTransaction>) -> Result<()> {
             protected_accounts,
         )?;
 
+    // Resume from the cursor and stop after the requested number of instructions.
+    let instructions_len = instructions_with_ordered_account_infos.len();
+    let start = usize::from(transaction.executed_instructions);
+    let end = instruction_count.map_or(instructions_len, |instruction_count| {
+        instructions_len.min(start.saturating_add(usize::from(instruction_count)))
+    });
+
     // Execute each instruction with the appropriate signers.
-    for (instruction, account_infos) in instructions_with_ordered_account_infos.iter() {
-        let ephemeral_signer_seeds_slice = ephemeral_signer_seeds
-            .iter()
-            .map(|second_layer| {
-                second_layer
-                    .iter()
-                    .map(|third_layer| third_layer.as_slice())
-                    .collect::<Vec<&[u8]>>()
-            })
-            .collect::<Vec<Vec<&[u8]>>>();
-
-        let mut ephemeral_signer_seeds_int_slice = ephemeral_signer_seeds_slice
-            .iter()
-            .map(Vec::as_slice)
-            .collect::<Vec<&[&[u8]]>>();
-
-        ephemeral_signer_seeds_int_slice.push(fund_seeds);
-        let signers_seeds = ephemeral_signer_seeds_int_slice.as_slice();
-
-        invoke_signed(instruction, account_infos, signers_seeds)?;
+    let mut receipt = invoke_transaction_instructions(
+        &instructions_with_ordered_account_infos[start..end],
+        &ephemeral_signer_seeds,
+        fund_seeds,
+        &fund_key,
+    )?;
+
+    // Index the returned data by the instruction's position in the message.
+    for return_data in receipt.return_data.iter_mut() {
+        return_data.instruction_index += u16::try_from(start).unwrap();
+    }
+
+    // Move the cursor, marking the transaction as executed after its last instruction.
+    transaction.executed_instructions = u16::try_from(end).unwrap();
+
+    ctx.accounts.vault.record_founder_activity()?;
+
+    emit_cpi!(ExecutionReceiptEvent {
+        vault: vault_key,
+        actor: ctx.accounts.founder.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Founder,
+        transaction: Some(transaction.key()),
+        receipt,
+    });
+
+    if end < instructions_len {
+        if transaction
+            .status
+            .ne(&VaultTransactionStatus::ExecutingPartial)
+        {
+            transaction.status = VaultTransactionStatus::ExecutingPartial;
+
+            emit_cpi!(TransactionStatusChangedEvent {
+                vault: vault_key,
+                actor: ctx.accounts.founder.key(),
+                transaction_index: transaction.transaction_index,
+                kind: TransactionKind::Founder,
+                transaction: transaction.key(),
+                status: transaction.status.clone(),
+            });
+        }
+
+        return Ok(());
     }
 
-    // Mark the transaction as executed.
     transaction.status = VaultTransactionStatus::Executed;
 
+    emit_cpi!(TransactionExecutedEvent {
+        vault: vault_key,
+        actor: ctx.accounts.founder.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Founder,
+        transaction: transaction.key(),
+    });
+
     Ok(())
 }
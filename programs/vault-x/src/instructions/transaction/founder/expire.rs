@@ -0,0 +1,58 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to mark a founder transaction as expired
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultFounderExpireTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_FOUNDER_TRANSACTION,
+            &transaction.transaction_index.to_le_bytes(),
+        ],
+        bump = transaction.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        constraint = matches!(
+            transaction.status,
+            VaultTransactionStatus::Active | VaultTransactionStatus::Approved
+        ) @ VaultError::InvalidTransactionStatus,
+        constraint = transaction.is_expired() @ VaultError::InvalidTransactionExpiry
+    )]
+    pub transaction: Account<'info, VaultFounderTransaction>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Whoever expires the transaction, only reported in the event. It doesn't need to sign
+    /// since expiring is permissionless.
+    pub caller: UncheckedAccount<'info>,
+}
+
+/// Moves an active or approved founder transaction past its expiry to the "Expired" state
+pub fn expire(ctx: Context<VaultFounderExpireTransaction>) -> Result<()> {
+    let transaction = &mut ctx.accounts.transaction;
+    transaction.status = VaultTransactionStatus::Expired;
+
+    emit_cpi!(TransactionStatusChangedEvent {
+        vault: ctx.accounts.vault.key(),
+        actor: ctx.accounts.caller.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Founder,
+        transaction: transaction.key(),
+        status: transaction.status.clone(),
+    });
+
+    Ok(())
+}
@@ -1,11 +1,17 @@
 pub mod approve;
 pub mod cancel;
+pub mod close;
 pub mod create;
 pub mod execute;
+pub mod expire;
 pub mod reject;
+pub mod veto;
 
 pub use approve::*;
 pub use cancel::*;
+pub use close::*;
 pub use create::*;
 pub use execute::*;
+pub use expire::*;
 pub use reject::*;
+pub use veto::*;
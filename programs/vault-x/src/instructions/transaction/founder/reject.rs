@@ -1,10 +1,12 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::id;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 /// Accounts required for a founder to reject a transaction
+#[event_cpi]
 #[derive(Accounts)]
 pub struct VaultFounderRejectTransaction<'info> {
     #[account(
@@ -22,9 +24,11 @@ pub struct VaultFounderRejectTransaction<'info> {
     pub transaction: Account<'info, VaultFounderTransaction>,
 
     #[account(
+        mut,
         seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
         bump = vault.bump,
-        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
     )]
     pub vault: Account<'info, Vault>,
 
@@ -39,29 +43,31 @@ pub struct VaultFounderRejectTransaction<'info> {
 pub fn reject(ctx: Context<VaultFounderRejectTransaction>) -> Result<()> {
     let founder = &ctx.accounts.founder;
     let transaction = &mut ctx.accounts.transaction;
-    let vault = &ctx.accounts.vault;
+    let vault: &Vault = &ctx.accounts.vault;
 
-    // Remove founder from the approved list if previously approved
-    if let Ok(approved_index) = transaction.approved.binary_search(founder.key) {
-        transaction.approved.remove(approved_index);
-    }
+    let status = transaction.status.clone();
+    transaction.reject(founder.key(), vault)?;
 
-    // Insert founder into the rejected list, or return an error if already rejected
-    match transaction.rejected.binary_search(founder.key) {
-        Ok(_) => return err!(VaultError::AlreadyApproved),
-        Err(index) => transaction.rejected.insert(index, founder.key()),
-    };
+    ctx.accounts.vault.record_founder_activity()?;
 
-    // Move transaction to the "Rejected" state if rejection threshold is reached
-    let vault_cutoff = vault
-        .founders
-        .len()
-        .checked_sub(vault.founder_threshold.into())
-        .unwrap();
+    emit_cpi!(TransactionVoteCastEvent {
+        vault: ctx.accounts.vault.key(),
+        actor: founder.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Founder,
+        transaction: transaction.key(),
+        vote: TransactionVote::Reject,
+    });
 
-    if transaction.rejected.len().ge(&vault_cutoff) {
-        msg!("Reject threshold reached");
-        transaction.status = VaultTransactionStatus::Rejected;
+    if transaction.status.ne(&status) {
+        emit_cpi!(TransactionStatusChangedEvent {
+            vault: ctx.accounts.vault.key(),
+            actor: founder.key(),
+            transaction_index: transaction.transaction_index,
+            kind: TransactionKind::Founder,
+            transaction: transaction.key(),
+            status: transaction.status.clone(),
+        });
     }
 
     Ok(())
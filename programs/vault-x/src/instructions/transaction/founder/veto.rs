@@ -0,0 +1,77 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for guardians to veto a founder transaction.
+/// Guardians co-signing the veto are passed in `remaining_accounts` as signers.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultFounderVetoTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_FOUNDER_TRANSACTION,
+            &transaction.transaction_index.to_le_bytes(),
+        ],
+        bump = transaction.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        constraint = transaction.status.eq(&VaultTransactionStatus::Approved) @ VaultError::InvalidTransactionStatus
+    )]
+    pub transaction: Account<'info, VaultFounderTransaction>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [SEED_PREFIX, vault.key().as_ref(), SEED_VETO_COUNCIL],
+        bump = veto_council.bump,
+        has_one = vault @ VaultError::InvalidAccount,
+        constraint = veto_council.is_guardian(&guardian.key()) @ VaultError::GuardianNotFound
+    )]
+    pub veto_council: Account<'info, VaultVetoCouncil>,
+
+    pub guardian: Signer<'info>,
+}
+
+/// Vetoes an approved founder transaction whose time lock has not elapsed,
+/// once the council's guardian threshold has signed
+pub fn veto(ctx: Context<VaultFounderVetoTransaction>) -> Result<()> {
+    let transaction = &mut ctx.accounts.transaction;
+    let vault = &ctx.accounts.vault;
+    let veto_council = &ctx.accounts.veto_council;
+
+    require!(
+        !transaction.is_time_lock_elapsed(Clock::get()?.unix_timestamp),
+        VaultError::VetoWindowElapsed
+    );
+    require!(
+        !transaction.is_veto_council_replacement(),
+        VaultError::VetoCouncilReplacement
+    );
+
+    veto_council.check_veto_signers(&ctx.accounts.guardian.key(), ctx.remaining_accounts)?;
+
+    transaction.status = VaultTransactionStatus::Vetoed;
+    msg!("Transaction vetoed");
+
+    emit_cpi!(TransactionStatusChangedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.guardian.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Founder,
+        transaction: transaction.key(),
+        status: transaction.status.clone(),
+    });
+
+    Ok(())
+}
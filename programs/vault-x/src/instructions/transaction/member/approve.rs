@@ -0,0 +1,72 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for a member to approve a transaction
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultMemberApproveTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_MEMBER_TRANSACTION,
+            &transaction.transaction_index.to_le_bytes(),
+        ],
+        bump = transaction.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        constraint = transaction.is_transaction_valid(&VaultTransactionStatus::Active, &vault) @ VaultError::InvalidTransactionStatus
+    )]
+    pub transaction: Account<'info, VaultMemberTransaction>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated,
+        constraint = vault.is_member_voting_enabled() @ VaultError::MemberVotingDisabled
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = vault.is_member(&member.key()) @ VaultError::MemberNotFound
+    )]
+    pub member: Signer<'info>,
+}
+
+/// Approves a member transaction, moving it to the "Approved" state if enough approvals are collected
+pub fn approve(ctx: Context<VaultMemberApproveTransaction>) -> Result<()> {
+    let member = &ctx.accounts.member;
+    let transaction = &mut ctx.accounts.transaction;
+    let vault = &ctx.accounts.vault;
+
+    let status = transaction.status.clone();
+    transaction.approve(member.key(), &vault.member_voters())?;
+
+    emit_cpi!(TransactionVoteCastEvent {
+        vault: vault.key(),
+        actor: member.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Member,
+        transaction: transaction.key(),
+        vote: TransactionVote::Approve,
+    });
+
+    if transaction.status.ne(&status) {
+        emit_cpi!(TransactionStatusChangedEvent {
+            vault: vault.key(),
+            actor: member.key(),
+            transaction_index: transaction.transaction_index,
+            kind: TransactionKind::Member,
+            transaction: transaction.key(),
+            status: transaction.status.clone(),
+        });
+    }
+
+    Ok(())
+}
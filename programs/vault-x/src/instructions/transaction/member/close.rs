@@ -0,0 +1,57 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to close a member transaction
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultMemberCloseTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_MEMBER_TRANSACTION,
+            &transaction.transaction_index.to_le_bytes(),
+        ],
+        bump = transaction.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        has_one = creator @ VaultError::InvalidAccount,
+        constraint = transaction.is_closable(&vault) || closer.key().eq(&transaction.creator) @ VaultError::InvalidTransactionStatus,
+        close = creator
+    )]
+    pub transaction: Account<'info, VaultMemberTransaction>,
+
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The creator of the transaction, who paid for its rent and receives it back
+    #[account(mut)]
+    pub creator: SystemAccount<'info>,
+
+    /// Anyone can close a stale or terminal transaction, otherwise only its creator can
+    pub closer: Signer<'info>,
+}
+
+/// Closes a member transaction, refunding the rent to its creator
+pub fn close(ctx: Context<VaultMemberCloseTransaction>) -> Result<()> {
+    let transaction = &ctx.accounts.transaction;
+
+    emit_cpi!(TransactionClosedEvent {
+        vault: ctx.accounts.vault.key(),
+        actor: ctx.accounts.closer.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Member,
+        transaction: transaction.key(),
+    });
+
+    Ok(())
+}
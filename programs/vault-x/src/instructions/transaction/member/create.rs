@@ -1,5 +1,6 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::id;
 use crate::state::*;
 use anchor_lang::prelude::*;
@@ -10,13 +11,20 @@ use spl_account_compression::{
 };
 
 /// Accounts required to create a member transaction
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: VaultMemberCreateTransactionArgs)]
 pub struct VaultMemberCreateTransaction<'info> {
     #[account(
         init,
         payer = creator,
-        space = VaultMemberTransaction::size(args.ephemeral_signers, &args.transaction_message)?,
+        space = VaultMemberTransaction::size(
+            args.ephemeral_signers,
+            transaction_buffer
+                .as_ref()
+                .map_or(&args.transaction_message, |transaction_buffer| &transaction_buffer.buffer),
+            if vault.is_member_voting_enabled() { vault.members.len() } else { 0 },
+        )?,
         seeds = [
             SEED_PREFIX,
             vault.key().as_ref(),
@@ -31,7 +39,8 @@ pub struct VaultMemberCreateTransaction<'info> {
         mut,
         seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
         bump = vault.bump,
-        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
     )]
     pub vault: Account<'info, Vault>,
 
@@ -41,6 +50,36 @@ pub struct VaultMemberCreateTransaction<'info> {
     )]
     pub creator: Signer<'info>,
 
+    /// CHECK: The member's rate limit PDA, enforced if the founders have set one
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_MEMBER_RATE_LIMIT,
+            creator.key().as_ref(),
+        ],
+        bump
+    )]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// Buffer holding the transaction message when it is too large to pass inline, closed once used
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            creator.key().as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes(),
+        ],
+        bump = transaction_buffer.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        has_one = creator @ VaultError::Unauthorized,
+        close = creator
+    )]
+    pub transaction_buffer: Option<Account<'info, VaultTransactionBuffer>>,
+
     /// CHECK: This account is validated in the instruction
     pub merkle_tree: UncheckedAccount<'info>,
 
@@ -61,6 +100,8 @@ pub fn create<'info>(
         creator,
         merkle_tree,
         account_compression_program,
+        rate_limit,
+        transaction_buffer,
         ..
     } = ctx.accounts;
 
@@ -80,6 +121,8 @@ pub fn create<'info>(
         VaultError::InvalidAllowlist
     );
 
+    VaultMemberRateLimit::consume(rate_limit, Clock::get()?.unix_timestamp)?;
+
     // Proof verification
     let VaultMemberCreateTransactionArgs {
         allow_list_leaf,
@@ -87,6 +130,8 @@ pub fn create<'info>(
         allow_list_root,
         transaction_message,
         ephemeral_signers,
+        max_executions,
+        cooldown_seconds,
     } = args;
 
     verify_leaf(
@@ -102,12 +147,17 @@ pub fn create<'info>(
         allow_list_leaf_index,
     )?;
 
+    // Take the message from the buffer when one is provided
+    let transaction_message = VaultTransactionBuffer::resolve_message(
+        transaction_buffer.as_deref(),
+        transaction_message,
+    )?;
+
     // Validate and deserialize the transaction message
-    let transaction_message =
-        match VaultTransactionMessage::deserialize(&mut transaction_message.as_slice()) {
-            Ok(transaction_message) => transaction_message,
-            Err(_) => return err!(VaultError::InvalidVaultTransactionMessage),
-        };
+    let transaction_message = match VaultTransactionMessage::try_from_bytes(&transaction_message) {
+        Ok(transaction_message) => transaction_message,
+        Err(_) => return err!(VaultError::InvalidVaultTransactionMessage),
+    };
     transaction_message.is_valid()?;
 
     // Retrieve keys for vault and transaction
@@ -139,17 +189,49 @@ pub fn create<'info>(
     let transaction_index = vault.transaction_index.checked_add(1).unwrap();
     vault.transaction_index = transaction_index;
 
+    // Without member voting the transaction can be executed right away
+    let status = if vault.is_member_voting_enabled() {
+        VaultTransactionStatus::Active
+    } else {
+        VaultTransactionStatus::Approved
+    };
+
     // Set the transaction fields
     transaction.set_inner(VaultMemberTransaction {
         creator: creator.key(),
         vault: vault_key,
         transaction_index,
+        status,
         bump: ctx.bumps.transaction,
         fund_bump,
         ephemeral_signer_bumps,
         message: transaction_message,
+        approved: Vec::new(),
+        rejected: Vec::new(),
+        cancelled: Vec::new(),
+        execution_count: 0,
+        max_executions: max_executions.unwrap_or(1),
+        cooldown_seconds: cooldown_seconds.unwrap_or(0),
+        last_executed_at: 0,
     });
 
+    emit_cpi!(TransactionCreatedEvent {
+        vault: vault.key(),
+        actor: creator.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Member,
+        transaction: transaction.key(),
+    });
+
+    if let Some(transaction_buffer) = transaction_buffer {
+        emit_cpi!(TransactionBufferClosedEvent {
+            vault: vault.key(),
+            actor: creator.key(),
+            transaction_index: transaction.transaction_index,
+            transaction_buffer: transaction_buffer.key(),
+        });
+    }
+
     Ok(())
 }
 
@@ -157,8 +239,13 @@ pub fn create<'info>(
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct VaultMemberCreateTransactionArgs {
     pub ephemeral_signers: u8,
+    /// Serialized transaction message, left empty when the message is uploaded to a buffer
     pub transaction_message: Vec<u8>,
     pub allow_list_root: [u8; 32],
     pub allow_list_leaf: [u8; 32],
     pub allow_list_leaf_index: u32,
+    /// Executions allowed before the transaction is spent, defaults to 1. 0 makes it a standing order
+    pub max_executions: Option<u32>,
+    /// Minimum delay in seconds between two executions, defaults to 0
+    pub cooldown_seconds: Option<u32>,
 }
@@ -0,0 +1,153 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to escalate a member transaction to the founders
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultMemberEscalateTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_MEMBER_TRANSACTION,
+            &member_transaction.transaction_index.to_le_bytes(),
+        ],
+        bump = member_transaction.bump,
+        has_one = vault @ VaultError::InvalidInstructionAccount,
+        has_one = creator @ VaultError::InvalidAccount,
+        constraint = member_transaction.status.ne(&VaultTransactionStatus::Executed) @ VaultError::InvalidTransactionStatus,
+        close = creator
+    )]
+    pub member_transaction: Account<'info, VaultMemberTransaction>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = VaultFounderTransaction::size_for_message(
+            u8::try_from(member_transaction.ephemeral_signer_bumps.len()).unwrap(),
+            &member_transaction.message,
+            vault.founders.len(),
+        )?,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_FOUNDER_TRANSACTION,
+            &vault.transaction_index.checked_add(1).unwrap().to_le_bytes(),
+        ],
+        bump
+    )]
+    pub founder_transaction: Account<'info, VaultFounderTransaction>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The member who created the transaction, paying for the founder transaction
+    /// and receiving the member transaction's rent back
+    #[account(
+        mut,
+        constraint = vault.is_member(&creator.key()) @ VaultError::MemberNotFound
+    )]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves the message of a member transaction into a new active founder transaction,
+/// closing the member transaction
+pub fn escalate(ctx: Context<VaultMemberEscalateTransaction>) -> Result<()> {
+    let VaultMemberEscalateTransaction {
+        vault,
+        member_transaction,
+        founder_transaction,
+        creator,
+        ..
+    } = ctx.accounts;
+
+    // Validate the default expiry against the vault's maximum transaction lifetime
+    let expiry = vault.resolve_transaction_expiry(None, &Clock::get()?)?;
+
+    let member_transaction_key = member_transaction.key();
+    let founder_transaction_key = founder_transaction.key();
+
+    // Ephemeral signers are derived from the transaction key, so they change with it
+    let (member_ephemeral_signer_keys, _) = fetch_ephemeral_keys(
+        &member_transaction.ephemeral_signer_bumps,
+        &member_transaction_key,
+    );
+
+    let mut founder_ephemeral_signer_keys = Vec::new();
+    let ephemeral_signer_bumps: Vec<u8> = (0..member_ephemeral_signer_keys.len())
+        .map(|ephemeral_signer_index| {
+            let (ephemeral_signer_key, ephemeral_signer_bump) = Pubkey::find_program_address(
+                &[
+                    SEED_PREFIX,
+                    founder_transaction_key.as_ref(),
+                    SEED_EPHEMERAL_SIGNER,
+                    &u8::try_from(ephemeral_signer_index).unwrap().to_le_bytes(),
+                ],
+                &id(),
+            );
+            founder_ephemeral_signer_keys.push(ephemeral_signer_key);
+
+            ephemeral_signer_bump
+        })
+        .collect();
+
+    // Point the message at the founder transaction's ephemeral signers
+    let mut transaction_message = member_transaction.message.clone();
+    for account_key in transaction_message.account_keys.iter_mut() {
+        if let Some(ephemeral_signer_index) = member_ephemeral_signer_keys
+            .iter()
+            .position(|ephemeral_signer_key| ephemeral_signer_key.eq(account_key))
+        {
+            *account_key = founder_ephemeral_signer_keys[ephemeral_signer_index];
+        }
+    }
+
+    // Increment transaction index for this vault
+    let transaction_index = vault.transaction_index.checked_add(1).unwrap();
+    vault.transaction_index = transaction_index;
+
+    // The member stays the creator, linking the founder transaction back to them
+    founder_transaction.set_inner(VaultFounderTransaction {
+        creator: creator.key(),
+        vault: vault.key(),
+        transaction_index,
+        status: VaultTransactionStatus::Active,
+        bump: ctx.bumps.founder_transaction,
+        fund_bump: member_transaction.fund_bump,
+        ephemeral_signer_bumps,
+        message: transaction_message,
+        approved: Vec::new(),
+        rejected: Vec::new(),
+        cancelled: Vec::new(),
+        approved_at: 0,
+        executable_at: 0,
+        expiry,
+        escalated_from: Some(member_transaction.transaction_index),
+        executed_instructions: 0,
+    });
+
+    emit_cpi!(TransactionEscalatedEvent {
+        vault: vault.key(),
+        actor: creator.key(),
+        transaction_index,
+        transaction: founder_transaction_key,
+        member_transaction_index: member_transaction.transaction_index,
+        member_transaction: member_transaction_key,
+    });
+
+    Ok(())
+}
@@ -1,12 +1,13 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::id;
 use crate::state::*;
 use crate::utils::*;
 use anchor_lang::prelude::*;
-use solana_program::program::invoke_signed;
 
 /// Accounts required for a member to execute a transaction
+#[event_cpi]
 #[derive(Accounts)]
 pub struct VaultMemberExecuteTransaction<'info> {
     #[account(
@@ -19,13 +20,15 @@ pub struct VaultMemberExecuteTransaction<'info> {
         ],
         bump = transaction.bump,
         has_one = vault @ VaultError::InvalidInstructionAccount,
+        constraint = transaction.is_transaction_valid(&VaultTransactionStatus::Approved, &vault) @ VaultError::InvalidTransactionStatus
     )]
     pub transaction: Account<'info, VaultMemberTransaction>,
 
     #[account(
         seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
         bump = vault.bump,
-        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
     )]
     pub vault: Account<'info, Vault>,
 
@@ -34,6 +37,19 @@ pub struct VaultMemberExecuteTransaction<'info> {
         constraint = vault.is_member(&member.key()) @ VaultError::MemberNotFound
     )]
     pub member: Signer<'info>,
+
+    /// CHECK: The member's rate limit PDA, enforced if the founders have set one
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_MEMBER_RATE_LIMIT,
+            member.key().as_ref(),
+        ],
+        bump
+    )]
+    pub rate_limit: UncheckedAccount<'info>,
 }
 
 /// Executes a member transaction by invoking the associated instructions
@@ -41,6 +57,13 @@ pub fn execute(ctx: Context<VaultMemberExecuteTransaction>) -> Result<()> {
     let transaction = &mut ctx.accounts.transaction;
     let vault = &ctx.accounts.vault;
     let vault_key = vault.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        transaction.is_cooldown_elapsed(now),
+        VaultError::CooldownNotElapsed
+    );
+    VaultMemberRateLimit::consume(&ctx.accounts.rate_limit, now)?;
 
     let fund_bump = transaction.fund_bump;
     let fund_seeds = &[SEED_PREFIX, vault_key.as_ref(), SEED_FUND, &[fund_bump]];
@@ -51,19 +74,19 @@ pub fn execute(ctx: Context<VaultMemberExecuteTransaction>) -> Result<()> {
     let (ephemeral_signer_keys, ephemeral_signer_seeds) =
         fetch_ephemeral_keys(&transaction.ephemeral_signer_bumps, &transaction.key());
 
-    // Remaining accounts for the transaction
-    let transaction_account_infos = ctx.remaining_accounts;
     let transaction_message = &transaction.message;
 
     // Validate and sanitize instructions and account infos
-    transaction_message.validate_message_account_infos(
-        transaction_account_infos,
+    // The address lookup tables come first in the remaining accounts, followed by the message accounts
+    let transaction_account_infos = transaction_message.validate_message_account_infos(
+        ctx.remaining_accounts,
         &fund_key,
         &ephemeral_signer_keys,
     )?;
 
     // Protect specific accounts from writable access during execution
-    let protected_accounts = &[vault_key, transaction_key];
+    let protected_accounts =
+        &VaultMemberTransaction::protected_accounts(&vault_key, &transaction_key);
 
     let instructions_with_ordered_account_infos = transaction_message
         .fetch_instructions_with_ordered_account_infos(
@@ -72,27 +95,32 @@ pub fn execute(ctx: Context<VaultMemberExecuteTransaction>) -> Result<()> {
         )?;
 
     // Execute each instruction with the appropriate signers
-    for (instruction, account_infos) in instructions_with_ordered_account_infos.iter() {
-        let ephemeral_signer_seeds_slice = ephemeral_signer_seeds
-            .iter()
-            .map(|second_layer| {
-                second_layer
-                    .iter()
-                    .map(|third_layer| third_layer.as_slice())
-                    .collect::<Vec<&[u8]>>()
-            })
-            .collect::<Vec<Vec<&[u8]>>>();
-
-        let mut ephemeral_signer_seeds_int_slice = ephemeral_signer_seeds_slice
-            .iter()
-            .map(Vec::as_slice)
-            .collect::<Vec<&[&[u8]]>>();
-
-        ephemeral_signer_seeds_int_slice.push(fund_seeds);
-        let signers_seeds = ephemeral_signer_seeds_int_slice.as_slice();
-
-        invoke_signed(instruction, account_infos, signers_seeds)?;
-    }
+    let receipt = invoke_transaction_instructions(
+        &instructions_with_ordered_account_infos,
+        &ephemeral_signer_seeds,
+        fund_seeds,
+        &fund_key,
+    )?;
+
+    // Spend one execution, marking the transaction as executed once none remain
+    transaction.record_execution(now);
+
+    emit_cpi!(ExecutionReceiptEvent {
+        vault: vault_key,
+        actor: ctx.accounts.member.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Member,
+        transaction: Some(transaction.key()),
+        receipt,
+    });
+
+    emit_cpi!(TransactionExecutedEvent {
+        vault: vault_key,
+        actor: ctx.accounts.member.key(),
+        transaction_index: transaction.transaction_index,
+        kind: TransactionKind::Member,
+        transaction: transaction.key(),
+    });
 
     Ok(())
 }
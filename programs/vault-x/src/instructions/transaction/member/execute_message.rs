@@ -0,0 +1,180 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+use spl_account_compression::{
+    cpi::{accounts::VerifyLeaf, verify_leaf},
+    id as compression_program_id,
+    program::SplAccountCompression,
+};
+
+/// Accounts required for a member to execute a message without creating a transaction.
+/// The allowlist proof accounts come first in `remaining_accounts`, followed by the message accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultMemberExecuteMessage<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated,
+        constraint = !vault.is_member_voting_enabled() @ VaultError::MemberVotingEnabled
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = vault.is_member(&member.key()) @ VaultError::MemberNotFound
+    )]
+    pub member: Signer<'info>,
+
+    /// CHECK: The member's rate limit PDA, enforced if the founders have set one
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_MEMBER_RATE_LIMIT,
+            member.key().as_ref(),
+        ],
+        bump
+    )]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// CHECK: This account is validated in the instruction
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// Account compression program
+    pub account_compression_program: Program<'info, SplAccountCompression>,
+}
+
+/// Arguments required to execute a message without creating a transaction
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultMemberExecuteMessageArgs {
+    pub transaction_message: Vec<u8>,
+    pub allow_list_root: [u8; 32],
+    pub allow_list_leaf: [u8; 32],
+    pub allow_list_leaf_index: u32,
+    /// Number of allowlist proof accounts at the start of `remaining_accounts`
+    pub allow_list_proof_length: u8,
+}
+
+/// Verifies the allowlist proof and executes the message right away, without persisting anything.
+/// Messages executed this way cannot use ephemeral signers.
+pub fn execute_message<'info>(
+    ctx: Context<'_, '_, '_, 'info, VaultMemberExecuteMessage<'info>>,
+    args: VaultMemberExecuteMessageArgs,
+) -> Result<()> {
+    let VaultMemberExecuteMessageArgs {
+        transaction_message,
+        allow_list_root,
+        allow_list_leaf,
+        allow_list_leaf_index,
+        allow_list_proof_length,
+    } = args;
+
+    let VaultMemberExecuteMessage {
+        vault,
+        member,
+        merkle_tree,
+        account_compression_program,
+        rate_limit,
+        ..
+    } = ctx.accounts;
+
+    VaultMemberRateLimit::consume(rate_limit, Clock::get()?.unix_timestamp)?;
+
+    // Split the remaining accounts between the proof and the message
+    let allow_list_proof_length = usize::from(allow_list_proof_length);
+    require!(
+        ctx.remaining_accounts.len() >= allow_list_proof_length,
+        VaultError::InvalidNumberOfAccounts
+    );
+    let (proof_accounts, transaction_account_infos) =
+        ctx.remaining_accounts.split_at(allow_list_proof_length);
+
+    // Validate that the Merkle tree account belongs to the account-compression program
+    require_eq!(
+        merkle_tree.owner,
+        &compression_program_id(),
+        VaultError::InvalidAllowlist
+    );
+
+    // Validate that the Merkle tree account matches the one under the current vault
+    require_eq!(
+        merkle_tree.key(),
+        vault.allow_list_merkle_tree,
+        VaultError::InvalidAllowlist
+    );
+
+    // Proof verification
+    verify_leaf(
+        CpiContext::new(
+            account_compression_program.to_account_info(),
+            VerifyLeaf {
+                merkle_tree: merkle_tree.to_account_info(),
+            },
+        )
+        .with_remaining_accounts(proof_accounts.to_vec()),
+        allow_list_root,
+        allow_list_leaf,
+        allow_list_leaf_index,
+    )?;
+
+    // Validate and deserialize the transaction message
+    let transaction_message = match VaultTransactionMessage::try_from_bytes(&transaction_message) {
+        Ok(transaction_message) => transaction_message,
+        Err(_) => return err!(VaultError::InvalidVaultTransactionMessage),
+    };
+    transaction_message.is_valid()?;
+
+    let vault_key = vault.key();
+    let (fund_key, fund_bump) =
+        Pubkey::find_program_address(&[SEED_PREFIX, vault_key.as_ref(), SEED_FUND], &id());
+    let fund_seeds = &[SEED_PREFIX, vault_key.as_ref(), SEED_FUND, &[fund_bump]];
+
+    // Validate and sanitize instructions and account infos, without ephemeral signers.
+    // The address lookup tables come first, followed by the message accounts.
+    let transaction_account_infos = transaction_message.validate_message_account_infos(
+        transaction_account_infos,
+        &fund_key,
+        &[],
+    )?;
+
+    // Protect specific accounts from writable access during execution
+    let protected_accounts = &[vault_key];
+
+    let instructions_with_ordered_account_infos = transaction_message
+        .fetch_instructions_with_ordered_account_infos(
+            transaction_account_infos,
+            protected_accounts,
+        )?;
+
+    // Execute each instruction with the fund as signer
+    let receipt = invoke_transaction_instructions(
+        &instructions_with_ordered_account_infos,
+        &[],
+        fund_seeds,
+        &fund_key,
+    )?;
+
+    emit_cpi!(ExecutionReceiptEvent {
+        vault: vault_key,
+        actor: member.key(),
+        transaction_index: vault.transaction_index,
+        kind: TransactionKind::Member,
+        transaction: None,
+        receipt,
+    });
+
+    emit_cpi!(MessageExecutedEvent {
+        vault: vault_key,
+        actor: member.key(),
+        transaction_index: vault.transaction_index,
+    });
+
+    Ok(())
+}
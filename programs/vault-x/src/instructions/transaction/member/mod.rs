@@ -1,5 +1,17 @@
+pub mod approve;
+pub mod cancel;
+pub mod close;
 pub mod create;
+pub mod escalate;
 pub mod execute;
+pub mod execute_message;
+pub mod reject;
 
+pub use approve::*;
+pub use cancel::*;
+pub use close::*;
 pub use create::*;
+pub use escalate::*;
 pub use execute::*;
+pub use execute_message::*;
+pub use reject::*;
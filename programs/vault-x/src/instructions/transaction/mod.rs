@@ -1,5 +1,17 @@
+pub mod batch;
+pub mod buffer;
+pub mod close_legacy_transaction;
+pub mod close_transactions;
+pub mod committee;
 pub mod founder;
 pub mod member;
+pub mod validate;
 
+pub use batch::*;
+pub use buffer::*;
+pub use close_legacy_transaction::*;
+pub use close_transactions::*;
+pub use committee::*;
 pub use founder::*;
 pub use member::*;
+pub use validate::*;
@@ -0,0 +1,133 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::id;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use solana_program::program::set_return_data;
+
+/// Accounts required to check a transaction's message against the accounts that would execute it.
+/// The message accounts are passed in `remaining_accounts`, as for execution.
+#[derive(Accounts)]
+pub struct VaultValidateTransaction<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: A founder or member transaction of the vault, told apart by its discriminator
+    #[account(
+        constraint = transaction.owner.eq(&id()) @ VaultError::InvalidAccount
+    )]
+    pub transaction: UncheckedAccount<'info>,
+}
+
+/// Result of validating a transaction, returned through the return data
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultTransactionValidationReport {
+    pub transaction_index: u32,
+    /// Number of keys the message references, including the ones loaded from lookup tables
+    pub num_account_keys: u16,
+    /// Number of account infos supplied, including the lookup tables
+    pub num_account_infos: u16,
+    pub num_instructions: u16,
+    /// Error code of the first failed check, none if the accounts line up with the message
+    pub error_code: Option<u64>,
+}
+
+/// Checks that the supplied accounts line up with a founder or member transaction's message,
+/// as execution would, and reports the outcome through the return data instead of failing.
+/// Meant to be simulated before voting, the instruction neither writes nor invokes any program,
+/// so it emits no event.
+pub fn validate(ctx: Context<VaultValidateTransaction>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let transaction_info = ctx.accounts.transaction.to_account_info();
+    let transaction_key = transaction_info.key();
+
+    // The discriminator tells founder and member transactions apart
+    let transaction_data = transaction_info.try_borrow_data()?;
+    let discriminator = transaction_data
+        .get(..8)
+        .ok_or(VaultError::InvalidTransactionAccount)?;
+    let (
+        transaction_vault,
+        transaction_index,
+        fund_bump,
+        ephemeral_signer_bumps,
+        message,
+        is_founder_transaction,
+    ) = if discriminator == VaultFounderTransaction::DISCRIMINATOR {
+        let transaction = VaultFounderTransaction::try_deserialize(&mut &transaction_data[..])?;
+        (
+            transaction.vault,
+            transaction.transaction_index,
+            transaction.fund_bump,
+            transaction.ephemeral_signer_bumps,
+            transaction.message,
+            true,
+        )
+    } else if discriminator == VaultMemberTransaction::DISCRIMINATOR {
+        let transaction = VaultMemberTransaction::try_deserialize(&mut &transaction_data[..])?;
+        (
+            transaction.vault,
+            transaction.transaction_index,
+            transaction.fund_bump,
+            transaction.ephemeral_signer_bumps,
+            transaction.message,
+            false,
+        )
+    } else {
+        return err!(VaultError::InvalidTransactionAccount);
+    };
+    drop(transaction_data);
+
+    require_keys_eq!(
+        transaction_vault,
+        vault_key,
+        VaultError::InvalidInstructionAccount
+    );
+
+    let fund_key = Pubkey::create_program_address(
+        &[SEED_PREFIX, vault_key.as_ref(), SEED_FUND, &[fund_bump]],
+        &id(),
+    )
+    .unwrap();
+    let (mut ephemeral_signer_keys, _) =
+        fetch_ephemeral_keys(&ephemeral_signer_bumps, &transaction_key);
+
+    // Founder transactions also sign as the founder authority, and each kind protects its own accounts
+    let protected_accounts = if is_founder_transaction {
+        ephemeral_signer_keys.push(fetch_founder_authority(&vault_key).0);
+        VaultFounderTransaction::protected_accounts(&vault_key, &transaction_key)
+    } else {
+        VaultMemberTransaction::protected_accounts(&vault_key, &transaction_key)
+    };
+
+    // Run the checks of execution, keeping the first error for the report
+    let validation = message
+        .validate_message_account_infos(ctx.remaining_accounts, &fund_key, &ephemeral_signer_keys)
+        .and_then(|transaction_account_infos| {
+            message.fetch_instructions_with_ordered_account_infos(
+                transaction_account_infos,
+                &protected_accounts,
+            )
+        });
+
+    let report = VaultTransactionValidationReport {
+        transaction_index,
+        num_account_keys: u16::try_from(message.num_all_account_keys()).unwrap(),
+        num_account_infos: u16::try_from(ctx.remaining_accounts.len()).unwrap(),
+        num_instructions: u16::try_from(message.instructions.len()).unwrap(),
+        error_code: validation
+            .err()
+            .map(|error| u64::from(ProgramError::from(error))),
+    };
+
+    set_return_data(&report.try_to_vec()?);
+
+    Ok(())
+}
@@ -1,10 +1,12 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::id;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 /// Accounts required to add a new founder to the vault
+#[event_cpi]
 #[derive(Accounts)]
 pub struct VaultAddFounder<'info> {
     #[account(
@@ -12,7 +14,8 @@ pub struct VaultAddFounder<'info> {
         seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
         bump = vault.bump,
         has_one = administrator @ VaultError::Unauthorized,
-        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
     )]
     pub vault: Account<'info, Vault>,
 
@@ -30,19 +33,22 @@ pub struct VaultAddFounder<'info> {
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct VaultAddFounderArgs {
     new_founder: Pubkey,
+    /// Vote weight of the new founder, defaults to 1
+    weight: Option<u16>,
 }
 
 /// Adds a new founder to the vault and reallocates if necessary
 pub fn add_founder(ctx: Context<VaultAddFounder>, args: VaultAddFounderArgs) -> Result<()> {
-    let VaultAddFounderArgs { new_founder } = args;
+    let VaultAddFounderArgs {
+        new_founder,
+        weight,
+    } = args;
     let vault = &mut ctx.accounts.vault;
     let payer = &ctx.accounts.rent_payer;
     let system_program = &ctx.accounts.system_program;
 
-    match vault.founders.binary_search(&new_founder) {
-        Ok(_) => return err!(VaultError::FounderAlreadyExists),
-        Err(founder_index) => vault.founders.insert(founder_index, new_founder),
-    };
+    let weight = weight.unwrap_or(1);
+    vault.insert_founder(new_founder, weight)?;
 
     // Ensure the number of founders doesn't exceed the limit
     require!(
@@ -62,5 +68,13 @@ pub fn add_founder(ctx: Context<VaultAddFounder>, args: VaultAddFounderArgs) ->
     vault.stale_transaction_index = vault.transaction_index;
     vault.validate()?;
 
+    emit_cpi!(FounderAddedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.administrator.key(),
+        transaction_index: vault.transaction_index,
+        founder: new_founder,
+        weight,
+    });
+
     Ok(())
 }
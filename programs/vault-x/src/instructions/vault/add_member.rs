@@ -1,17 +1,20 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::id;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 /// Accounts required to add a new member to the vault
+#[event_cpi]
 #[derive(Accounts)]
 pub struct VaultAddMember<'info> {
     #[account(
         mut,
         seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
         bump = vault.bump,
-        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
     )]
     pub vault: Account<'info, Vault>,
 
@@ -62,5 +65,12 @@ pub fn add_member(ctx: Context<VaultAddMember>, args: VaultAddMemberArgs) -> Res
     vault.stale_transaction_index = vault.transaction_index;
     vault.validate()?;
 
+    emit_cpi!(MembersAddedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.founder.key(),
+        transaction_index: vault.transaction_index,
+        members: vec![new_member],
+    });
+
     Ok(())
 }
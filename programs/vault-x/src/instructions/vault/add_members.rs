@@ -0,0 +1,104 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use std::cmp::Ordering;
+
+/// Accounts required to add a batch of members to the vault
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultAddMembers<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(constraint = vault.is_founder(&founder.key()) @ VaultError::FounderNotFound)]
+    pub founder: Signer<'info>,
+
+    /// The account used to pay for additional storage if the vault needs to expand.
+    #[account(mut)]
+    pub rent_payer: Option<Signer<'info>>,
+
+    /// Required if reallocation is needed
+    pub system_program: Option<Program<'info, System>>,
+}
+
+/// Arguments required to add a batch of members to the vault
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultAddMembersArgs {
+    new_members: Vec<Pubkey>,
+}
+
+/// Adds a batch of members to the vault in a single sorted merge, reallocating once if necessary
+pub fn add_members(ctx: Context<VaultAddMembers>, args: VaultAddMembersArgs) -> Result<()> {
+    let VaultAddMembersArgs { mut new_members } = args;
+    let vault = &mut ctx.accounts.vault;
+    let payer = &ctx.accounts.rent_payer;
+    let system_program = &ctx.accounts.system_program;
+
+    require!(!new_members.is_empty(), VaultError::InvalidRoleCount);
+
+    // Sort and deduplicate the new members to merge them into the sorted members list
+    new_members.sort();
+    new_members.dedup();
+
+    // Ensure the administrator isn't one of the new members
+    require!(
+        !vault.check_for_admin_presence(&new_members),
+        VaultError::AdminCannotBeMember
+    );
+
+    // Ensure the number of members doesn't exceed the limit
+    require!(
+        vault.members.len() + new_members.len() <= usize::from(MAX_VAULT_ROLES),
+        VaultError::InvalidRoleCount
+    );
+
+    let added_members = new_members.clone();
+
+    let mut members = Vec::with_capacity(vault.members.len() + new_members.len());
+    let mut existing_members = vault.members.iter().peekable();
+    let mut new_members = new_members.into_iter().peekable();
+
+    while let (Some(existing_member), Some(new_member)) =
+        (existing_members.peek(), new_members.peek())
+    {
+        match existing_member.cmp(&new_member) {
+            Ordering::Less => members.push(*existing_members.next().unwrap()),
+            Ordering::Greater => members.push(new_members.next().unwrap()),
+            Ordering::Equal => return err!(VaultError::MemberAlreadyExists),
+        }
+    }
+    members.extend(existing_members);
+    members.extend(new_members);
+
+    vault.members = members;
+
+    // Reallocate if necessary
+    Vault::realloc_if_needed(
+        vault.to_account_info(),
+        &vault.founders,
+        &vault.members,
+        payer.as_ref().map(ToAccountInfo::to_account_info),
+        system_program.as_ref().map(ToAccountInfo::to_account_info),
+    )?;
+
+    vault.stale_transaction_index = vault.transaction_index;
+    vault.validate()?;
+
+    emit_cpi!(MembersAddedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.founder.key(),
+        transaction_index: vault.transaction_index,
+        members: added_members,
+    });
+
+    Ok(())
+}
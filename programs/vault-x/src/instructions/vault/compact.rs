@@ -0,0 +1,50 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to compact the vault
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultCompact<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: The vault's rent collector, receives the rent refunded when the vault shrinks
+    #[account(
+        mut,
+        address = vault.rent_collector @ VaultError::InvalidAccount
+    )]
+    pub rent_collector: UncheckedAccount<'info>,
+}
+
+/// Shrinks the vault down to the size its founders and members require, refunding the excess rent
+pub fn compact(ctx: Context<VaultCompact>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let rent_collector = &ctx.accounts.rent_collector;
+
+    Vault::shrink_if_needed(
+        vault.to_account_info(),
+        &vault.founders,
+        &vault.members,
+        Some(rent_collector.to_account_info()),
+    )?;
+
+    // Compacting is permissionless, the rent collector it refunds is reported as the actor
+    emit_cpi!(VaultUpdatedEvent {
+        vault: vault.key(),
+        actor: rent_collector.key(),
+        transaction_index: vault.transaction_index,
+        update: VaultUpdate::Compacted,
+    });
+
+    Ok(())
+}
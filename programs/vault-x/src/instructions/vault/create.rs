@@ -1,11 +1,14 @@
+use super::VaultSetMetadataArgs;
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use spl_account_compression::id as compression_program_id;
 
 /// Accounts required to create a new vault
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: CreateVaultArgs)]
 pub struct CreateVault<'info> {
@@ -41,13 +44,22 @@ pub struct CreateVault<'info> {
     #[account(mut)]
     pub administrator: Signer<'info>,
     pub system_program: Program<'info, System>,
+
+    /// CHECK: Required if metadata is provided, the PDA is validated and created in the instruction
+    #[account(mut)]
+    pub vault_metadata: Option<UncheckedAccount<'info>>,
 }
 
 /// Arguments required to create a vault
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateVaultArgs {
     pub founder_threshold: u16,
+    /// Delay in seconds between a founder transaction's approval and its execution
+    pub time_lock_seconds: u32,
     pub initial_founders: Vec<Pubkey>,
+    /// Vote weight of each initial founder, in the same order. Defaults to 1 for every founder
+    pub initial_founder_weights: Option<Vec<u16>>,
+    pub metadata: Option<VaultSetMetadataArgs>,
 }
 
 /// Creates a new vault
@@ -62,13 +74,32 @@ pub fn create(ctx: Context<CreateVault>, args: CreateVaultArgs) -> Result<()> {
     );
 
     let CreateVaultArgs {
-        mut initial_founders,
+        initial_founders,
+        initial_founder_weights,
         founder_threshold,
+        time_lock_seconds,
+        metadata,
     } = args;
 
+    // Pair each founder with its vote weight
+    let initial_founder_weights =
+        initial_founder_weights.unwrap_or_else(|| vec![1; initial_founders.len()]);
+    require!(
+        initial_founder_weights.len() == initial_founders.len(),
+        VaultError::InvalidFounderWeight
+    );
+
+    let mut founders_with_weights: Vec<(Pubkey, u16)> = initial_founders
+        .into_iter()
+        .zip(initial_founder_weights)
+        .collect();
+
     // Sort and deduplicate founders for binary search
-    initial_founders.sort();
-    initial_founders.dedup();
+    founders_with_weights.sort_by_key(|(founder, _)| *founder);
+    founders_with_weights.dedup_by_key(|(founder, _)| *founder);
+
+    let (initial_founders, founder_weights): (Vec<Pubkey>, Vec<u16>) =
+        founders_with_weights.into_iter().unzip();
 
     // Ensure the administrator isn't a founder
     require!(
@@ -79,8 +110,9 @@ pub fn create(ctx: Context<CreateVault>, args: CreateVaultArgs) -> Result<()> {
     // Validate the initial founders list
     vault.role_array_validate(&initial_founders)?;
 
-    // Validate the founder threshold
-    if founder_threshold < 1 || usize::from(founder_threshold) > initial_founders.len() {
+    // Validate the founder threshold against the total founder vote weight
+    let total_founder_weight: u64 = founder_weights.iter().copied().map(u64::from).sum();
+    if founder_threshold < 1 || u64::from(founder_threshold) > total_founder_weight {
         return err!(VaultError::InvalidFounderThreshold);
     }
 
@@ -95,8 +127,44 @@ pub fn create(ctx: Context<CreateVault>, args: CreateVaultArgs) -> Result<()> {
         stale_transaction_index: 0,
         founder_threshold,
         transaction_index: 0,
+        version: VAULT_LAYOUT_VERSION,
+        founder_weights,
+        committee_index: 0,
+        time_lock_seconds,
+        max_expiry_seconds: 0,
+        max_expiry_slots: 0,
+        rent_collector: administrator.key(),
+        last_activity_at: Clock::get()?.unix_timestamp,
+        member_threshold: 0,
+        legacy_transaction_index: 0,
+    });
+    vault.validate()?;
+
+    emit_cpi!(VaultCreatedEvent {
+        vault: vault.key(),
+        actor: administrator.key(),
+        transaction_index: vault.transaction_index,
+        founders: vault.founders.clone(),
+        founder_weights: vault.founder_weights.clone(),
+        founder_threshold: vault.founder_threshold,
     });
 
+    // Create the vault metadata if provided
+    if let Some(metadata) = metadata {
+        let vault_metadata = ctx
+            .accounts
+            .vault_metadata
+            .as_ref()
+            .ok_or(VaultError::MissingAccount)?;
+
+        metadata.into_metadata(vault.key()).write(
+            &vault_metadata.to_account_info(),
+            &administrator.to_account_info(),
+            &administrator.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
     // Transfer the creation fee to the treasury if applicable
     let creation_fee = ctx.accounts.vault_config.creation_fee;
     if creation_fee > 0 {
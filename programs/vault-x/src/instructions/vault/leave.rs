@@ -0,0 +1,76 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for a member to leave the vault
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultLeave<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(constraint = vault.is_member(&member.key()) @ VaultError::MemberNotFound)]
+    pub member: Signer<'info>,
+
+    /// CHECK: The vault's rent collector, receives the rent refunded when the vault shrinks
+    #[account(
+        mut,
+        address = vault.rent_collector @ VaultError::InvalidAccount
+    )]
+    pub rent_collector: Option<UncheckedAccount<'info>>,
+}
+
+/// Removes the signing member from the vault
+pub fn leave(ctx: Context<VaultLeave>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let rent_collector = &ctx.accounts.rent_collector;
+    let member = &ctx.accounts.member;
+
+    if let Ok(member_index) = vault.members.binary_search(member.key) {
+        vault.members.remove(member_index);
+    } else {
+        return err!(VaultError::MemberNotFound);
+    }
+
+    // Reclaim the freed space if a rent collector is provided
+    Vault::shrink_if_needed(
+        vault.to_account_info(),
+        &vault.founders,
+        &vault.members,
+        rent_collector.as_ref().map(ToAccountInfo::to_account_info),
+    )?;
+
+    // Keep member voting reachable by the remaining members
+    let member_threshold_lowered = vault.clamp_member_threshold();
+
+    vault.stale_transaction_index = vault.transaction_index;
+    vault.validate()?;
+
+    emit_cpi!(MembersRemovedEvent {
+        vault: vault.key(),
+        actor: member.key(),
+        transaction_index: vault.transaction_index,
+        members: vec![member.key()],
+    });
+    if member_threshold_lowered {
+        emit_cpi!(VaultUpdatedEvent {
+            vault: vault.key(),
+            actor: member.key(),
+            transaction_index: vault.transaction_index,
+            update: VaultUpdate::MemberThreshold {
+                member_threshold: vault.member_threshold,
+            },
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to migrate a vault created with the legacy layout
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultMigrate<'info> {
+    /// CHECK: The legacy vault can't be deserialized as a `Vault`, it is checked in the instruction
+    #[account(
+        mut,
+        constraint = vault.owner.eq(&id()) @ VaultError::InvalidProgram
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// The vault administrator, paying for the additional storage
+    #[account(mut)]
+    pub administrator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrites a legacy vault with the current layout.
+/// Founders get a vote weight of 1 and every setting added since defaults to disabled.
+pub fn migrate(ctx: Context<VaultMigrate>) -> Result<()> {
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let administrator = &ctx.accounts.administrator;
+
+    let legacy = VaultLegacy::try_deserialize(&vault_info.try_borrow_data()?)?;
+
+    require_keys_eq!(
+        legacy.administrator,
+        administrator.key(),
+        VaultError::Unauthorized
+    );
+
+    // The vault must be the PDA derived from its own create key
+    let vault_key = Pubkey::create_program_address(
+        &[
+            SEED_PREFIX,
+            SEED_VAULT,
+            legacy.create_key.as_ref(),
+            &[legacy.bump],
+        ],
+        &id(),
+    )
+    .map_err(|_| VaultError::InvalidAccount)?;
+    require_keys_eq!(vault_key, vault_info.key(), VaultError::InvalidAccount);
+
+    let vault = legacy.migrate(Clock::get()?.unix_timestamp);
+    vault.validate()?;
+
+    Vault::realloc_if_needed(
+        vault_info.clone(),
+        &vault.founders,
+        &vault.members,
+        Some(administrator.to_account_info()),
+        Some(ctx.accounts.system_program.to_account_info()),
+    )?;
+
+    vault.try_serialize(&mut &mut vault_info.try_borrow_mut_data()?[..])?;
+
+    emit_cpi!(VaultUpdatedEvent {
+        vault: vault_key,
+        actor: administrator.key(),
+        transaction_index: vault.transaction_index,
+        update: VaultUpdate::Migrated {
+            version: vault.version,
+        },
+    });
+
+    Ok(())
+}
@@ -1,13 +1,41 @@
 pub mod add_founder;
 pub mod add_member;
+pub mod add_members;
+pub mod compact;
 pub mod create;
+pub mod leave;
+pub mod migrate;
 pub mod remove_founder;
 pub mod remove_member;
+pub mod remove_members;
+pub mod resign_founder;
+pub mod set_member_rate_limit;
+pub mod set_metadata;
+pub mod set_veto_council;
 pub mod update_founder_threshold;
+pub mod update_founder_weight;
+pub mod update_max_expiry;
+pub mod update_member_threshold;
+pub mod update_rent_collector;
+pub mod update_time_lock;
 
 pub use add_founder::*;
 pub use add_member::*;
+pub use add_members::*;
+pub use compact::*;
 pub use create::*;
+pub use leave::*;
+pub use migrate::*;
 pub use remove_founder::*;
 pub use remove_member::*;
+pub use remove_members::*;
+pub use resign_founder::*;
+pub use set_member_rate_limit::*;
+pub use set_metadata::*;
+pub use set_veto_council::*;
 pub use update_founder_threshold::*;
+pub use update_founder_weight::*;
+pub use update_max_expiry::*;
+pub use update_member_threshold::*;
+pub use update_rent_collector::*;
+pub use update_time_lock::*;
@@ -1,10 +1,12 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::id;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 /// Accounts required to remove a founder from the vault
+#[event_cpi]
 #[derive(Accounts)]
 pub struct VaultRemoveFounder<'info> {
     #[account(
@@ -12,12 +14,20 @@ pub struct VaultRemoveFounder<'info> {
         seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
         bump = vault.bump,
         has_one = administrator @ VaultError::Unauthorized,
-        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
     )]
     pub vault: Account<'info, Vault>,
 
     #[account(mut)]
     pub administrator: Signer<'info>,
+
+    /// CHECK: The vault's rent collector, receives the rent refunded when the vault shrinks
+    #[account(
+        mut,
+        address = vault.rent_collector @ VaultError::InvalidAccount
+    )]
+    pub rent_collector: Option<UncheckedAccount<'info>>,
 }
 
 /// Arguments required to remove a founder from the vault
@@ -33,23 +43,44 @@ pub fn remove_founder(
     args: VaultRemoveFounderArgs,
 ) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
+    let rent_collector = &ctx.accounts.rent_collector;
 
-    if let Ok(founder_index) = vault.founders.binary_search(&args.founder) {
-        vault.founders.remove(founder_index);
-    } else {
-        return err!(VaultError::FounderNotFound);
-    }
+    vault.remove_founder(&args.founder)?;
 
     if let Some(new_founder_threshold) = args.new_founder_threshold {
         require!(
-            new_founder_threshold > 0 && usize::from(new_founder_threshold) <= vault.founders.len(),
+            new_founder_threshold > 0
+                && u64::from(new_founder_threshold) <= vault.total_vote_weight(),
             VaultError::InvalidFounderThreshold
         );
         vault.founder_threshold = new_founder_threshold;
     }
 
+    // Reclaim the freed space if a rent collector is provided
+    Vault::shrink_if_needed(
+        vault.to_account_info(),
+        &vault.founders,
+        &vault.members,
+        rent_collector.as_ref().map(ToAccountInfo::to_account_info),
+    )?;
+
     vault.stale_transaction_index = vault.transaction_index;
     vault.validate()?;
 
+    emit_cpi!(FounderRemovedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.administrator.key(),
+        transaction_index: vault.transaction_index,
+        founder: args.founder,
+    });
+    if let Some(founder_threshold) = args.new_founder_threshold {
+        emit_cpi!(FounderThresholdChangedEvent {
+            vault: vault.key(),
+            actor: ctx.accounts.administrator.key(),
+            transaction_index: vault.transaction_index,
+            founder_threshold,
+        });
+    }
+
     Ok(())
 }
@@ -1,17 +1,20 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::id;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 /// Accounts required to remove a member from the vault
+#[event_cpi]
 #[derive(Accounts)]
 pub struct VaultRemoveMember<'info> {
     #[account(
         mut,
         seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
         bump = vault.bump,
-        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
     )]
     pub vault: Account<'info, Vault>,
 
@@ -20,6 +23,13 @@ pub struct VaultRemoveMember<'info> {
         constraint = vault.is_founder(&founder.key()) @ VaultError::FounderNotFound
     )]
     pub founder: Signer<'info>,
+
+    /// CHECK: The vault's rent collector, receives the rent refunded when the vault shrinks
+    #[account(
+        mut,
+        address = vault.rent_collector @ VaultError::InvalidAccount
+    )]
+    pub rent_collector: Option<UncheckedAccount<'info>>,
 }
 
 /// Arguments required to remove a member from the vault
@@ -31,6 +41,7 @@ pub struct VaultRemoveMemberArgs {
 /// Removes a member from the vault
 pub fn remove_member(ctx: Context<VaultRemoveMember>, args: VaultRemoveMemberArgs) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
+    let rent_collector = &ctx.accounts.rent_collector;
 
     if let Ok(member_index) = vault.members.binary_search(&args.member) {
         vault.members.remove(member_index);
@@ -38,8 +49,36 @@ pub fn remove_member(ctx: Context<VaultRemoveMember>, args: VaultRemoveMemberArg
         return err!(VaultError::MemberNotFound);
     }
 
+    // Reclaim the freed space if a rent collector is provided
+    Vault::shrink_if_needed(
+        vault.to_account_info(),
+        &vault.founders,
+        &vault.members,
+        rent_collector.as_ref().map(ToAccountInfo::to_account_info),
+    )?;
+
+    // Keep member voting reachable by the remaining members
+    let member_threshold_lowered = vault.clamp_member_threshold();
+
     vault.stale_transaction_index = vault.transaction_index;
     vault.validate()?;
 
+    emit_cpi!(MembersRemovedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.founder.key(),
+        transaction_index: vault.transaction_index,
+        members: vec![args.member],
+    });
+    if member_threshold_lowered {
+        emit_cpi!(VaultUpdatedEvent {
+            vault: vault.key(),
+            actor: ctx.accounts.founder.key(),
+            transaction_index: vault.transaction_index,
+            update: VaultUpdate::MemberThreshold {
+                member_threshold: vault.member_threshold,
+            },
+        });
+    }
+
     Ok(())
 }
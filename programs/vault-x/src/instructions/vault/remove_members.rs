@@ -0,0 +1,99 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to remove a batch of members from the vault
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultRemoveMembers<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = vault.is_founder(&founder.key()) @ VaultError::FounderNotFound
+    )]
+    pub founder: Signer<'info>,
+
+    /// CHECK: The vault's rent collector, receives the rent refunded when the vault shrinks
+    #[account(
+        mut,
+        address = vault.rent_collector @ VaultError::InvalidAccount
+    )]
+    pub rent_collector: Option<UncheckedAccount<'info>>,
+}
+
+/// Arguments required to remove a batch of members from the vault
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultRemoveMembersArgs {
+    members: Vec<Pubkey>,
+}
+
+/// Removes a batch of members from the vault
+pub fn remove_members(
+    ctx: Context<VaultRemoveMembers>,
+    args: VaultRemoveMembersArgs,
+) -> Result<()> {
+    let VaultRemoveMembersArgs { mut members } = args;
+    let vault = &mut ctx.accounts.vault;
+    let rent_collector = &ctx.accounts.rent_collector;
+
+    require!(!members.is_empty(), VaultError::InvalidRoleCount);
+
+    // Sort and deduplicate the removed members for binary search
+    members.sort();
+    members.dedup();
+
+    let members_len = vault.members.len();
+    vault
+        .members
+        .retain(|member| members.binary_search(member).is_err());
+
+    // Every removed member must have been part of the vault
+    require!(
+        members_len - vault.members.len() == members.len(),
+        VaultError::MemberNotFound
+    );
+
+    // Reclaim the freed space if a rent collector is provided
+    Vault::shrink_if_needed(
+        vault.to_account_info(),
+        &vault.founders,
+        &vault.members,
+        rent_collector.as_ref().map(ToAccountInfo::to_account_info),
+    )?;
+
+    // Keep member voting reachable by the remaining members
+    let member_threshold_lowered = vault.clamp_member_threshold();
+
+    vault.stale_transaction_index = vault.transaction_index;
+    vault.validate()?;
+
+    emit_cpi!(MembersRemovedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.founder.key(),
+        transaction_index: vault.transaction_index,
+        members,
+    });
+    if member_threshold_lowered {
+        emit_cpi!(VaultUpdatedEvent {
+            vault: vault.key(),
+            actor: ctx.accounts.founder.key(),
+            transaction_index: vault.transaction_index,
+            update: VaultUpdate::MemberThreshold {
+                member_threshold: vault.member_threshold,
+            },
+        });
+    }
+
+    Ok(())
+}
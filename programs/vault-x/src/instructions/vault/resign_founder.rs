@@ -0,0 +1,97 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required for a founder to resign from the vault
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultResignFounder<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(constraint = vault.is_founder(&founder.key()) @ VaultError::FounderNotFound)]
+    pub founder: Signer<'info>,
+
+    /// CHECK: The vault's rent collector, receives the rent refunded when the vault shrinks
+    #[account(
+        mut,
+        address = vault.rent_collector @ VaultError::InvalidAccount
+    )]
+    pub rent_collector: Option<UncheckedAccount<'info>>,
+}
+
+/// Arguments required for a founder to resign from the vault
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultResignFounderArgs {
+    /// Required if, and only accepted if, the remaining founders' weight falls below the current threshold
+    new_founder_threshold: Option<u16>,
+}
+
+/// Removes the signing founder from the vault, lowering the founder threshold if needed
+pub fn resign_founder(
+    ctx: Context<VaultResignFounder>,
+    args: VaultResignFounderArgs,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let rent_collector = &ctx.accounts.rent_collector;
+    let founder = &ctx.accounts.founder;
+
+    vault.remove_founder(founder.key)?;
+
+    // The last founder cannot resign
+    require!(!vault.founders.is_empty(), VaultError::InvalidRoleCount);
+
+    // A resigning founder can't change the threshold while the remaining founders can still reach it,
+    // otherwise the threshold is lowered to at most the remaining weight
+    let remaining_weight = vault.total_vote_weight();
+    let founder_threshold_changed = remaining_weight < u64::from(vault.founder_threshold);
+    match args.new_founder_threshold {
+        Some(new_founder_threshold) if founder_threshold_changed => {
+            require!(
+                new_founder_threshold > 0 && u64::from(new_founder_threshold) <= remaining_weight,
+                VaultError::InvalidFounderThreshold
+            );
+            vault.founder_threshold = new_founder_threshold;
+        }
+        None if !founder_threshold_changed => {}
+        _ => return err!(VaultError::InvalidFounderThreshold),
+    }
+
+    // Reclaim the freed space if a rent collector is provided
+    Vault::shrink_if_needed(
+        vault.to_account_info(),
+        &vault.founders,
+        &vault.members,
+        rent_collector.as_ref().map(ToAccountInfo::to_account_info),
+    )?;
+
+    vault.stale_transaction_index = vault.transaction_index;
+
+    vault.validate()?;
+
+    emit_cpi!(FounderRemovedEvent {
+        vault: vault.key(),
+        actor: founder.key(),
+        transaction_index: vault.transaction_index,
+        founder: founder.key(),
+    });
+    if founder_threshold_changed {
+        emit_cpi!(FounderThresholdChangedEvent {
+            vault: vault.key(),
+            actor: ctx.accounts.founder.key(),
+            transaction_index: vault.transaction_index,
+            founder_threshold: vault.founder_threshold,
+        });
+    }
+
+    Ok(())
+}
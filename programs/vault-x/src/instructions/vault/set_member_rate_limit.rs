@@ -0,0 +1,99 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to limit how many member transactions a member can create or execute
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: VaultSetMemberRateLimitArgs)]
+pub struct VaultSetMemberRateLimit<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated,
+        constraint = vault.is_member(&args.member) @ VaultError::MemberNotFound
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: The rate limit PDA is created in the instruction
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            vault.key().as_ref(),
+            SEED_MEMBER_RATE_LIMIT,
+            args.member.as_ref(),
+        ],
+        bump
+    )]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// Pays for the rate limit account on first use
+    #[account(
+        mut,
+        constraint = vault.is_founder(&founder.key()) @ VaultError::FounderNotFound
+    )]
+    pub founder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Arguments required to set a member's rate limit
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultSetMemberRateLimitArgs {
+    pub member: Pubkey,
+    /// Transactions the member can create or execute per window, 0 to lift the limit
+    pub max_transactions: u32,
+    pub window_seconds: u32,
+}
+
+/// Sets how many member transactions a member can create or execute per time window,
+/// starting a new window
+pub fn set_member_rate_limit(
+    ctx: Context<VaultSetMemberRateLimit>,
+    args: VaultSetMemberRateLimitArgs,
+) -> Result<()> {
+    let VaultSetMemberRateLimitArgs {
+        member,
+        max_transactions,
+        window_seconds,
+    } = args;
+    let vault = &ctx.accounts.vault;
+
+    require!(
+        max_transactions == 0 || window_seconds > 0,
+        VaultError::InvalidRateLimit
+    );
+
+    VaultMemberRateLimit {
+        vault: vault.key(),
+        member,
+        bump: 0,
+        max_transactions,
+        window_seconds,
+        window_started_at: Clock::get()?.unix_timestamp,
+        transaction_count: 0,
+    }
+    .write(
+        &ctx.accounts.rate_limit.to_account_info(),
+        &ctx.accounts.founder.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit_cpi!(VaultUpdatedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.founder.key(),
+        transaction_index: vault.transaction_index,
+        update: VaultUpdate::MemberRateLimit {
+            member,
+            max_transactions,
+            window_seconds,
+        },
+    });
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to create or update the vault metadata
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultSetMetadata<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: The metadata PDA is created or reallocated in the instruction
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, vault.key().as_ref(), SEED_VAULT_METADATA],
+        bump
+    )]
+    pub vault_metadata: UncheckedAccount<'info>,
+
+    #[account(constraint = vault.is_founder(&founder.key()) @ VaultError::FounderNotFound)]
+    pub founder: Signer<'info>,
+
+    /// The account paying for additional storage
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    /// CHECK: The vault's rent collector, receives the excess rent if the metadata shrinks
+    #[account(
+        mut,
+        address = vault.rent_collector @ VaultError::InvalidAccount
+    )]
+    pub rent_collector: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Arguments holding the vault metadata contents
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultSetMetadataArgs {
+    pub name: String,
+    pub description: String,
+    pub image_uri: String,
+    pub website: String,
+    pub tags: Vec<String>,
+}
+
+impl VaultSetMetadataArgs {
+    /// Builds the metadata account contents for the given vault
+    pub fn into_metadata(self, vault: Pubkey) -> VaultMetadata {
+        let VaultSetMetadataArgs {
+            name,
+            description,
+            image_uri,
+            website,
+            tags,
+        } = self;
+
+        VaultMetadata {
+            vault,
+            bump: 0,
+            name,
+            description,
+            image_uri,
+            website,
+            tags,
+        }
+    }
+}
+
+/// Creates or overwrites the vault metadata, resizing the account to fit the new contents
+pub fn set_metadata(ctx: Context<VaultSetMetadata>, args: VaultSetMetadataArgs) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+
+    args.into_metadata(vault.key()).write(
+        &ctx.accounts.vault_metadata.to_account_info(),
+        &ctx.accounts.rent_payer.to_account_info(),
+        &ctx.accounts.rent_collector.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit_cpi!(VaultUpdatedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.founder.key(),
+        transaction_index: vault.transaction_index,
+        update: VaultUpdate::Metadata,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to replace the veto guardians of the vault.
+/// The founder authority must sign, so the guardians can only be changed through an executed founder transaction.
+/// Member transactions sign with the fund but never with the founder authority.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultSetVetoCouncil<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: The veto council PDA is created or reallocated in the instruction
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, vault.key().as_ref(), SEED_VETO_COUNCIL],
+        bump
+    )]
+    pub veto_council: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX, vault.key().as_ref(), SEED_FOUNDER_AUTHORITY],
+        bump
+    )]
+    pub founder_authority: Signer<'info>,
+
+    /// The account paying for additional storage
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    /// CHECK: The vault's rent collector, receives the excess rent if the council shrinks
+    #[account(
+        mut,
+        address = vault.rent_collector @ VaultError::InvalidAccount
+    )]
+    pub rent_collector: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Arguments required to replace the veto guardians
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultSetVetoCouncilArgs {
+    pub guardians: Vec<Pubkey>,
+    /// Guardians that must co-sign a veto
+    pub guardian_threshold: u16,
+}
+
+/// Replaces the guardians allowed to veto founder transactions
+pub fn set_veto_council(
+    ctx: Context<VaultSetVetoCouncil>,
+    args: VaultSetVetoCouncilArgs,
+) -> Result<()> {
+    let VaultSetVetoCouncilArgs {
+        mut guardians,
+        guardian_threshold,
+    } = args;
+
+    // Sort and deduplicate guardians for binary search
+    guardians.sort();
+    guardians.dedup();
+
+    let vault = &ctx.accounts.vault;
+
+    VaultVetoCouncil {
+        vault: vault.key(),
+        bump: 0,
+        guardians: guardians.clone(),
+        guardian_threshold,
+    }
+    .write(
+        &ctx.accounts.veto_council.to_account_info(),
+        &ctx.accounts.rent_payer.to_account_info(),
+        &ctx.accounts.rent_collector.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit_cpi!(VaultUpdatedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.founder_authority.key(),
+        transaction_index: vault.transaction_index,
+        update: VaultUpdate::VetoCouncil {
+            guardians,
+            guardian_threshold,
+        },
+    });
+
+    Ok(())
+}
@@ -1,10 +1,12 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::id;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 /// Accounts required to update the founder threshold
+#[event_cpi]
 #[derive(Accounts)]
 pub struct VaultUpdateFounderThreshold<'info> {
     #[account(
@@ -12,7 +14,8 @@ pub struct VaultUpdateFounderThreshold<'info> {
         seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
         bump = vault.bump,
         has_one = administrator @ VaultError::Unauthorized,
-        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
     )]
     pub vault: Account<'info, Vault>,
 
@@ -44,5 +47,12 @@ pub fn update_founder_threshold(
 
     vault.validate()?;
 
+    emit_cpi!(FounderThresholdChangedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.administrator.key(),
+        transaction_index: vault.transaction_index,
+        founder_threshold: vault.founder_threshold,
+    });
+
     Ok(())
 }
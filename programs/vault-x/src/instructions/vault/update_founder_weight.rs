@@ -0,0 +1,79 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to update the vote weight of a founder
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultUpdateFounderWeight<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        has_one = administrator @ VaultError::Unauthorized,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub administrator: Signer<'info>,
+}
+
+/// Arguments required to update the vote weight of a founder
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultUpdateFounderWeightArgs {
+    founder: Pubkey,
+    new_weight: u16,
+    new_founder_threshold: Option<u16>,
+}
+
+/// Changes the vote weight of a founder and optionally updates the founder threshold
+pub fn update_founder_weight(
+    ctx: Context<VaultUpdateFounderWeight>,
+    args: VaultUpdateFounderWeightArgs,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    let founder_index = vault
+        .founders
+        .binary_search(&args.founder)
+        .map_err(|_| VaultError::FounderNotFound)?;
+    vault.founder_weights[founder_index] = args.new_weight;
+
+    if let Some(new_founder_threshold) = args.new_founder_threshold {
+        require!(
+            new_founder_threshold > 0,
+            VaultError::InvalidFounderThreshold
+        );
+        vault.founder_threshold = new_founder_threshold;
+    }
+
+    // Update the change index to deprecate any active transactions
+    vault.stale_transaction_index = vault.transaction_index;
+
+    vault.validate()?;
+
+    emit_cpi!(VaultUpdatedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.administrator.key(),
+        transaction_index: vault.transaction_index,
+        update: VaultUpdate::FounderWeight {
+            founder: args.founder,
+            weight: args.new_weight,
+        },
+    });
+    if let Some(founder_threshold) = args.new_founder_threshold {
+        emit_cpi!(FounderThresholdChangedEvent {
+            vault: vault.key(),
+            actor: ctx.accounts.administrator.key(),
+            transaction_index: vault.transaction_index,
+            founder_threshold,
+        });
+    }
+
+    Ok(())
+}
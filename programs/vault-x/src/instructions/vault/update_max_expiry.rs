@@ -0,0 +1,55 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to update the maximum founder transaction lifetime
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultUpdateMaxExpiry<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        has_one = administrator @ VaultError::Unauthorized,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub administrator: Signer<'info>,
+}
+
+/// Arguments required to update the maximum founder transaction lifetime, 0 leaves it unbounded
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultUpdateMaxExpiryArgs {
+    new_max_expiry_seconds: u32,
+    new_max_expiry_slots: u64,
+}
+
+/// Changes the maximum lifetime of founder transactions created from now on
+pub fn update_max_expiry(
+    ctx: Context<VaultUpdateMaxExpiry>,
+    args: VaultUpdateMaxExpiryArgs,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.max_expiry_seconds = args.new_max_expiry_seconds;
+    vault.max_expiry_slots = args.new_max_expiry_slots;
+
+    vault.validate()?;
+
+    emit_cpi!(VaultUpdatedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.administrator.key(),
+        transaction_index: vault.transaction_index,
+        update: VaultUpdate::MaxExpiry {
+            max_expiry_seconds: vault.max_expiry_seconds,
+            max_expiry_slots: vault.max_expiry_slots,
+        },
+    });
+
+    Ok(())
+}
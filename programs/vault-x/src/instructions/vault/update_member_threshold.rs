@@ -0,0 +1,56 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to update the member threshold
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultUpdateMemberThreshold<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        has_one = administrator @ VaultError::Unauthorized,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub administrator: Signer<'info>,
+}
+
+/// Arguments required to update the member threshold
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultUpdateMemberThresholdArgs {
+    new_member_threshold: u16,
+}
+
+/// Changes the member threshold for the vault, 0 disables member voting.
+/// Member transactions approved while voting was disabled can't be executed once it is enabled.
+pub fn update_member_threshold(
+    ctx: Context<VaultUpdateMemberThreshold>,
+    args: VaultUpdateMemberThresholdArgs,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.member_threshold = args.new_member_threshold;
+
+    // Update the change index to deprecate any active transactions
+    vault.stale_transaction_index = vault.transaction_index;
+
+    vault.validate()?;
+
+    emit_cpi!(VaultUpdatedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.administrator.key(),
+        transaction_index: vault.transaction_index,
+        update: VaultUpdate::MemberThreshold {
+            member_threshold: vault.member_threshold,
+        },
+    });
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to update the rent collector
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultUpdateRentCollector<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        has_one = administrator @ VaultError::Unauthorized,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub administrator: Signer<'info>,
+}
+
+/// Arguments required to update the rent collector
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultUpdateRentCollectorArgs {
+    new_rent_collector: Pubkey,
+}
+
+/// Changes the account receiving the rent refunded when the vault shrinks
+pub fn update_rent_collector(
+    ctx: Context<VaultUpdateRentCollector>,
+    args: VaultUpdateRentCollectorArgs,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    require!(
+        args.new_rent_collector.ne(&Pubkey::default()) && args.new_rent_collector.ne(&vault.key()),
+        VaultError::InvalidAccount
+    );
+
+    vault.rent_collector = args.new_rent_collector;
+
+    emit_cpi!(VaultUpdatedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.administrator.key(),
+        transaction_index: vault.transaction_index,
+        update: VaultUpdate::RentCollector {
+            rent_collector: vault.rent_collector,
+        },
+    });
+
+    Ok(())
+}
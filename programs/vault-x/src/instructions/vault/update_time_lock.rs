@@ -0,0 +1,52 @@
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Accounts required to update the time lock
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultUpdateTimeLock<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_VAULT, vault.create_key.key().as_ref()],
+        bump = vault.bump,
+        has_one = administrator @ VaultError::Unauthorized,
+        constraint = vault.to_account_info().owner.eq(&id()) @ VaultError::InvalidProgram,
+        constraint = vault.is_migrated() @ VaultError::VaultNotMigrated
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub administrator: Signer<'info>,
+}
+
+/// Arguments required to update the time lock
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultUpdateTimeLockArgs {
+    new_time_lock_seconds: u32,
+}
+
+/// Changes the delay between a founder transaction's approval and its execution
+pub fn update_time_lock(
+    ctx: Context<VaultUpdateTimeLock>,
+    args: VaultUpdateTimeLockArgs,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.time_lock_seconds = args.new_time_lock_seconds;
+
+    vault.validate()?;
+
+    emit_cpi!(VaultUpdatedEvent {
+        vault: vault.key(),
+        actor: ctx.accounts.administrator.key(),
+        transaction_index: vault.transaction_index,
+        update: VaultUpdate::TimeLock {
+            time_lock_seconds: vault.time_lock_seconds,
+        },
+    });
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::pubkey;
@@ -9,6 +10,7 @@ use anchor_lang::solana_program::pubkey;
 const INITIALIZER: Pubkey = pubkey!("BrQAbGdWQ9YUHmWWgKFdFe4miTURH71jkYFPXfaosqDv");
 
 /// Accounts required to initialize the vault configuration
+#[event_cpi]
 #[derive(Accounts)]
 pub struct VaultConfigInit<'info> {
     /// Vault configuration account to be initialized
@@ -53,5 +55,13 @@ pub fn init(ctx: Context<VaultConfigInit>, creation_fee: u64) -> Result<()> {
     vault_config.authority = ctx.accounts.authority.key();
     vault_config.treasury = ctx.accounts.treasury.key();
 
+    emit_cpi!(VaultConfigUpdatedEvent {
+        vault_config: vault_config.key(),
+        actor: ctx.accounts.initializer.key(),
+        authority: vault_config.authority,
+        treasury: vault_config.treasury,
+        creation_fee: vault_config.creation_fee,
+    });
+
     Ok(())
 }
@@ -1,9 +1,11 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 /// Accounts required to update the authority of the vault configuration
+#[event_cpi]
 #[derive(Accounts)]
 pub struct VaultConfigUpdateAuthority<'info> {
     #[account(
@@ -33,5 +35,13 @@ pub fn update_authority(
     let vault_config = &mut ctx.accounts.vault_config;
     vault_config.authority = new_update_authority;
 
+    emit_cpi!(VaultConfigUpdatedEvent {
+        vault_config: vault_config.key(),
+        actor: ctx.accounts.authority.key(),
+        authority: vault_config.authority,
+        treasury: vault_config.treasury,
+        creation_fee: vault_config.creation_fee,
+    });
+
     Ok(())
 }
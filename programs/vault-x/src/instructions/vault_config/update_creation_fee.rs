@@ -1,9 +1,11 @@
 use crate::constants::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 /// Accounts required to update the creation fee of the vault configuration
+#[event_cpi]
 #[derive(Accounts)]
 pub struct VaultConfigUpdateCreationFee<'info> {
     #[account(
@@ -28,5 +30,13 @@ pub fn update_creation_fee(
     let vault_config = &mut ctx.accounts.vault_config;
     vault_config.creation_fee = new_creation_fee;
 
+    emit_cpi!(VaultConfigUpdatedEvent {
+        vault_config: vault_config.key(),
+        actor: ctx.accounts.authority.key(),
+        authority: vault_config.authority,
+        treasury: vault_config.treasury,
+        creation_fee: vault_config.creation_fee,
+    });
+
     Ok(())
 }
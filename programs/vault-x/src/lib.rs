@@ -6,6 +6,7 @@ use instructions::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
@@ -47,6 +48,10 @@ pub mod vaultx {
         vault::create(ctx, args)
     }
 
+    pub fn migrate_vault(ctx: Context<VaultMigrate>) -> Result<()> {
+        vault::migrate(ctx)
+    }
+
     pub fn add_member(ctx: Context<VaultAddMember>, args: VaultAddMemberArgs) -> Result<()> {
         vault::add_member(ctx, args)
     }
@@ -58,6 +63,17 @@ pub mod vaultx {
         vault::remove_member(ctx, args)
     }
 
+    pub fn add_members(ctx: Context<VaultAddMembers>, args: VaultAddMembersArgs) -> Result<()> {
+        vault::add_members(ctx, args)
+    }
+
+    pub fn remove_members(
+        ctx: Context<VaultRemoveMembers>,
+        args: VaultRemoveMembersArgs,
+    ) -> Result<()> {
+        vault::remove_members(ctx, args)
+    }
+
     pub fn add_founder(ctx: Context<VaultAddFounder>, args: VaultAddFounderArgs) -> Result<()> {
         vault::add_founder(ctx, args)
     }
@@ -69,6 +85,17 @@ pub mod vaultx {
         vault::remove_founder(ctx, args)
     }
 
+    pub fn leave_vault(ctx: Context<VaultLeave>) -> Result<()> {
+        vault::leave(ctx)
+    }
+
+    pub fn resign_founder(
+        ctx: Context<VaultResignFounder>,
+        args: VaultResignFounderArgs,
+    ) -> Result<()> {
+        vault::resign_founder(ctx, args)
+    }
+
     pub fn update_founder_threshold(
         ctx: Context<VaultUpdateFounderThreshold>,
         args: VaultUpdateFounderThresholdArgs,
@@ -76,6 +103,130 @@ pub mod vaultx {
         vault::update_founder_threshold(ctx, args)
     }
 
+    pub fn update_founder_weight(
+        ctx: Context<VaultUpdateFounderWeight>,
+        args: VaultUpdateFounderWeightArgs,
+    ) -> Result<()> {
+        vault::update_founder_weight(ctx, args)
+    }
+
+    pub fn update_time_lock(
+        ctx: Context<VaultUpdateTimeLock>,
+        args: VaultUpdateTimeLockArgs,
+    ) -> Result<()> {
+        vault::update_time_lock(ctx, args)
+    }
+
+    pub fn update_member_threshold(
+        ctx: Context<VaultUpdateMemberThreshold>,
+        args: VaultUpdateMemberThresholdArgs,
+    ) -> Result<()> {
+        vault::update_member_threshold(ctx, args)
+    }
+
+    pub fn update_max_expiry(
+        ctx: Context<VaultUpdateMaxExpiry>,
+        args: VaultUpdateMaxExpiryArgs,
+    ) -> Result<()> {
+        vault::update_max_expiry(ctx, args)
+    }
+
+    pub fn update_rent_collector(
+        ctx: Context<VaultUpdateRentCollector>,
+        args: VaultUpdateRentCollectorArgs,
+    ) -> Result<()> {
+        vault::update_rent_collector(ctx, args)
+    }
+
+    pub fn compact_vault(ctx: Context<VaultCompact>) -> Result<()> {
+        vault::compact(ctx)
+    }
+
+    pub fn set_member_rate_limit(
+        ctx: Context<VaultSetMemberRateLimit>,
+        args: VaultSetMemberRateLimitArgs,
+    ) -> Result<()> {
+        vault::set_member_rate_limit(ctx, args)
+    }
+
+    pub fn set_vault_metadata(
+        ctx: Context<VaultSetMetadata>,
+        args: VaultSetMetadataArgs,
+    ) -> Result<()> {
+        vault::set_metadata(ctx, args)
+    }
+
+    pub fn set_veto_council(
+        ctx: Context<VaultSetVetoCouncil>,
+        args: VaultSetVetoCouncilArgs,
+    ) -> Result<()> {
+        vault::set_veto_council(ctx, args)
+    }
+
+    /** INVITATION INSTRUCTIONS */
+    pub fn create_invitation(
+        ctx: Context<VaultCreateInvitation>,
+        args: VaultCreateInvitationArgs,
+    ) -> Result<()> {
+        invitation::create(ctx, args)
+    }
+
+    pub fn accept_invitation(ctx: Context<VaultAcceptInvitation>) -> Result<()> {
+        invitation::accept(ctx)
+    }
+
+    pub fn revoke_invitation(ctx: Context<VaultRevokeInvitation>) -> Result<()> {
+        invitation::revoke(ctx)
+    }
+
+    /** RECOVERY INSTRUCTIONS */
+    pub fn set_vault_recovery(
+        ctx: Context<VaultSetRecovery>,
+        args: VaultSetRecoveryArgs,
+    ) -> Result<()> {
+        recovery::set(ctx, args)
+    }
+
+    pub fn propose_recovery(
+        ctx: Context<VaultProposeRecovery>,
+        args: VaultProposeRecoveryArgs,
+    ) -> Result<()> {
+        recovery::propose(ctx, args)
+    }
+
+    pub fn approve_recovery(ctx: Context<VaultApproveRecovery>) -> Result<()> {
+        recovery::approve(ctx)
+    }
+
+    pub fn execute_recovery(ctx: Context<VaultExecuteRecovery>) -> Result<()> {
+        recovery::execute(ctx)
+    }
+
+    pub fn cancel_recovery(ctx: Context<VaultCancelRecovery>) -> Result<()> {
+        recovery::cancel(ctx)
+    }
+
+    /** COMMITTEE INSTRUCTIONS */
+    pub fn create_committee(
+        ctx: Context<VaultCreateCommittee>,
+        args: VaultCreateCommitteeArgs,
+    ) -> Result<()> {
+        committee::create(ctx, args)
+    }
+
+    pub fn dissolve_committee<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VaultDissolveCommittee<'info>>,
+    ) -> Result<()> {
+        committee::dissolve(ctx)
+    }
+
+    pub fn sweep_committee_fund<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VaultSweepCommitteeFund<'info>>,
+        args: VaultSweepCommitteeFundArgs,
+    ) -> Result<()> {
+        committee::sweep(ctx, args)
+    }
+
     /** FOUNDER TRANSACTION INSTRUCTIONS */
     pub fn create_founder_transaction(
         ctx: Context<VaultFounderCreateTransaction>,
@@ -100,6 +251,25 @@ pub mod vaultx {
         transaction::founder::execute(ctx)
     }
 
+    pub fn execute_founder_transaction_partial(
+        ctx: Context<VaultFounderExecuteTransaction>,
+        args: VaultFounderExecutePartialArgs,
+    ) -> Result<()> {
+        transaction::founder::execute_partial(ctx, args)
+    }
+
+    pub fn expire_founder_transaction(ctx: Context<VaultFounderExpireTransaction>) -> Result<()> {
+        transaction::founder::expire(ctx)
+    }
+
+    pub fn veto_founder_transaction(ctx: Context<VaultFounderVetoTransaction>) -> Result<()> {
+        transaction::founder::veto(ctx)
+    }
+
+    pub fn close_founder_transaction(ctx: Context<VaultFounderCloseTransaction>) -> Result<()> {
+        transaction::founder::close(ctx)
+    }
+
     /** MEMBER TRANSACTION INSTRUCTIONS */
     pub fn create_member_transaction<'info>(
         ctx: Context<'_, '_, '_, 'info, VaultMemberCreateTransaction<'info>>,
@@ -108,7 +278,149 @@ pub mod vaultx {
         transaction::member::create(ctx, args)
     }
 
+    pub fn approve_member_transaction(ctx: Context<VaultMemberApproveTransaction>) -> Result<()> {
+        transaction::member::approve(ctx)
+    }
+
+    pub fn reject_member_transaction(ctx: Context<VaultMemberRejectTransaction>) -> Result<()> {
+        transaction::member::reject(ctx)
+    }
+
+    pub fn cancel_member_transaction(ctx: Context<VaultMemberCancelTransaction>) -> Result<()> {
+        transaction::member::cancel(ctx)
+    }
+
     pub fn execute_member_transaction(ctx: Context<VaultMemberExecuteTransaction>) -> Result<()> {
         transaction::member::execute(ctx)
     }
+
+    pub fn execute_member_message<'info>(
+        ctx: Context<'_, '_, '_, 'info, VaultMemberExecuteMessage<'info>>,
+        args: VaultMemberExecuteMessageArgs,
+    ) -> Result<()> {
+        transaction::member::execute_message(ctx, args)
+    }
+
+    pub fn escalate_member_transaction(ctx: Context<VaultMemberEscalateTransaction>) -> Result<()> {
+        transaction::member::escalate(ctx)
+    }
+
+    pub fn close_member_transaction(ctx: Context<VaultMemberCloseTransaction>) -> Result<()> {
+        transaction::member::close(ctx)
+    }
+
+    /** COMMITTEE TRANSACTION INSTRUCTIONS */
+    pub fn create_committee_transaction(
+        ctx: Context<VaultCommitteeCreateTransaction>,
+        args: VaultCommitteeCreateTransactionArgs,
+    ) -> Result<()> {
+        transaction::committee::create(ctx, args)
+    }
+
+    pub fn approve_committee_transaction(
+        ctx: Context<VaultCommitteeApproveTransaction>,
+    ) -> Result<()> {
+        transaction::committee::approve(ctx)
+    }
+
+    pub fn reject_committee_transaction(
+        ctx: Context<VaultCommitteeRejectTransaction>,
+    ) -> Result<()> {
+        transaction::committee::reject(ctx)
+    }
+
+    pub fn cancel_committee_transaction(
+        ctx: Context<VaultCommitteeCancelTransaction>,
+    ) -> Result<()> {
+        transaction::committee::cancel(ctx)
+    }
+
+    pub fn execute_committee_transaction(
+        ctx: Context<VaultCommitteeExecuteTransaction>,
+    ) -> Result<()> {
+        transaction::committee::execute(ctx)
+    }
+
+    pub fn close_committee_transaction(ctx: Context<VaultCommitteeCloseTransaction>) -> Result<()> {
+        transaction::committee::close(ctx)
+    }
+
+    /** TRANSACTION BUFFER INSTRUCTIONS */
+    pub fn create_transaction_buffer(
+        ctx: Context<VaultCreateTransactionBuffer>,
+        args: VaultCreateTransactionBufferArgs,
+    ) -> Result<()> {
+        transaction::buffer::create(ctx, args)
+    }
+
+    pub fn extend_transaction_buffer(
+        ctx: Context<VaultExtendTransactionBuffer>,
+        args: VaultExtendTransactionBufferArgs,
+    ) -> Result<()> {
+        transaction::buffer::extend(ctx, args)
+    }
+
+    pub fn close_transaction_buffer(ctx: Context<VaultCloseTransactionBuffer>) -> Result<()> {
+        transaction::buffer::close(ctx)
+    }
+
+    /** BATCH INSTRUCTIONS */
+    pub fn create_batch(ctx: Context<VaultCreateBatch>) -> Result<()> {
+        transaction::batch::create(ctx)
+    }
+
+    pub fn add_batch_transaction(
+        ctx: Context<VaultAddBatchTransaction>,
+        args: VaultAddBatchTransactionArgs,
+    ) -> Result<()> {
+        transaction::batch::add_transaction(ctx, args)
+    }
+
+    pub fn activate_batch(ctx: Context<VaultActivateBatch>) -> Result<()> {
+        transaction::batch::activate(ctx)
+    }
+
+    pub fn approve_batch(ctx: Context<VaultApproveBatch>) -> Result<()> {
+        transaction::batch::approve(ctx)
+    }
+
+    pub fn reject_batch(ctx: Context<VaultRejectBatch>) -> Result<()> {
+        transaction::batch::reject(ctx)
+    }
+
+    pub fn cancel_batch(ctx: Context<VaultCancelBatch>) -> Result<()> {
+        transaction::batch::cancel(ctx)
+    }
+
+    pub fn veto_batch(ctx: Context<VaultVetoBatch>) -> Result<()> {
+        transaction::batch::veto(ctx)
+    }
+
+    pub fn execute_batch_transaction(ctx: Context<VaultExecuteBatchTransaction>) -> Result<()> {
+        transaction::batch::execute_transaction(ctx)
+    }
+
+    pub fn close_batch_transaction(ctx: Context<VaultCloseBatchTransaction>) -> Result<()> {
+        transaction::batch::close_transaction(ctx)
+    }
+
+    pub fn close_batch(ctx: Context<VaultCloseBatch>) -> Result<()> {
+        transaction::batch::close(ctx)
+    }
+
+    /** TRANSACTION VALIDATION INSTRUCTIONS */
+    pub fn validate_transaction(ctx: Context<VaultValidateTransaction>) -> Result<()> {
+        transaction::validate(ctx)
+    }
+
+    /** TRANSACTION CLEANUP INSTRUCTIONS */
+    pub fn close_transactions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VaultCloseTransactions<'info>>,
+    ) -> Result<()> {
+        transaction::close_transactions(ctx)
+    }
+
+    pub fn close_legacy_transaction(ctx: Context<VaultCloseLegacyTransaction>) -> Result<()> {
+        transaction::close_legacy_transaction(ctx)
+    }
 }
@@ -0,0 +1,191 @@
+use super::{Vault, VoteTransaction};
+use crate::state::transaction_message::*;
+use anchor_lang::prelude::*;
+use solana_program::borsh0_10::get_instance_packed_len;
+
+/// Enum representing the status of a batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum VaultBatchStatus {
+    Draft,
+    Active,
+    Approved,
+    Rejected,
+    Cancelled,
+    Vetoed,
+    Executed,
+}
+
+/// Account grouping many transaction messages under a single founder vote.
+/// Messages are added while the batch is a draft and executed one by one once it is approved.
+#[account]
+pub struct VaultBatch {
+    // Creator of the batch, who pays for its transactions
+    pub creator: Pubkey,
+    // The vault this batch belongs to
+    pub vault: Pubkey,
+    // Used for seed, shared with the vault's founder transactions
+    pub transaction_index: u32,
+    // The status of the batch
+    pub status: VaultBatchStatus,
+    // Bump for the seed
+    pub bump: u8,
+    /// Derivation bump of the fund PDA this batch belongs to
+    pub fund_bump: u8,
+
+    // Number of transactions added to the batch
+    pub size: u32,
+    // Number of transactions executed so far, the next one to execute is at `executed_index + 1`
+    pub executed_index: u32,
+    // Number of transactions closed so far
+    pub closed_count: u32,
+
+    // Keys that have approved/signed
+    pub approved: Vec<Pubkey>,
+    // Keys that have rejected
+    pub rejected: Vec<Pubkey>,
+    // Keys that have cancelled (Approved only)
+    pub cancelled: Vec<Pubkey>,
+
+    // Unix timestamp at which the approval threshold was reached
+    pub approved_at: i64,
+    // Unix timestamp from which the approved batch can be executed
+    pub executable_at: i64,
+}
+
+impl VaultBatch {
+    /// Calculates the size of the batch account
+    pub fn size(founders_length: usize) -> usize {
+        8 +   // Anchor account discriminator
+        32 +  // Creator
+        32 +  // Vault
+        4 +   // Transaction index
+        (1 + std::mem::size_of::<VaultBatchStatus>()) + // Enum discriminator and size
+        1 +   // Bump
+        1 +   // Fund bump
+        4 +   // Size
+        4 +   // Executed index
+        4 +   // Closed count
+        3 * ( // Approved, rejected, cancelled
+            4 + // Vector discriminator
+            (32 * founders_length)
+        ) +
+        8 + // Approved at
+        8 // Executable at
+    }
+
+    /// Checks if a batch is valid based on its status and the vault's stale transaction index
+    pub fn is_transaction_valid(
+        &self,
+        transaction_status: &VaultBatchStatus,
+        vault: &Vault,
+    ) -> bool {
+        if self.status.ne(transaction_status) {
+            return false;
+        }
+
+        // Drafts and active batches become stale once the vault's roles or threshold change
+        !(matches!(
+            transaction_status,
+            VaultBatchStatus::Draft | VaultBatchStatus::Active
+        ) && self.transaction_index.le(&vault.stale_transaction_index))
+    }
+
+    /// Starts the time lock of the approved batch, using the vault's time lock at approval time
+    pub fn start_time_lock(&mut self, vault: &Vault, now: i64) {
+        self.approved_at = now;
+        self.executable_at = now.saturating_add(i64::from(vault.time_lock_seconds));
+    }
+
+    /// Checks if the time lock set when the batch was approved has elapsed
+    pub fn is_time_lock_elapsed(&self, now: i64) -> bool {
+        now >= self.executable_at
+    }
+
+    /// Checks if no more transactions of the batch can be executed
+    pub fn is_finished(&self, vault: &Vault) -> bool {
+        match self.status {
+            VaultBatchStatus::Executed
+            | VaultBatchStatus::Rejected
+            | VaultBatchStatus::Cancelled
+            | VaultBatchStatus::Vetoed => true,
+            VaultBatchStatus::Draft | VaultBatchStatus::Active => {
+                !self.is_transaction_valid(&self.status, vault)
+            }
+            VaultBatchStatus::Approved => false,
+        }
+    }
+
+    /// Checks if the batch is finished and all its transactions are closed
+    pub fn is_closable(&self, vault: &Vault) -> bool {
+        self.is_finished(vault) && self.closed_count == self.size
+    }
+}
+
+impl VoteTransaction for VaultBatch {
+    fn mark_approved(&mut self) {
+        self.status = VaultBatchStatus::Approved;
+    }
+
+    fn mark_rejected(&mut self) {
+        self.status = VaultBatchStatus::Rejected;
+    }
+
+    fn mark_cancelled(&mut self) {
+        self.status = VaultBatchStatus::Cancelled;
+    }
+
+    fn approved_mut(&mut self) -> &mut Vec<Pubkey> {
+        &mut self.approved
+    }
+
+    fn rejected_mut(&mut self) -> &mut Vec<Pubkey> {
+        &mut self.rejected
+    }
+
+    fn cancelled_mut(&mut self) -> &mut Vec<Pubkey> {
+        &mut self.cancelled
+    }
+}
+
+/// Enum representing the status of a transaction within a batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum VaultBatchTransactionStatus {
+    Pending,
+    Executed,
+}
+
+/// Account representing a single transaction message of a batch
+#[account]
+pub struct VaultBatchTransaction {
+    // The batch this transaction belongs to
+    pub batch: Pubkey,
+    // Position of the transaction in the batch, starting at 1
+    pub index: u32,
+    // Bump for the seed
+    pub bump: u8,
+    // The status of the transaction
+    pub status: VaultBatchTransactionStatus,
+    // Bumps of the ephemeral signer PDAs, see `VaultFounderTransaction`
+    pub ephemeral_signer_bumps: Vec<u8>,
+
+    // The transaction message
+    pub message: VaultTransactionMessage,
+}
+
+impl VaultBatchTransaction {
+    /// Calculates the size of the batch transaction account
+    pub fn size(ephemeral_signers_count: u8, transaction_message: &[u8]) -> Result<usize> {
+        let message = VaultTransactionMessage::try_from_bytes(transaction_message)?;
+        let message_size = get_instance_packed_len(&message)?;
+
+        Ok(
+            8 +   // Anchor account discriminator
+            32 +  // Batch
+            4 +   // Index
+            1 +   // Bump
+            (1 + std::mem::size_of::<VaultBatchTransactionStatus>()) + // Enum discriminator and size
+            (4 + usize::from(ephemeral_signers_count)) + // Ephemeral signer bumps vec
+            message_size, // Message
+        )
+    }
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use super::VoterSet;
+use crate::constants::*;
+use crate::errors::*;
+
+/// Account representing a committee of members voting on their own transactions
+#[account]
+pub struct VaultCommittee {
+    pub vault: Pubkey,          // The vault this committee belongs to
+    pub creator: Pubkey,        // Founder who created the committee and paid for its rent
+    pub committee_index: u32,   // Used for seed
+    pub bump: u8,               // PDA bump
+    pub fund_bump: u8,          // Derivation bump of the committee's own fund PDA
+    pub members: Vec<Pubkey>,   // Committee members list
+    pub threshold: u16,         // Minimum approvals required for committee transactions
+    pub transaction_index: u32, // Last transaction index created for this committee
+}
+
+impl VaultCommittee {
+    /// Calculates the size of the committee account based on its members
+    pub fn size(members: &[Pubkey]) -> usize {
+        8 +  // Anchor account discriminator
+        32 + // Vault
+        32 + // Creator
+        4 +  // Committee index
+        1 +  // PDA bump
+        1 +  // Fund bump
+        (4 + members.len() * 32) + // Members vector
+        2 +  // Threshold
+        4 // Transaction index
+    }
+
+    /// Validates that the committee's members and threshold are consistent
+    pub fn validate(&self) -> Result<()> {
+        let members_len = self.members.len();
+        require!(
+            members_len >= 1 && members_len <= usize::from(MAX_VAULT_ROLES),
+            VaultError::InvalidRoleCount
+        );
+
+        require!(
+            self.threshold >= 1 && usize::from(self.threshold) <= members_len,
+            VaultError::InvalidCommitteeThreshold
+        );
+        Ok(())
+    }
+
+    /// Committee seats outlive vault membership, so handlers also check `Vault::is_member`
+    pub fn is_member(&self, member: &Pubkey) -> bool {
+        self.members.binary_search(member).ok().is_some()
+    }
+}
+
+impl VoterSet for VaultCommittee {
+    fn vote_weight(&self, voter: &Pubkey) -> u64 {
+        u64::from(self.is_member(voter))
+    }
+
+    fn total_vote_weight(&self) -> u64 {
+        self.members.len() as u64
+    }
+
+    fn vote_threshold(&self) -> u64 {
+        u64::from(self.threshold)
+    }
+}
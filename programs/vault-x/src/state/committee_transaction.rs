@@ -0,0 +1,100 @@
+use super::{VaultTransactionStatus, VoteTransaction};
+use crate::state::transaction_message::*;
+use anchor_lang::prelude::*;
+use solana_program::borsh0_10::get_instance_packed_len;
+
+/// Account representing a transaction voted on by a committee and executed from the committee's fund
+#[account]
+pub struct VaultCommitteeTransaction {
+    // Creator of the transaction
+    pub creator: Pubkey,
+    // The vault this transaction belongs to
+    pub vault: Pubkey,
+    // The committee voting on this transaction
+    pub committee: Pubkey,
+    // Used for seed
+    pub transaction_index: u32,
+    // The status of the transaction
+    pub status: VaultTransactionStatus,
+    // Bump for the seed
+    pub bump: u8,
+    /// Derivation bump of the committee fund PDA this transaction belongs to
+    pub fund_bump: u8,
+    // Bumps of the ephemeral signer PDAs, see `VaultFounderTransaction`
+    pub ephemeral_signer_bumps: Vec<u8>,
+
+    // The transaction message
+    pub message: VaultTransactionMessage,
+
+    // Keys that have approved/signed
+    pub approved: Vec<Pubkey>,
+    // Keys that have rejected
+    pub rejected: Vec<Pubkey>,
+    // Keys that have cancelled (Approved only)
+    pub cancelled: Vec<Pubkey>,
+}
+
+impl VaultCommitteeTransaction {
+    /// Calculates the size of the committee transaction account
+    pub fn size(
+        ephemeral_signers_count: u8,
+        transaction_message: &[u8],
+        members_length: usize,
+    ) -> Result<usize> {
+        let message = VaultTransactionMessage::try_from_bytes(transaction_message)?;
+        let message_size = get_instance_packed_len(&message)?;
+
+        Ok(
+            8 +   // Anchor account discriminator
+            32 +  // Creator
+            32 +  // Vault
+            32 +  // Committee
+            4 +   // Transaction index
+            (1 + std::mem::size_of::<VaultTransactionStatus>()) + // Enum discriminator and size
+            1 +   // Bump
+            1 +   // Fund bump
+            (4 + usize::from(ephemeral_signers_count)) +   // Ephemeral signer bumps vec
+            message_size +
+            3 * ( // Approved, rejected, cancelled
+                4 + // Vector discriminator
+                (32 * members_length)
+            ), // Message
+        )
+    }
+
+    /// Checks if the transaction has reached a terminal state and its account can be closed
+    pub fn is_closable(&self) -> bool {
+        matches!(
+            self.status,
+            VaultTransactionStatus::Executed
+                | VaultTransactionStatus::Rejected
+                | VaultTransactionStatus::Cancelled
+        )
+    }
+}
+
+impl VoteTransaction for VaultCommitteeTransaction {
+    fn mark_approved(&mut self) {
+        self.status = VaultTransactionStatus::Approved;
+    }
+
+    fn mark_rejected(&mut self) {
+        self.status = VaultTransactionStatus::Rejected;
+    }
+
+    fn mark_cancelled(&mut self) {
+        self.status = VaultTransactionStatus::Cancelled;
+    }
+
+    fn approved_mut(&mut self) -> &mut Vec<Pubkey> {
+        &mut self.approved
+    }
+
+    fn rejected_mut(&mut self) -> &mut Vec<Pubkey> {
+        &mut self.rejected
+    }
+
+    fn cancelled_mut(&mut self) -> &mut Vec<Pubkey> {
+        &mut self.cancelled
+    }
+}
@@ -1,6 +1,8 @@
-use super::Vault;
+use super::{Vault, VoteTransaction};
+use crate::id;
+use crate::instruction::SetVetoCouncil;
 use crate::state::transaction_message::*;
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, Discriminator};
 use solana_program::borsh0_10::get_instance_packed_len;
 
 /// Enum representing the status of a Vault Transaction
@@ -11,6 +13,26 @@ pub enum VaultTransactionStatus {
     Rejected,
     Cancelled,
     Approved,
+    Expired,
+    Vetoed,
+    ExecutingPartial,
+}
+
+/// Point in time after which a founder transaction can no longer be voted on or executed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum VaultTransactionExpiry {
+    Timestamp(i64),
+    Slot(u64),
+}
+
+impl VaultTransactionExpiry {
+    /// Checks if the expiry has been reached at the given clock
+    pub fn is_reached(&self, clock: &Clock) -> bool {
+        match self {
+            VaultTransactionExpiry::Timestamp(timestamp) => clock.unix_timestamp >= *timestamp,
+            VaultTransactionExpiry::Slot(slot) => clock.slot >= *slot,
+        }
+    }
 }
 
 /// Account representing a founder transaction in the Vault
@@ -45,17 +67,45 @@ pub struct VaultFounderTransaction {
     pub rejected: Vec<Pubkey>,
     // Keys that have cancelled (ExecuteReady only)
     pub cancelled: Vec<Pubkey>,
+
+    // Unix timestamp at which the approval threshold was reached
+    pub approved_at: i64,
+    // Unix timestamp from which the approved transaction can be executed
+    pub executable_at: i64,
+
+    // Optional expiry after which the transaction is invalid
+    pub expiry: Option<VaultTransactionExpiry>,
+
+    // Index of the member transaction this transaction was escalated from, if any
+    pub escalated_from: Option<u32>,
+
+    // Number of message instructions executed so far, the next call resumes from there
+    pub executed_instructions: u16,
 }
 
 impl VaultFounderTransaction {
+    /// Accounts the message can't write to while the transaction executes
+    pub fn protected_accounts(vault: &Pubkey, transaction: &Pubkey) -> [Pubkey; 2] {
+        [*vault, *transaction]
+    }
+
     /// Calculates the size of the founder transaction account
     pub fn size(
         ephemeral_signers_count: u8,
-        transaction_message: &Vec<u8>,
+        transaction_message: &[u8],
+        founders_length: usize,
+    ) -> Result<usize> {
+        let message = VaultTransactionMessage::try_from_bytes(transaction_message)?;
+        Self::size_for_message(ephemeral_signers_count, &message, founders_length)
+    }
+
+    /// Calculates the size of a founder transaction account holding an already decoded message
+    pub fn size_for_message(
+        ephemeral_signers_count: u8,
+        message: &VaultTransactionMessage,
         founders_length: usize,
     ) -> Result<usize> {
-        let message = VaultTransactionMessage::deserialize(&mut transaction_message.as_slice())?;
-        let message_size = get_instance_packed_len(&message)?;
+        let message_size = get_instance_packed_len(message)?;
 
         Ok(
             8 +   // Anchor account discriminator
@@ -70,11 +120,16 @@ impl VaultFounderTransaction {
             3 * ( // Approved, rejected, cancelled
                 4 + // Vector discriminator
                 (32 * founders_length)
-            ), // Message
+            ) + // Message
+            8 + // Approved at
+            8 + // Executable at
+            (1 + 1 + 8) + // Optional expiry enum discriminator and value
+            (1 + 4) + // Optional escalated member transaction index
+            2, // Executed instructions
         )
     }
 
-    /// Checks if a transaction is valid based on its status and the vault's stale transaction index
+    /// Checks if a transaction is valid based on its status, expiry and the vault's stale transaction index
     pub fn is_transaction_valid(
         &self,
         transaction_status: &VaultTransactionStatus,
@@ -96,6 +151,101 @@ impl VaultFounderTransaction {
             return false;
         }
 
+        // A partially executed transaction has to run to completion, so it no longer expires
+        if transaction_status.ne(&VaultTransactionStatus::ExecutingPartial) && self.is_expired() {
+            return false;
+        }
+
         true
     }
+
+    /// Checks if the transaction's expiry has been reached
+    pub fn is_expired(&self) -> bool {
+        match (&self.expiry, Clock::get()) {
+            (Some(expiry), Ok(clock)) => expiry.is_reached(&clock),
+            _ => false,
+        }
+    }
+
+    /// Starts the time lock of the approved transaction, using the vault's time lock at approval time
+    pub fn start_time_lock(&mut self, vault: &Vault, now: i64) {
+        self.approved_at = now;
+        self.executable_at = now.saturating_add(i64::from(vault.time_lock_seconds));
+    }
+
+    /// Checks if the transaction only replaces the veto council, so guardians can't veto their own replacement.
+    /// Any other instruction in the message makes it vetoable, so a replacement can't shield a transfer.
+    pub fn is_veto_council_replacement(&self) -> bool {
+        let VaultTransactionMessage {
+            account_keys,
+            instructions,
+            ..
+        } = &self.message;
+
+        !instructions.is_empty()
+            && instructions.iter().all(|instruction| {
+                account_keys.get(usize::from(instruction.program_id_index)) == Some(&id())
+                    && instruction.data.starts_with(&SetVetoCouncil::DISCRIMINATOR)
+            })
+    }
+
+    /// Checks if the time lock set when the transaction was approved has elapsed
+    pub fn is_time_lock_elapsed(&self, now: i64) -> bool {
+        now >= self.executable_at
+    }
+
+    /// Checks if a transaction has reached a terminal state and its account can be closed
+    pub fn is_closable(&self, vault: &Vault) -> bool {
+        match self.status {
+            VaultTransactionStatus::Executed
+            | VaultTransactionStatus::Rejected
+            | VaultTransactionStatus::Cancelled
+            | VaultTransactionStatus::Expired
+            | VaultTransactionStatus::Vetoed => true,
+            // Active transactions become stale once the vault's roles or threshold change
+            VaultTransactionStatus::Active => {
+                !self.is_transaction_valid(&VaultTransactionStatus::Active, vault)
+            }
+            VaultTransactionStatus::Approved => self.is_expired(),
+            // Founders abandon the rest of the message by cancelling it first
+            VaultTransactionStatus::ExecutingPartial => false,
+        }
+    }
+}
+
+impl VoteTransaction for VaultFounderTransaction {
+    fn mark_approved(&mut self) {
+        self.status = VaultTransactionStatus::Approved;
+    }
+
+    fn mark_rejected(&mut self) {
+        self.status = VaultTransactionStatus::Rejected;
+    }
+
+    fn mark_cancelled(&mut self) {
+        self.status = VaultTransactionStatus::Cancelled;
+    }
+
+    fn approved_mut(&mut self) -> &mut Vec<Pubkey> {
+        &mut self.approved
+    }
+
+    fn rejected_mut(&mut self) -> &mut Vec<Pubkey> {
+        &mut self.rejected
+    }
+
+    fn cancelled_mut(&mut self) -> &mut Vec<Pubkey> {
+        &mut self.cancelled
+    }
+}
+
+/// Leading fields of the founder transactions created before the vault was migrated.
+/// Fields added since were inserted before the votes, so only these still read the same.
+#[derive(AnchorDeserialize)]
+pub struct VaultFounderTransactionLegacy {
+    pub creator: Pubkey,
+    pub vault: Pubkey,
+    pub transaction_index: u32,
+    pub status: VaultTransactionStatus,
+    pub bump: u8,
 }
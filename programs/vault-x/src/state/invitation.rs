@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+/// Enum representing the role a key can hold in a Vault
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum VaultRole {
+    Founder,
+    Member,
+}
+
+/// Account representing a pending invitation to join the Vault
+#[account]
+pub struct VaultInvitation {
+    pub vault: Pubkey,       // The vault the invitee is invited to
+    pub inviter: Pubkey,     // Founder or administrator who paid for the invitation's rent
+    pub invitee: Pubkey,     // Key that has to accept the invitation
+    pub role: VaultRole,     // Role granted on acceptance
+    pub founder_weight: u16, // Vote weight granted if invited as a founder
+    pub expires_at: i64,     // Unix timestamp after which the invitation can't be accepted
+    pub bump: u8,            // PDA bump
+}
+
+impl VaultInvitation {
+    /// Calculates the size of the invitation account
+    pub fn size() -> usize {
+        8 +  // Anchor account discriminator
+        32 + // Vault
+        32 + // Inviter
+        32 + // Invitee
+        (1 + std::mem::size_of::<VaultRole>()) + // Enum discriminator and size
+        2 +  // Founder weight
+        8 +  // Expires at
+        1 // PDA bump
+    }
+
+    /// Checks if the invitation can no longer be accepted
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+}
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::id;
+use crate::utils::*;
+
+/// Account limiting how many member transactions a member can create or execute per time window
+#[account]
+pub struct VaultMemberRateLimit {
+    pub vault: Pubkey,          // The vault this limit belongs to
+    pub member: Pubkey,         // The limited member
+    pub bump: u8,               // PDA bump
+    pub max_transactions: u32,  // Transactions allowed per window, 0 if unlimited
+    pub window_seconds: u32,    // Length of a window
+    pub window_started_at: i64, // Unix timestamp the current window started at
+    pub transaction_count: u32, // Transactions created or executed in the current window
+}
+
+impl VaultMemberRateLimit {
+    /// Calculates the size of the rate limit account
+    pub fn size() -> usize {
+        8 +  // Anchor account discriminator
+        32 + // Vault
+        32 + // Member
+        1 +  // PDA bump
+        4 +  // Max transactions
+        4 +  // Window seconds
+        8 +  // Window started at
+        4 // Transaction count
+    }
+
+    /// Counts a transaction against the limit, starting a new window if the current one is over
+    pub fn record_transaction(&mut self, now: i64) -> Result<()> {
+        if self.max_transactions == 0 {
+            return Ok(());
+        }
+
+        if now
+            >= self
+                .window_started_at
+                .saturating_add(i64::from(self.window_seconds))
+        {
+            self.window_started_at = now;
+            self.transaction_count = 0;
+        }
+
+        require!(
+            self.transaction_count < self.max_transactions,
+            VaultError::RateLimitExceeded
+        );
+        self.transaction_count += 1;
+
+        Ok(())
+    }
+
+    /// Counts a transaction against the member's limit if one has been set.
+    /// The account must already be checked to be the member's rate limit PDA.
+    pub fn consume(rate_limit: &AccountInfo, now: i64) -> Result<()> {
+        // Members without a rate limit account are unlimited
+        if rate_limit.data_is_empty() {
+            return Ok(());
+        }
+
+        require_keys_eq!(*rate_limit.owner, id(), VaultError::InvalidAccount);
+
+        let mut rate_limit_data = rate_limit.try_borrow_mut_data()?;
+        let mut member_rate_limit =
+            VaultMemberRateLimit::try_deserialize(&mut &rate_limit_data[..])?;
+        member_rate_limit.record_transaction(now)?;
+        member_rate_limit.try_serialize(&mut &mut rate_limit_data[..])?;
+
+        Ok(())
+    }
+
+    /// Writes the rate limit into its PDA, creating the account if needed
+    pub fn write<'info>(
+        &mut self,
+        rate_limit: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let (rate_limit_key, bump) = Pubkey::find_program_address(
+            &[
+                SEED_PREFIX,
+                self.vault.as_ref(),
+                SEED_MEMBER_RATE_LIMIT,
+                self.member.as_ref(),
+            ],
+            &id(),
+        );
+        require_keys_eq!(rate_limit.key(), rate_limit_key, VaultError::InvalidAccount);
+
+        self.bump = bump;
+
+        create_or_realloc_pda_account(
+            rate_limit,
+            VaultMemberRateLimit::size(),
+            &[
+                SEED_PREFIX,
+                self.vault.as_ref(),
+                SEED_MEMBER_RATE_LIMIT,
+                self.member.as_ref(),
+                &[bump],
+            ],
+            payer,
+            payer,
+            system_program,
+        )?;
+
+        let mut rate_limit_data = rate_limit.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut rate_limit_data[..])?;
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use super::{Vault, VaultTransactionStatus, VoteTransaction};
 use crate::state::transaction_message::*;
 use anchor_lang::prelude::*;
 use solana_program::borsh0_10::get_instance_packed_len;
@@ -11,6 +12,8 @@ pub struct VaultMemberTransaction {
     pub vault: Pubkey,
     // Used for seed
     pub transaction_index: u32,
+    // The status of the transaction, "Approved" from creation if member voting is disabled
+    pub status: VaultTransactionStatus,
     // Bump for the seed
     pub bump: u8,
     /// Derivation bump of the fund PDA this transaction belongs to
@@ -25,12 +28,38 @@ pub struct VaultMemberTransaction {
 
     // The transaction message
     pub message: VaultTransactionMessage,
+
+    // Members that have approved, only used when member voting is enabled
+    pub approved: Vec<Pubkey>,
+    // Members that have rejected
+    pub rejected: Vec<Pubkey>,
+    // Members that have cancelled (ExecuteReady only)
+    pub cancelled: Vec<Pubkey>,
+
+    // Number of times the transaction has been executed
+    pub execution_count: u32,
+    // Executions allowed before the transaction is "Executed", 0 for a standing order
+    pub max_executions: u32,
+    // Minimum delay between two executions
+    pub cooldown_seconds: u32,
+    // Unix timestamp of the last execution, 0 if never executed
+    pub last_executed_at: i64,
 }
 
 impl VaultMemberTransaction {
+    /// Accounts the message can't write to while the transaction executes
+    pub fn protected_accounts(vault: &Pubkey, transaction: &Pubkey) -> [Pubkey; 2] {
+        [*vault, *transaction]
+    }
+
     /// Calculates the size of the member transaction account
-    pub fn size(ephemeral_signers_count: u8, transaction_message: &Vec<u8>) -> Result<usize> {
-        let message = VaultTransactionMessage::deserialize(&mut transaction_message.as_slice())?;
+    /// Vote lists are only allocated for the given number of voters, 0 if member voting is disabled
+    pub fn size(
+        ephemeral_signers_count: u8,
+        transaction_message: &[u8],
+        voters_length: usize,
+    ) -> Result<usize> {
+        let message = VaultTransactionMessage::try_from_bytes(transaction_message)?;
         let message_size = get_instance_packed_len(&message)?;
 
         Ok(
@@ -38,10 +67,117 @@ impl VaultMemberTransaction {
             32 +  // Creator
             32 +  // Vault
             8 +   // Transaction index
+            (1 + std::mem::size_of::<VaultTransactionStatus>()) + // Enum discriminator and size
             1 +   // Bump
             1 +   // Fund bump
             (4 + usize::from(ephemeral_signers_count)) +   // Ephemeral signer bumps vec
-            message_size, // Transaction message
+            message_size + // Transaction message
+            3 * ( // Approved, rejected, cancelled
+                4 + // Vector discriminator
+                (32 * voters_length)
+            ) +
+            4 + // Execution count
+            4 + // Max executions
+            4 + // Cooldown seconds
+            8, // Last executed at
         )
     }
+
+    /// Checks if a transaction was created before the vault's last role change
+    pub fn is_stale(&self, vault: &Vault) -> bool {
+        self.transaction_index.le(&vault.stale_transaction_index)
+    }
+
+    /// Checks if the transaction was approved without any vote because member voting was disabled
+    /// when it was created. Such transactions are invalid while member voting is enabled.
+    pub fn is_auto_approved(&self) -> bool {
+        self.status.eq(&VaultTransactionStatus::Approved) && self.approved.is_empty()
+    }
+
+    /// Checks if the transaction has reached a terminal state, or was created before the
+    /// vault's last role change, so that anyone can close it
+    pub fn is_closable(&self, vault: &Vault) -> bool {
+        self.is_stale(vault)
+            || (self.is_auto_approved() && vault.is_member_voting_enabled())
+            || matches!(
+                self.status,
+                VaultTransactionStatus::Executed
+                    | VaultTransactionStatus::Rejected
+                    | VaultTransactionStatus::Cancelled
+            )
+    }
+
+    /// Checks if the cooldown since the last execution has elapsed
+    pub fn is_cooldown_elapsed(&self, now: i64) -> bool {
+        self.execution_count == 0
+            || now
+                >= self
+                    .last_executed_at
+                    .saturating_add(i64::from(self.cooldown_seconds))
+    }
+
+    /// Records an execution, moving the transaction to the "Executed" state once it runs out of executions
+    pub fn record_execution(&mut self, now: i64) {
+        self.execution_count = self.execution_count.saturating_add(1);
+        self.last_executed_at = now;
+
+        if self.max_executions > 0 && self.execution_count >= self.max_executions {
+            self.status = VaultTransactionStatus::Executed;
+        }
+    }
+
+    /// Checks if a transaction is valid based on its status and the vault's stale transaction index
+    pub fn is_transaction_valid(
+        &self,
+        transaction_status: &VaultTransactionStatus,
+        vault: &Vault,
+    ) -> bool {
+        if self.status.ne(transaction_status) {
+            return false;
+        }
+
+        // Enabling member voting invalidates the transactions approved without votes
+        if self.is_auto_approved() && vault.is_member_voting_enabled() {
+            return false;
+        }
+
+        // Votes are only collected on active transactions created after the last role change
+        !(transaction_status.eq(&VaultTransactionStatus::Active) && self.is_stale(vault))
+    }
+}
+
+impl VoteTransaction for VaultMemberTransaction {
+    fn mark_approved(&mut self) {
+        self.status = VaultTransactionStatus::Approved;
+    }
+
+    fn mark_rejected(&mut self) {
+        self.status = VaultTransactionStatus::Rejected;
+    }
+
+    fn mark_cancelled(&mut self) {
+        self.status = VaultTransactionStatus::Cancelled;
+    }
+
+    fn approved_mut(&mut self) -> &mut Vec<Pubkey> {
+        &mut self.approved
+    }
+
+    fn rejected_mut(&mut self) -> &mut Vec<Pubkey> {
+        &mut self.rejected
+    }
+
+    fn cancelled_mut(&mut self) -> &mut Vec<Pubkey> {
+        &mut self.cancelled
+    }
+}
+
+/// Leading fields of the member transactions created before the vault was migrated.
+/// The status was inserted after them, so only these still read the same.
+#[derive(AnchorDeserialize)]
+pub struct VaultMemberTransactionLegacy {
+    pub creator: Pubkey,
+    pub vault: Pubkey,
+    pub transaction_index: u32,
+    pub bump: u8,
 }
@@ -1,11 +1,31 @@
+pub mod batch;
+pub mod committee;
+pub mod committee_transaction;
 pub mod founder_transaction;
+pub mod invitation;
+pub mod member_rate_limit;
 pub mod member_transaction;
+pub mod recovery;
+pub mod transaction_buffer;
 pub mod transaction_message;
 pub mod vault;
 pub mod vault_config;
+pub mod vault_metadata;
+pub mod veto_council;
+pub mod voting;
 
+pub use batch::*;
+pub use committee::*;
+pub use committee_transaction::*;
 pub use founder_transaction::*;
+pub use invitation::*;
+pub use member_rate_limit::*;
 pub use member_transaction::*;
+pub use recovery::*;
+pub use transaction_buffer::*;
 pub use transaction_message::*;
 pub use vault::*;
 pub use vault_config::*;
+pub use vault_metadata::*;
+pub use veto_council::*;
+pub use voting::*;
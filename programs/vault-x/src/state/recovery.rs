@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::id;
+use crate::utils::*;
+
+/// Founder set proposed by the guardians to replace the current founders
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VaultRecoveryProposal {
+    pub proposer: Pubkey,              // Guardian who proposed the recovery
+    pub new_founders: Vec<Pubkey>,     // Founders replacing the current founder set
+    pub new_founder_weights: Vec<u16>, // Vote weights, parallel to the new founders
+    pub new_founder_threshold: u16,    // Founder threshold applied on recovery
+    pub approved: Vec<Pubkey>,         // Guardians who approved the recovery
+    pub proposed_at: i64,              // Unix timestamp the recovery was proposed at
+    pub approved_at: i64,              // Guardian threshold reached at, 0 if not
+}
+
+impl VaultRecoveryProposal {
+    /// Calculates the size of a proposal based on its new founders and the vault guardians
+    pub fn size(new_founders_len: usize, guardians_len: usize) -> usize {
+        32 + // Proposer
+        (4 + new_founders_len * 32) + // New founders vector
+        (4 + new_founders_len * 2) +  // New founder weights vector
+        2 + // New founder threshold
+        (4 + guardians_len * 32) + // Approved vector
+        8 + // Proposed at
+        8 // Approved at
+    }
+}
+
+/// Account holding the recovery guardians of a Vault, who can replace its founders
+/// once the founders have been inactive for long enough
+#[account]
+pub struct VaultRecovery {
+    pub vault: Pubkey,                           // The vault this recovery belongs to
+    pub bump: u8,                                // PDA bump
+    pub guardians: Vec<Pubkey>,                  // Guardians list
+    pub guardian_threshold: u16,                 // Minimum guardian approvals to recover the vault
+    pub inactivity_period_seconds: u32,          // Founder inactivity required before a recovery
+    pub time_lock_seconds: u32,                  // Delay between guardian approval and recovery
+    pub proposal: Option<VaultRecoveryProposal>, // Pending recovery, if any
+}
+
+impl VaultRecovery {
+    /// Calculates the size of the recovery account based on its guardians and pending proposal
+    pub fn size(guardians: &[Pubkey], proposal: Option<&VaultRecoveryProposal>) -> usize {
+        8 +  // Anchor account discriminator
+        32 + // Vault
+        1 +  // PDA bump
+        (4 + guardians.len() * 32) + // Guardians vector
+        2 + // Guardian threshold
+        4 + // Inactivity period seconds
+        4 + // Time lock seconds
+        1 + // Option discriminator
+        proposal.map_or(0, |proposal| {
+            VaultRecoveryProposal::size(proposal.new_founders.len(), guardians.len())
+        })
+    }
+
+    /// Validates the guardians configuration
+    pub fn validate(&self) -> Result<()> {
+        let guardians_len = self.guardians.len();
+        require!(
+            guardians_len >= 1 && guardians_len <= usize::from(MAX_VAULT_ROLES),
+            VaultError::InvalidRoleCount
+        );
+        require!(
+            self.guardian_threshold >= 1 && usize::from(self.guardian_threshold) <= guardians_len,
+            VaultError::InvalidGuardianThreshold
+        );
+        require!(
+            self.inactivity_period_seconds > 0,
+            VaultError::InvalidInactivityPeriod
+        );
+        Ok(())
+    }
+
+    pub fn is_guardian(&self, guardian: &Pubkey) -> bool {
+        self.guardians.binary_search(guardian).ok().is_some()
+    }
+
+    /// Checks if the vault founders have been inactive for long enough to allow a recovery
+    pub fn is_recovery_available(&self, last_activity_at: i64, now: i64) -> bool {
+        now >= last_activity_at.saturating_add(i64::from(self.inactivity_period_seconds))
+    }
+
+    /// Writes the recovery into its PDA, creating or reallocating the account to fit the contents
+    pub fn write<'info>(
+        &mut self,
+        vault_recovery: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let (vault_recovery_key, bump) =
+            Pubkey::find_program_address(&[SEED_PREFIX, self.vault.as_ref(), SEED_RECOVERY], &id());
+        require_keys_eq!(
+            vault_recovery.key(),
+            vault_recovery_key,
+            VaultError::InvalidAccount
+        );
+
+        self.bump = bump;
+        self.validate()?;
+
+        let required_size = VaultRecovery::size(&self.guardians, self.proposal.as_ref());
+
+        create_or_realloc_pda_account(
+            vault_recovery,
+            required_size,
+            &[SEED_PREFIX, self.vault.as_ref(), SEED_RECOVERY, &[bump]],
+            payer,
+            payer,
+            system_program,
+        )?;
+
+        let mut vault_recovery_data = vault_recovery.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut vault_recovery_data[..])?;
+
+        Ok(())
+    }
+}
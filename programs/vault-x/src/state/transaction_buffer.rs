@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::constants::*;
+use crate::errors::*;
+
+/// Account holding a transaction message uploaded in chunks, for messages too large to pass inline
+#[account]
+pub struct VaultTransactionBuffer {
+    pub vault: Pubkey,               // The vault the message is for
+    pub creator: Pubkey,             // Creator of the buffer and of the transaction using it
+    pub buffer_index: u8,            // Used for seed, allows several buffers per creator
+    pub bump: u8,                    // PDA bump
+    pub final_buffer_hash: [u8; 32], // SHA-256 hash of the complete message
+    pub final_buffer_size: u16,      // Size of the complete message
+    pub buffer: Vec<u8>,             // Message bytes uploaded so far
+}
+
+impl VaultTransactionBuffer {
+    /// Calculates the size of the buffer account, allocated up front for the complete message
+    pub fn size(final_buffer_size: u16) -> usize {
+        8 +  // Anchor account discriminator
+        32 + // Vault
+        32 + // Creator
+        1 +  // Buffer index
+        1 +  // PDA bump
+        32 + // Final buffer hash
+        2 +  // Final buffer size
+        (4 + usize::from(final_buffer_size)) // Buffer vector
+    }
+
+    /// Validates the announced size and the chunks uploaded so far
+    pub fn validate_size(&self) -> Result<()> {
+        require!(
+            usize::from(self.final_buffer_size) <= MAX_TRANSACTION_BUFFER_SIZE,
+            VaultError::InvalidTransactionBufferSize
+        );
+        require!(
+            self.buffer.len() <= usize::from(self.final_buffer_size),
+            VaultError::InvalidTransactionBufferSize
+        );
+
+        Ok(())
+    }
+
+    /// Returns the inline transaction message, or the buffered one once its upload is complete
+    pub fn resolve_message(
+        transaction_buffer: Option<&VaultTransactionBuffer>,
+        transaction_message: Vec<u8>,
+    ) -> Result<Vec<u8>> {
+        let Some(transaction_buffer) = transaction_buffer else {
+            return Ok(transaction_message);
+        };
+
+        // The message is either passed inline or uploaded, never both
+        require!(
+            transaction_message.is_empty(),
+            VaultError::InvalidVaultTransactionMessage
+        );
+        transaction_buffer.validate_hash()?;
+
+        Ok(transaction_buffer.buffer.clone())
+    }
+
+    /// Checks that the upload is complete and matches the announced hash
+    pub fn validate_hash(&self) -> Result<()> {
+        require!(
+            self.buffer.len() == usize::from(self.final_buffer_size),
+            VaultError::InvalidTransactionBufferSize
+        );
+        require!(
+            hash(&self.buffer).to_bytes() == self.final_buffer_hash,
+            VaultError::InvalidTransactionBufferHash
+        );
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use crate::errors::*;
 use anchor_lang::prelude::*;
+use solana_program::address_lookup_table::{self, state::AddressLookupTable};
 use solana_program::instruction::Instruction;
 
 /// Represents an instruction within a transaction
@@ -10,6 +11,21 @@ pub struct VaultInstruction {
     pub data: Vec<u8>,
 }
 
+/// Represents the keys loaded from an address lookup table, as in v0 messages
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VaultMessageAddressTableLookup {
+    /// The address lookup table account
+    pub account_key: Pubkey,
+    /// Indexes of the writable keys loaded from the table
+    pub writable_indexes: Vec<u8>,
+    /// Indexes of the readonly keys loaded from the table
+    pub readonly_indexes: Vec<u8>,
+}
+
+/// Prefix of the transaction messages sent with address table lookups, as in v0 messages.
+/// Messages sent without it are decoded with the legacy layout, which has no lookups.
+pub const VAULT_TRANSACTION_MESSAGE_V0_PREFIX: u8 = 0x80;
+
 /// Represents a message containing instructions for a transaction
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct VaultTransactionMessage {
@@ -35,9 +51,52 @@ pub struct VaultTransactionMessage {
 
     /// The instructions that make up the transaction
     pub instructions: Vec<VaultInstruction>,
+
+    /// The address lookup tables the transaction loads keys from.
+    /// Loaded keys are indexed after the account keys, the writable ones of every table first,
+    /// then the readonly ones, and can never be signers.
+    pub address_table_lookups: Vec<VaultMessageAddressTableLookup>,
+}
+
+/// Layout of the transaction messages sent before address table lookups were supported
+#[derive(AnchorDeserialize)]
+struct VaultLegacyTransactionMessage {
+    num_signers: u8,
+    num_writable_signers: u8,
+    num_writable_non_signers: u8,
+    account_keys: Vec<Pubkey>,
+    instructions: Vec<VaultInstruction>,
 }
 
 impl VaultTransactionMessage {
+    /// Decodes a transaction message sent by a client.
+    /// Messages prefixed with `VAULT_TRANSACTION_MESSAGE_V0_PREFIX` carry address table lookups,
+    /// any other message uses the legacy layout.
+    pub fn try_from_bytes(transaction_message: &[u8]) -> Result<Self> {
+        if let Some((&VAULT_TRANSACTION_MESSAGE_V0_PREFIX, mut message)) =
+            transaction_message.split_first()
+        {
+            return Ok(Self::deserialize(&mut message)?);
+        }
+
+        let VaultLegacyTransactionMessage {
+            num_signers,
+            num_writable_signers,
+            num_writable_non_signers,
+            account_keys,
+            instructions,
+        } = VaultLegacyTransactionMessage::deserialize(&mut &transaction_message[..])?;
+
+        Ok(Self {
+            num_signers,
+            num_writable_signers,
+            num_writable_non_signers,
+            account_keys,
+            instructions,
+            address_table_lookups: vec![],
+        })
+    }
+
     /// Validates the transaction message structure
     pub fn is_valid(&self) -> Result<()> {
         let VaultTransactionMessage {
@@ -50,10 +109,12 @@ impl VaultTransactionMessage {
         } = self;
 
         let account_keys_len = account_keys.len();
+        let num_all_account_keys = self.num_all_account_keys();
 
-        // 1. Check total signers length
+        // 1. Check total signers length, legacy messages can't start with the version prefix
         require!(
-            usize::from(*num_signers) <= account_keys_len,
+            usize::from(*num_signers) <= account_keys_len
+                && *num_signers < VAULT_TRANSACTION_MESSAGE_V0_PREFIX,
             VaultError::InvalidVaultTransactionMessage
         );
 
@@ -72,7 +133,13 @@ impl VaultTransactionMessage {
             VaultError::InvalidVaultTransactionMessage
         );
 
-        // 4. Check all instructions
+        // 4. Check that every key, static or loaded, can be referenced by a u8 index
+        require!(
+            num_all_account_keys <= usize::from(u8::MAX) + 1,
+            VaultError::InvalidVaultTransactionMessage
+        );
+
+        // 5. Check all instructions
         for instruction in instructions {
             let VaultInstruction {
                 program_id_index,
@@ -82,14 +149,14 @@ impl VaultTransactionMessage {
 
             // 1. Check if the program_id_index is within bounds
             require!(
-                usize::from(*program_id_index) < account_keys_len,
+                usize::from(*program_id_index) < num_all_account_keys,
                 VaultError::InvalidVaultTransactionMessage
             );
 
             for account_index in account_indexes {
                 // 2. Check each account's index boundary inside current instruction
                 require!(
-                    usize::from(*account_index) < account_keys_len,
+                    usize::from(*account_index) < num_all_account_keys,
                     VaultError::InvalidVaultTransactionMessage
                 );
             }
@@ -98,14 +165,27 @@ impl VaultTransactionMessage {
         Ok(())
     }
 
-    /// Validates the transaction message account infos against the expected keys and attributes
-    pub fn validate_message_account_infos(
+    /// Validates the transaction message account infos against the expected keys and attributes.
+    /// The address lookup tables come first in the account infos, followed by the message accounts,
+    /// which are returned once validated.
+    pub fn validate_message_account_infos<'a, 'info>(
         &self,
-        account_infos: &[AccountInfo],
+        account_infos: &'a [AccountInfo<'info>],
         fund_key: &Pubkey,
         ephemeral_signer_keys: &[Pubkey],
-    ) -> Result<()> {
-        let VaultTransactionMessage { account_keys, .. } = self;
+    ) -> Result<&'a [AccountInfo<'info>]> {
+        let address_table_lookups_len = self.address_table_lookups.len();
+
+        require!(
+            account_infos.len() >= address_table_lookups_len,
+            VaultError::InvalidNumberOfAccounts
+        );
+
+        let (lookup_table_account_infos, account_infos) =
+            account_infos.split_at(address_table_lookups_len);
+
+        // Static keys followed by the keys loaded from the address lookup tables
+        let account_keys = self.resolve_account_keys(lookup_table_account_infos)?;
 
         let account_keys_len = account_keys.len();
         let account_infos_len = account_infos.len();
@@ -147,7 +227,60 @@ impl VaultTransactionMessage {
             }
         }
 
-        Ok(())
+        Ok(account_infos)
+    }
+
+    /// Resolves every key of the message, loading the keys referenced in the address lookup tables
+    pub fn resolve_account_keys(
+        &self,
+        lookup_table_account_infos: &[AccountInfo],
+    ) -> Result<Vec<Pubkey>> {
+        let mut writable_keys = vec![];
+        let mut readonly_keys = vec![];
+
+        for (address_table_lookup, lookup_table_account_info) in self
+            .address_table_lookups
+            .iter()
+            .zip(lookup_table_account_infos)
+        {
+            require_keys_eq!(
+                *lookup_table_account_info.key,
+                address_table_lookup.account_key,
+                VaultError::InvalidAddressLookupTable
+            );
+            require_keys_eq!(
+                *lookup_table_account_info.owner,
+                address_lookup_table::program::ID,
+                VaultError::InvalidAddressLookupTable
+            );
+
+            let lookup_table_data = lookup_table_account_info.try_borrow_data()?;
+            let lookup_table = AddressLookupTable::deserialize(&lookup_table_data)
+                .map_err(|_| error!(VaultError::InvalidAddressLookupTable))?;
+
+            let load_key = |index: &u8| {
+                lookup_table
+                    .addresses
+                    .get(usize::from(*index))
+                    .copied()
+                    .ok_or(error!(VaultError::InvalidAddressLookupTable))
+            };
+
+            for index in address_table_lookup.writable_indexes.iter() {
+                writable_keys.push(load_key(index)?);
+            }
+            for index in address_table_lookup.readonly_indexes.iter() {
+                readonly_keys.push(load_key(index)?);
+            }
+        }
+
+        Ok(self
+            .account_keys
+            .iter()
+            .copied()
+            .chain(writable_keys)
+            .chain(readonly_keys)
+            .collect())
     }
 
     /// Retrieves the instructions with ordered account infos for execution
@@ -216,7 +349,29 @@ impl VaultTransactionMessage {
 
     /// Checks if the given instruction index is within bounds
     pub fn check_ix_account_index_boundary(&self, instruction_index: usize) -> bool {
-        instruction_index < self.account_keys.len()
+        instruction_index < self.num_all_account_keys()
+    }
+
+    /// Number of keys loaded as writable from the address lookup tables
+    pub fn num_writable_lookup_keys(&self) -> usize {
+        self.address_table_lookups
+            .iter()
+            .map(|address_table_lookup| address_table_lookup.writable_indexes.len())
+            .sum()
+    }
+
+    /// Number of static keys and keys loaded from the address lookup tables
+    pub fn num_all_account_keys(&self) -> usize {
+        let num_lookup_keys: usize = self
+            .address_table_lookups
+            .iter()
+            .map(|address_table_lookup| {
+                address_table_lookup.writable_indexes.len()
+                    + address_table_lookup.readonly_indexes.len()
+            })
+            .sum();
+
+        self.account_keys.len() + num_lookup_keys
     }
 
     /// Checks if the account index corresponds to a signer
@@ -233,6 +388,13 @@ impl VaultTransactionMessage {
             ..
         } = self;
 
+        // Check if account_key_index falls under the keys loaded from the address lookup tables,
+        // the writable ones coming first
+        let account_keys_len = self.account_keys.len();
+        if account_key_index.ge(&account_keys_len) {
+            return account_key_index.lt(&(account_keys_len + self.num_writable_lookup_keys()));
+        }
+
         let num_signers_usize = usize::from(*num_signers);
         let num_writable_signers_usize = &usize::from(*num_writable_signers);
         let num_writable_non_signers_usize = usize::from(*num_writable_non_signers);
@@ -1,5 +1,6 @@
-use anchor_lang::{prelude::*, system_program};
+use anchor_lang::{prelude::*, system_program, Discriminator};
 
+use super::{VaultTransactionExpiry, VoterSet};
 use crate::constants::*;
 use crate::errors::*;
 
@@ -13,8 +14,18 @@ pub struct Vault {
     pub founders: Vec<Pubkey>,          // Founders list
     pub members: Vec<Pubkey>,           // Members list
     pub stale_transaction_index: u32,   // Transactions created before this index are invalid
-    pub founder_threshold: u16,         // Minimum approvals required for founder transactions
+    pub founder_threshold: u16,         // Minimum approval weight for founder transactions
     pub transaction_index: u32,         // Last transaction index created for this vault
+    pub version: u8,                    // Layout version, legacy vaults have none until migrated
+    pub founder_weights: Vec<u16>,      // Founder vote weights, parallel to founders
+    pub committee_index: u32,           // Last committee index created for this vault
+    pub time_lock_seconds: u32,         // Delay between founder approval and execution
+    pub max_expiry_seconds: u32,        // Max transaction lifetime in seconds, 0 if unbounded
+    pub max_expiry_slots: u64,          // Max transaction lifetime in slots, 0 if unbounded
+    pub rent_collector: Pubkey,         // Receives the rent refunded when the vault shrinks
+    pub last_activity_at: i64,          // Last founder vote or execution, for recovery
+    pub member_threshold: u16,          // Approvals required for member transactions, 0 if none
+    pub legacy_transaction_index: u32, // Last transaction created with the legacy layout, 0 if none
 }
 
 impl Vault {
@@ -29,17 +40,40 @@ impl Vault {
         (4 + members.len() * 32) +  // Members vector
         4 + // Stale transaction index
         2 + // Founder threshold
-        4 // Transaction index
+        4 + // Transaction index
+        1 + // Version
+        (4 + founders.len() * 2) + // Founder weights vector
+        4 + // Committee index
+        4 + // Time lock seconds
+        4 + // Max expiry seconds
+        8 + // Max expiry slots
+        32 + // Rent collector
+        8 + // Last activity at
+        2 + // Member threshold
+        4 // Legacy transaction index
     }
 
     /// Validates that the vault's configuration is consistent and correct
     pub fn validate(&self) -> Result<()> {
-        // Founder threshold must not exceed the number of founders
+        // Every founder must carry a non-zero vote weight
         require!(
-            usize::from(self.founder_threshold) <= self.founders.len(),
+            self.founder_weights.len() == self.founders.len()
+                && self.founder_weights.iter().all(|weight| *weight > 0),
+            VaultError::InvalidFounderWeight
+        );
+
+        // Founder threshold must not exceed the total founder vote weight
+        require!(
+            u64::from(self.founder_threshold) <= self.total_vote_weight(),
             VaultError::InvalidFounderThreshold
         );
 
+        // Member threshold must be reachable by the current members
+        require!(
+            usize::from(self.member_threshold) <= self.members.len(),
+            VaultError::InvalidMemberThreshold
+        );
+
         // Stale transaction index must be less than or equal to transaction index
         require!(
             self.stale_transaction_index <= self.transaction_index,
@@ -48,14 +82,114 @@ impl Vault {
         Ok(())
     }
 
+    /// Resolves a founder transaction expiry, capping it by the vault's maximum lifetime.
+    /// Transactions must expire if a maximum lifetime is set, defaulting to that maximum.
+    pub fn resolve_transaction_expiry(
+        &self,
+        expiry: Option<VaultTransactionExpiry>,
+        clock: &Clock,
+    ) -> Result<Option<VaultTransactionExpiry>> {
+        let max_timestamp = (self.max_expiry_seconds > 0).then(|| {
+            clock
+                .unix_timestamp
+                .saturating_add(i64::from(self.max_expiry_seconds))
+        });
+        let max_slot =
+            (self.max_expiry_slots > 0).then(|| clock.slot.saturating_add(self.max_expiry_slots));
+
+        let expiry = match expiry {
+            Some(VaultTransactionExpiry::Timestamp(timestamp)) => {
+                require!(
+                    timestamp > clock.unix_timestamp
+                        && max_timestamp.map_or(max_slot.is_none(), |max| timestamp <= max),
+                    VaultError::InvalidTransactionExpiry
+                );
+                Some(VaultTransactionExpiry::Timestamp(timestamp))
+            }
+            Some(VaultTransactionExpiry::Slot(slot)) => {
+                require!(
+                    slot > clock.slot
+                        && max_slot.map_or(max_timestamp.is_none(), |max| slot <= max),
+                    VaultError::InvalidTransactionExpiry
+                );
+                Some(VaultTransactionExpiry::Slot(slot))
+            }
+            None => max_timestamp
+                .map(VaultTransactionExpiry::Timestamp)
+                .or_else(|| max_slot.map(VaultTransactionExpiry::Slot)),
+        };
+
+        Ok(expiry)
+    }
+
+    /// Records a founder vote or execution, postponing any recovery of the vault
+    pub fn record_founder_activity(&mut self) -> Result<()> {
+        self.last_activity_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Checks if the vault uses the current layout, legacy vaults must be migrated first.
+    /// Legacy vaults can hold stale bytes where the version is now, left by removed founders or members,
+    /// so the founder weights, only written by the current layout, must also line up with the founders.
+    pub fn is_migrated(&self) -> bool {
+        self.version == VAULT_LAYOUT_VERSION && self.founder_weights.len() == self.founders.len()
+    }
+
     pub fn is_founder(&self, founder: &Pubkey) -> bool {
         self.founders.binary_search(founder).ok().is_some()
     }
 
+    /// Inserts a founder along with its vote weight, keeping both lists aligned
+    pub fn insert_founder(&mut self, founder: Pubkey, weight: u16) -> Result<()> {
+        require!(weight > 0, VaultError::InvalidFounderWeight);
+
+        match self.founders.binary_search(&founder) {
+            Ok(_) => err!(VaultError::FounderAlreadyExists),
+            Err(founder_index) => {
+                self.founders.insert(founder_index, founder);
+                self.founder_weights.insert(founder_index, weight);
+                Ok(())
+            }
+        }
+    }
+
+    /// Removes a founder along with its vote weight
+    pub fn remove_founder(&mut self, founder: &Pubkey) -> Result<()> {
+        match self.founders.binary_search(founder) {
+            Ok(founder_index) => {
+                self.founders.remove(founder_index);
+                self.founder_weights.remove(founder_index);
+                Ok(())
+            }
+            Err(_) => err!(VaultError::FounderNotFound),
+        }
+    }
+
     pub fn is_member(&self, member: &Pubkey) -> bool {
         self.members.binary_search(member).ok().is_some()
     }
 
+    /// Checks if member transactions have to be approved by members before being executed
+    pub fn is_member_voting_enabled(&self) -> bool {
+        self.member_threshold > 0
+    }
+
+    /// Lowers the member threshold to the number of members left, returning whether it changed
+    pub fn clamp_member_threshold(&mut self) -> bool {
+        let max_member_threshold = u16::try_from(self.members.len()).unwrap_or(u16::MAX);
+        if self.member_threshold <= max_member_threshold {
+            return false;
+        }
+
+        self.member_threshold = max_member_threshold;
+        true
+    }
+
+    /// Members of the vault as the voters of member transactions
+    pub fn member_voters(&self) -> VaultMemberVoters<'_> {
+        VaultMemberVoters(self)
+    }
+
     pub fn check_for_admin_presence(&self, role_array: &[Pubkey]) -> bool {
         // Admin cannot be a founder/member
         role_array.binary_search(&self.administrator).ok().is_some()
@@ -122,4 +256,140 @@ impl Vault {
 
         Ok(true)
     }
+
+    /// Shrinks a vault account down to the size its founders and members require,
+    /// refunding the excess rent to the rent collector
+    pub fn shrink_if_needed<'info>(
+        vault: AccountInfo<'info>,
+        founders: &[Pubkey],
+        members: &[Pubkey],
+        rent_collector: Option<AccountInfo<'info>>,
+    ) -> Result<bool> {
+        let vault_current_size = vault.data.borrow().len();
+        let required_size = Vault::size(founders, members);
+
+        // Check if there is space to reclaim
+        if vault_current_size <= required_size {
+            return Ok(false);
+        }
+
+        // Without a rent collector the dead space is kept until the vault is compacted
+        let Some(rent_collector) = rent_collector else {
+            return Ok(false);
+        };
+
+        // Reallocate less space
+        msg!("Shrinking vault's space");
+        AccountInfo::realloc(&vault, required_size, false)?;
+
+        // Refund the lamports that are no longer required for rent exemption
+        let rent = Rent::get()?;
+        let required_lamports = rent.minimum_balance(required_size).max(1);
+        let lamports_diff = vault.lamports().saturating_sub(required_lamports);
+
+        if lamports_diff > 0 {
+            **vault.try_borrow_mut_lamports()? -= lamports_diff;
+            **rent_collector.try_borrow_mut_lamports()? = rent_collector
+                .lamports()
+                .checked_add(lamports_diff)
+                .unwrap();
+        }
+
+        Ok(true)
+    }
+}
+
+impl VoterSet for Vault {
+    fn vote_weight(&self, voter: &Pubkey) -> u64 {
+        self.founders
+            .binary_search(voter)
+            .map_or(0, |founder_index| {
+                u64::from(self.founder_weights[founder_index])
+            })
+    }
+
+    fn total_vote_weight(&self) -> u64 {
+        self.founder_weights.iter().copied().map(u64::from).sum()
+    }
+
+    fn vote_threshold(&self) -> u64 {
+        u64::from(self.founder_threshold)
+    }
+}
+
+/// Members of a Vault voting on member transactions, each carrying a single vote
+pub struct VaultMemberVoters<'a>(&'a Vault);
+
+impl VoterSet for VaultMemberVoters<'_> {
+    fn vote_weight(&self, voter: &Pubkey) -> u64 {
+        u64::from(self.0.is_member(voter))
+    }
+
+    fn total_vote_weight(&self) -> u64 {
+        self.0.members.len() as u64
+    }
+
+    fn vote_threshold(&self) -> u64 {
+        u64::from(self.0.member_threshold)
+    }
+}
+
+/// Layout of the vaults created before the layout was versioned.
+/// Its fields are the leading fields of the current layout.
+#[derive(AnchorDeserialize)]
+pub struct VaultLegacy {
+    pub allow_list_merkle_tree: Pubkey,
+    pub administrator: Pubkey,
+    pub bump: u8,
+    pub create_key: Pubkey,
+    pub founders: Vec<Pubkey>,
+    pub members: Vec<Pubkey>,
+    pub stale_transaction_index: u32,
+    pub founder_threshold: u16,
+    pub transaction_index: u32,
+}
+
+impl VaultLegacy {
+    /// Deserializes the legacy fields of a vault account.
+    /// The bytes following them are whatever the account held before, so the account is only
+    /// known to use the current layout if it deserializes as a consistent migrated `Vault`.
+    pub fn try_deserialize(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() > 8 && data[..8] == Vault::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        let is_migrated = Vault::try_deserialize(&mut &data[..])
+            .is_ok_and(|vault| vault.is_migrated() && vault.validate().is_ok());
+        require!(!is_migrated, VaultError::VaultAlreadyMigrated);
+
+        Ok(Self::deserialize(&mut &data[8..])?)
+    }
+
+    /// Converts the legacy vault into the current layout.
+    /// Every founder gets a vote weight of 1, which keeps the founder threshold meaning unchanged.
+    /// Every transaction created so far keeps the legacy layout and can only be closed.
+    pub fn migrate(self, last_activity_at: i64) -> Vault {
+        Vault {
+            allow_list_merkle_tree: self.allow_list_merkle_tree,
+            administrator: self.administrator,
+            bump: self.bump,
+            create_key: self.create_key,
+            founder_weights: vec![1; self.founders.len()],
+            founders: self.founders,
+            members: self.members,
+            stale_transaction_index: self.stale_transaction_index,
+            founder_threshold: self.founder_threshold,
+            transaction_index: self.transaction_index,
+            version: VAULT_LAYOUT_VERSION,
+            committee_index: 0,
+            time_lock_seconds: 0,
+            max_expiry_seconds: 0,
+            max_expiry_slots: 0,
+            rent_collector: self.administrator,
+            last_activity_at,
+            member_threshold: 0,
+            legacy_transaction_index: self.transaction_index,
+        }
+    }
 }
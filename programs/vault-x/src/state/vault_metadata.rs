@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::id;
+use crate::utils::*;
+
+/// Account holding the displayable metadata of a Vault
+#[account]
+pub struct VaultMetadata {
+    pub vault: Pubkey,       // The vault this metadata belongs to
+    pub bump: u8,            // PDA bump
+    pub name: String,        // Display name
+    pub description: String, // Free-form description
+    pub image_uri: String,   // Image or logo URI
+    pub website: String,     // Website URL
+    pub tags: Vec<String>,   // Free-form tags
+}
+
+impl VaultMetadata {
+    /// Calculates the size of the metadata account based on its contents
+    pub fn size(
+        name: &str,
+        description: &str,
+        image_uri: &str,
+        website: &str,
+        tags: &[String],
+    ) -> usize {
+        8 +  // Anchor account discriminator
+        32 + // Vault
+        1 +  // PDA bump
+        (4 + name.len()) +        // Name
+        (4 + description.len()) + // Description
+        (4 + image_uri.len()) +   // Image URI
+        (4 + website.len()) +     // Website
+        (4 + tags.iter().map(|tag| 4 + tag.len()).sum::<usize>()) // Tags vector
+    }
+
+    /// Validates the metadata fields against their length limits
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.name.len() <= MAX_METADATA_NAME_LENGTH
+                && self.description.len() <= MAX_METADATA_DESCRIPTION_LENGTH
+                && self.image_uri.len() <= MAX_METADATA_URI_LENGTH
+                && self.website.len() <= MAX_METADATA_URI_LENGTH
+                && self.tags.len() <= MAX_METADATA_TAGS
+                && self
+                    .tags
+                    .iter()
+                    .all(|tag| tag.len() <= MAX_METADATA_TAG_LENGTH),
+            VaultError::InvalidMetadata
+        );
+        Ok(())
+    }
+
+    /// Writes the metadata into its PDA, creating or reallocating the account to fit the contents
+    pub fn write<'info>(
+        &mut self,
+        vault_metadata: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        rent_collector: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let (vault_metadata_key, bump) = Pubkey::find_program_address(
+            &[SEED_PREFIX, self.vault.as_ref(), SEED_VAULT_METADATA],
+            &id(),
+        );
+        require_keys_eq!(
+            vault_metadata.key(),
+            vault_metadata_key,
+            VaultError::InvalidAccount
+        );
+
+        self.bump = bump;
+        self.validate()?;
+
+        let required_size = VaultMetadata::size(
+            &self.name,
+            &self.description,
+            &self.image_uri,
+            &self.website,
+            &self.tags,
+        );
+
+        create_or_realloc_pda_account(
+            vault_metadata,
+            required_size,
+            &[
+                SEED_PREFIX,
+                self.vault.as_ref(),
+                SEED_VAULT_METADATA,
+                &[bump],
+            ],
+            payer,
+            rent_collector,
+            system_program,
+        )?;
+
+        let mut vault_metadata_data = vault_metadata.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut vault_metadata_data[..])?;
+
+        Ok(())
+    }
+}
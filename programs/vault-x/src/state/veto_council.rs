@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::id;
+use crate::utils::*;
+
+/// Account holding the guardians of a Vault who can veto founder transactions during
+/// their time lock, without being able to create or approve any
+#[account]
+pub struct VaultVetoCouncil {
+    pub vault: Pubkey,           // The vault this council belongs to
+    pub bump: u8,                // PDA bump
+    pub guardians: Vec<Pubkey>,  // Guardians list
+    pub guardian_threshold: u16, // Minimum guardians co-signing a veto
+}
+
+impl VaultVetoCouncil {
+    /// Calculates the size of the veto council account based on its guardians
+    pub fn size(guardians: &[Pubkey]) -> usize {
+        8 +  // Anchor account discriminator
+        32 + // Vault
+        1 +  // PDA bump
+        (4 + guardians.len() * 32) + // Guardians vector
+        2 // Guardian threshold
+    }
+
+    /// Validates the guardians configuration
+    pub fn validate(&self) -> Result<()> {
+        let guardians_len = self.guardians.len();
+        require!(
+            guardians_len >= 1 && guardians_len <= usize::from(MAX_VAULT_ROLES),
+            VaultError::InvalidRoleCount
+        );
+        require!(
+            self.guardian_threshold >= 1 && usize::from(self.guardian_threshold) <= guardians_len,
+            VaultError::InvalidGuardianThreshold
+        );
+        Ok(())
+    }
+
+    pub fn is_guardian(&self, guardian: &Pubkey) -> bool {
+        self.guardians.binary_search(guardian).ok().is_some()
+    }
+
+    /// Checks that the guardian threshold signed a veto, along with the signing guardian
+    pub fn check_veto_signers(&self, guardian: &Pubkey, co_signers: &[AccountInfo]) -> Result<()> {
+        let mut guardians = vec![*guardian];
+        for co_signer in co_signers {
+            require!(
+                co_signer.is_signer && self.is_guardian(co_signer.key),
+                VaultError::GuardianNotFound
+            );
+            guardians.push(co_signer.key());
+        }
+
+        guardians.sort();
+        guardians.dedup();
+        require!(
+            guardians.len() >= usize::from(self.guardian_threshold),
+            VaultError::VetoThresholdNotReached
+        );
+        Ok(())
+    }
+
+    /// Writes the council into its PDA, creating or reallocating the account to fit the guardians
+    pub fn write<'info>(
+        &mut self,
+        veto_council: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        rent_collector: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let (veto_council_key, bump) = Pubkey::find_program_address(
+            &[SEED_PREFIX, self.vault.as_ref(), SEED_VETO_COUNCIL],
+            &id(),
+        );
+        require_keys_eq!(
+            veto_council.key(),
+            veto_council_key,
+            VaultError::InvalidAccount
+        );
+
+        self.bump = bump;
+        self.validate()?;
+
+        create_or_realloc_pda_account(
+            veto_council,
+            VaultVetoCouncil::size(&self.guardians),
+            &[SEED_PREFIX, self.vault.as_ref(), SEED_VETO_COUNCIL, &[bump]],
+            payer,
+            rent_collector,
+            system_program,
+        )?;
+
+        let mut veto_council_data = veto_council.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut veto_council_data[..])?;
+
+        Ok(())
+    }
+}
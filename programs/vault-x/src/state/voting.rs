@@ -0,0 +1,100 @@
+use crate::errors::*;
+use anchor_lang::prelude::*;
+
+/// A set of voters able to approve, reject and cancel transactions
+pub trait VoterSet {
+    /// Voting weight of a single voter
+    fn vote_weight(&self, voter: &Pubkey) -> u64;
+
+    /// Combined voting weight of every voter in the set
+    fn total_vote_weight(&self) -> u64;
+
+    /// Weight required to approve or cancel a transaction
+    fn vote_threshold(&self) -> u64;
+
+    /// Combined voting weight of the given voters
+    fn votes_weight(&self, voters: &[Pubkey]) -> u64 {
+        voters.iter().map(|voter| self.vote_weight(voter)).sum()
+    }
+}
+
+/// Approve/reject/cancel state machine shared by every transaction that is voted on
+pub trait VoteTransaction {
+    fn mark_approved(&mut self);
+    fn mark_rejected(&mut self);
+    fn mark_cancelled(&mut self);
+    fn approved_mut(&mut self) -> &mut Vec<Pubkey>;
+    fn rejected_mut(&mut self) -> &mut Vec<Pubkey>;
+    fn cancelled_mut(&mut self) -> &mut Vec<Pubkey>;
+
+    /// Records an approval, moving the transaction to the "Approved" state if enough approvals are collected
+    fn approve(&mut self, voter: Pubkey, voters: &impl VoterSet) -> Result<()> {
+        // Remove voter from the rejected list if previously rejected
+        let rejected = self.rejected_mut();
+        if let Ok(rejected_index) = rejected.binary_search(&voter) {
+            rejected.remove(rejected_index);
+        }
+
+        // Insert voter into the approved list, or return an error if already approved
+        let approved = self.approved_mut();
+        match approved.binary_search(&voter) {
+            Ok(_) => return err!(VaultError::AlreadyApproved),
+            Err(approved_index) => approved.insert(approved_index, voter),
+        };
+
+        // Move transaction to the "Approved" state if approval threshold is reached
+        if voters.votes_weight(approved).ge(&voters.vote_threshold()) {
+            msg!("Approval threshold reached");
+            self.mark_approved();
+        }
+
+        Ok(())
+    }
+
+    /// Records a rejection, moving the transaction to the "Rejected" state once approval can no longer be reached
+    fn reject(&mut self, voter: Pubkey, voters: &impl VoterSet) -> Result<()> {
+        // Remove voter from the approved list if previously approved
+        let approved = self.approved_mut();
+        if let Ok(approved_index) = approved.binary_search(&voter) {
+            approved.remove(approved_index);
+        }
+
+        // Insert voter into the rejected list, or return an error if already rejected
+        let rejected = self.rejected_mut();
+        match rejected.binary_search(&voter) {
+            Ok(_) => return err!(VaultError::AlreadyRejected),
+            Err(rejected_index) => rejected.insert(rejected_index, voter),
+        };
+
+        // Move transaction to the "Rejected" state if rejection threshold is reached
+        let cutoff = voters
+            .total_vote_weight()
+            .checked_sub(voters.vote_threshold())
+            .unwrap();
+
+        if voters.votes_weight(rejected).ge(&cutoff) {
+            msg!("Reject threshold reached");
+            self.mark_rejected();
+        }
+
+        Ok(())
+    }
+
+    /// Records a cancellation, moving the transaction to the "Cancelled" state if enough cancellations are collected
+    fn cancel(&mut self, voter: Pubkey, voters: &impl VoterSet) -> Result<()> {
+        // Insert voter into the cancelled list, or return an error if already cancelled
+        let cancelled = self.cancelled_mut();
+        match cancelled.binary_search(&voter) {
+            Ok(_) => return err!(VaultError::AlreadyCancelled),
+            Err(cancelled_index) => cancelled.insert(cancelled_index, voter),
+        };
+
+        // Move transaction to the "Cancelled" state if cancellation threshold is reached
+        if voters.votes_weight(cancelled).ge(&voters.vote_threshold()) {
+            msg!("Cancel threshold reached");
+            self.mark_cancelled();
+        }
+
+        Ok(())
+    }
+}
@@ -1,7 +1,33 @@
 use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
 use crate::id;
 
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::spl_token;
+use solana_program::instruction::Instruction;
+use solana_program::program::{get_return_data, invoke_signed};
+use solana_program::program_option::COption;
+use solana_program::program_pack::Pack;
+
+/// Derives the founder authority of a vault along with its signer seeds.
+/// Only founder transactions and batches sign with it, member and committee transactions can't.
+pub fn fetch_founder_authority(vault: &Pubkey) -> (Pubkey, Vec<Vec<u8>>) {
+    let (founder_authority_key, founder_authority_bump) = Pubkey::find_program_address(
+        &[SEED_PREFIX, vault.as_ref(), SEED_FOUNDER_AUTHORITY],
+        &id(),
+    );
+
+    let founder_authority_seeds = vec![
+        SEED_PREFIX.to_vec(),
+        vault.to_bytes().to_vec(),
+        SEED_FOUNDER_AUTHORITY.to_vec(),
+        vec![founder_authority_bump],
+    ];
+
+    (founder_authority_key, founder_authority_seeds)
+}
 
 pub fn fetch_ephemeral_keys(
     ephemeral_signer_bumps: &[u8],
@@ -42,3 +68,241 @@ pub fn fetch_ephemeral_keys(
         // Unzip takes the 1st and 2nd element of a tuple and inserts into their own arrays when returned from a map function
         .unzip()
 }
+
+/// Invokes the sanitized transaction instructions, signing with the fund and the ephemeral signers.
+/// Returns a receipt of the fund's balance around the execution and of the data each instruction returned.
+pub fn invoke_transaction_instructions(
+    instructions_with_ordered_account_infos: &[(Instruction, Vec<AccountInfo>)],
+    ephemeral_signer_seeds: &[Vec<Vec<u8>>],
+    fund_seeds: &[&[u8]],
+    fund_key: &Pubkey,
+) -> Result<ExecutionReceipt> {
+    let ephemeral_signer_seeds_slice = ephemeral_signer_seeds
+        .iter()
+        .map(|second_layer| {
+            second_layer
+                .iter()
+                .map(|third_layer| third_layer.as_slice())
+                .collect::<Vec<&[u8]>>()
+        })
+        .collect::<Vec<Vec<&[u8]>>>();
+
+    let mut ephemeral_signer_seeds_int_slice = ephemeral_signer_seeds_slice
+        .iter()
+        .map(Vec::as_slice)
+        .collect::<Vec<&[&[u8]]>>();
+
+    ephemeral_signer_seeds_int_slice.push(fund_seeds);
+    let signers_seeds = ephemeral_signer_seeds_int_slice.as_slice();
+
+    // The fund's balance can only be read when the message references it
+    let fund_account_info = instructions_with_ordered_account_infos
+        .iter()
+        .flat_map(|(_, account_infos)| account_infos.iter())
+        .find(|account_info| account_info.key.eq(fund_key));
+    let fund_lamports_before = fund_account_info.map(|account_info| account_info.lamports());
+    let fund_token_account_keys =
+        fetch_fund_token_account_keys(instructions_with_ordered_account_infos, fund_key);
+
+    // Execute each instruction with the appropriate signers, collecting the data it returns.
+    // Return data is reset before every invocation, so it always comes from the current instruction.
+    let mut return_data = vec![];
+    for (instruction_index, (instruction, account_infos)) in
+        instructions_with_ordered_account_infos.iter().enumerate()
+    {
+        invoke_signed(instruction, account_infos, signers_seeds)?;
+
+        if let Some((program_id, data)) = get_return_data() {
+            return_data.push(InstructionReturnData {
+                instruction_index: u16::try_from(instruction_index).unwrap(),
+                program_id,
+                data,
+            });
+        }
+    }
+
+    // Fail the whole execution if a message took control of the fund or of its token accounts
+    verify_fund_integrity(
+        instructions_with_ordered_account_infos,
+        fund_key,
+        &fund_token_account_keys,
+    )?;
+
+    Ok(ExecutionReceipt {
+        fund_lamports_before,
+        fund_lamports_after: fund_account_info.map(|account_info| account_info.lamports()),
+        return_data,
+    })
+}
+
+/// Fetches the keys of the token accounts owned by the fund among the instructions' accounts
+fn fetch_fund_token_account_keys(
+    instructions_with_ordered_account_infos: &[(Instruction, Vec<AccountInfo>)],
+    fund_key: &Pubkey,
+) -> Vec<Pubkey> {
+    let mut fund_token_account_keys = vec![];
+
+    for account_info in instructions_with_ordered_account_infos
+        .iter()
+        .flat_map(|(_, account_infos)| account_infos.iter())
+    {
+        if !account_info.owner.eq(&spl_token::ID)
+            || fund_token_account_keys.contains(account_info.key)
+        {
+            continue;
+        }
+
+        let Ok(account_data) = account_info.try_borrow_data() else {
+            continue;
+        };
+        if let Ok(token_account) = spl_token::state::Account::unpack(&account_data) {
+            if token_account.owner.eq(fund_key) {
+                fund_token_account_keys.push(account_info.key());
+            }
+        }
+    }
+
+    fund_token_account_keys
+}
+
+/// Verifies that an execution left the fund a system account without data,
+/// and that the fund still owns and can close the token accounts it owned before, unless they were closed
+fn verify_fund_integrity(
+    instructions_with_ordered_account_infos: &[(Instruction, Vec<AccountInfo>)],
+    fund_key: &Pubkey,
+    fund_token_account_keys: &[Pubkey],
+) -> Result<()> {
+    for account_info in instructions_with_ordered_account_infos
+        .iter()
+        .flat_map(|(_, account_infos)| account_infos.iter())
+    {
+        if account_info.key.eq(fund_key) {
+            // Assigning or allocating the fund would hand it to another program
+            require!(
+                account_info.owner.eq(&system_program::ID) && account_info.data_is_empty(),
+                VaultError::FundIntegrityViolated
+            );
+        } else if fund_token_account_keys.contains(account_info.key) {
+            // Closing a token account is allowed, the fund being its close authority
+            if account_info.lamports() == 0 || account_info.data_is_empty() {
+                continue;
+            }
+
+            let account_data = account_info.try_borrow_data()?;
+            let token_account = spl_token::state::Account::unpack(&account_data)
+                .map_err(|_| error!(VaultError::FundIntegrityViolated))?;
+
+            // Without a close authority, the owner is the one able to close the account
+            let is_fund_close_authority = match token_account.close_authority {
+                COption::Some(close_authority) => close_authority.eq(fund_key),
+                COption::None => true,
+            };
+
+            require!(
+                account_info.owner.eq(&spl_token::ID)
+                    && token_account.owner.eq(fund_key)
+                    && is_fund_close_authority,
+                VaultError::FundIntegrityViolated
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates a program owned PDA with the given size, or reallocates it if it already exists.
+/// Any additional rent is paid by the payer and any excess rent is refunded to the rent collector.
+pub fn create_or_realloc_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    size: usize,
+    signer_seeds: &[&[u8]],
+    payer: &AccountInfo<'info>,
+    rent_collector: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent = Rent::get()?;
+    let required_lamports = rent.minimum_balance(size).max(1);
+    let current_lamports = account.lamports();
+
+    // Account doesn't exist yet, create it under the program
+    if account.owner.eq(&system_program::ID) {
+        if current_lamports == 0 {
+            return system_program::create_account(
+                CpiContext::new_with_signer(
+                    system_program.clone(),
+                    system_program::CreateAccount {
+                        from: payer.clone(),
+                        to: account.clone(),
+                    },
+                    &[signer_seeds],
+                ),
+                required_lamports,
+                size as u64,
+                &id(),
+            );
+        }
+
+        // The PDA was pre-funded, so top it up, allocate and assign it instead
+        let lamports_diff = required_lamports.saturating_sub(current_lamports);
+        if lamports_diff > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: payer.clone(),
+                        to: account.clone(),
+                    },
+                ),
+                lamports_diff,
+            )?;
+        }
+
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Allocate {
+                    account_to_allocate: account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            size as u64,
+        )?;
+
+        return system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Assign {
+                    account_to_assign: account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            &id(),
+        );
+    }
+
+    require_keys_eq!(*account.owner, id(), VaultError::InvalidAccount);
+
+    if current_lamports < required_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    } else if current_lamports > required_lamports {
+        let excess_lamports = current_lamports - required_lamports;
+        **account.try_borrow_mut_lamports()? = required_lamports;
+        **rent_collector.try_borrow_mut_lamports()? = rent_collector
+            .lamports()
+            .checked_add(excess_lamports)
+            .unwrap();
+    }
+
+    account.realloc(size, false)?;
+
+    Ok(())
+}
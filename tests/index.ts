@@ -12,3 +12,28 @@ import './suites/vault';
 import './suites/founder';
 import './suites/member';
 import './suites/compression';
+import './suites/closeTransactions';
+import './suites/metadata';
+import './suites/committee';
+import './suites/weightedVoting';
+import './suites/timeLock';
+import './suites/expiry';
+import './suites/memberBatch';
+import './suites/vaultRent';
+import './suites/invitation';
+import './suites/leave';
+import './suites/recovery';
+import './suites/vetoCouncil';
+import './suites/events';
+import './suites/memberThreshold';
+import './suites/escalation';
+import './suites/memberLifecycle';
+import './suites/executeMessage';
+import './suites/rateLimit';
+import './suites/batch';
+import './suites/transactionBuffer';
+import './suites/addressLookupTable';
+import './suites/partialExecution';
+import './suites/validateTransaction';
+import './suites/executionReceipt';
+import './suites/fundIntegrity';
@@ -0,0 +1,224 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  getTestProgramId,
+  getEventCpiAccounts,
+  getVaultTransactionIndex,
+  sendTransaction,
+  createTestVault,
+  createTestLookupTable,
+  airdrop,
+} from '../utils';
+
+import {
+  AccountMeta,
+  AddressLookupTableAccount,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from '@solana/web3.js';
+import {
+  VaultFounderTransaction,
+  createApproveFounderTransactionInstruction,
+  createCreateFounderTransactionInstruction,
+  createExecuteFounderTransactionInstruction,
+} from '../../vault-x-sdk/src/generated';
+import {
+  populateVaultTransactionExecuteRemainingAccounts,
+  serializeVaultTransactionMessage,
+} from '../../vault-x-sdk/src/utils';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Address lookup tables', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let vaultPda: PublicKey;
+  let fundPda: PublicKey;
+  const recipients = Array.from({ length: 3 }).map(
+    () => Keypair.generate().publicKey
+  );
+
+  async function createApprovedTransaction(
+    lookupTable: AddressLookupTableAccount,
+    lamports: number
+  ) {
+    const message = new TransactionMessage({
+      payerKey: fundPda,
+      recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+      instructions: recipients.map(recipient =>
+        SystemProgram.transfer({
+          fromPubkey: fundPda,
+          toPubkey: recipient,
+          lamports,
+        })
+      ),
+    }).compileToV0Message([lookupTable]);
+
+    // The recipients are loaded from the lookup table
+    assert.strictEqual(message.addressTableLookups.length, 1);
+
+    const transactionIndex = await getVaultTransactionIndex(
+      connection,
+      vaultPda
+    );
+    const [transactionPda] = sdk.getFounderTransactionPda({
+      vaultPda,
+      index: Number(transactionIndex),
+      programId,
+    });
+
+    const createTransactionIx = createCreateFounderTransactionInstruction(
+      {
+        creator: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          ephemeralSigners: 0,
+          transactionMessage: serializeVaultTransactionMessage(message),
+          expiry: null,
+        },
+      },
+      programId
+    );
+    const approveIx = createApproveFounderTransactionInstruction(
+      {
+        founder: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [createTransactionIx, approveIx],
+      founder.publicKey,
+      [founder]
+    );
+
+    return transactionPda;
+  }
+
+  async function getRemainingAccounts(
+    transactionPda: PublicKey,
+    lookupTable: AddressLookupTableAccount
+  ) {
+    const { message, ephemeralSignerBumps } =
+      await VaultFounderTransaction.fromAccountAddress(
+        connection,
+        transactionPda
+      );
+
+    return populateVaultTransactionExecuteRemainingAccounts(
+      message,
+      [...ephemeralSignerBumps],
+      fundPda,
+      transactionPda,
+      sdk.getFounderAuthorityPda({ vaultPda, programId })[0],
+      [lookupTable]
+    );
+  }
+
+  function execute(
+    transactionPda: PublicKey,
+    anchorRemainingAccounts: AccountMeta[]
+  ) {
+    const executeIx = createExecuteFounderTransactionInstruction(
+      {
+        founder: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+        anchorRemainingAccounts,
+      },
+      programId
+    );
+
+    return sendTransaction(connection, [executeIx], founder.publicKey, [
+      founder,
+    ]).catch(sdk.errors.translateAndThrowAnchorError);
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      initialFounders: [founder.publicKey],
+    }));
+
+    [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    await airdrop(connection, fundPda, LAMPORTS_PER_SOL);
+  });
+
+  it('execute a transaction loading its accounts from a lookup table', async () => {
+    const lamports = LAMPORTS_PER_SOL / 10;
+    const lookupTable = await createTestLookupTable(
+      connection,
+      founder,
+      recipients
+    );
+    const transactionPda = await createApprovedTransaction(
+      lookupTable,
+      lamports
+    );
+
+    await execute(
+      transactionPda,
+      await getRemainingAccounts(transactionPda, lookupTable)
+    );
+
+    for (const recipient of recipients) {
+      assert.strictEqual(await connection.getBalance(recipient), lamports);
+    }
+  });
+
+  it('error: execute with another lookup table than the message one', async () => {
+    const lookupTable = await createTestLookupTable(
+      connection,
+      founder,
+      recipients
+    );
+    const otherLookupTable = await createTestLookupTable(
+      connection,
+      founder,
+      recipients
+    );
+    const transactionPda = await createApprovedTransaction(
+      lookupTable,
+      LAMPORTS_PER_SOL / 10
+    );
+
+    // The lookup tables come first in the remaining accounts
+    const [, ...remainingAccounts] = await getRemainingAccounts(
+      transactionPda,
+      lookupTable
+    );
+
+    await assert.rejects(
+      () =>
+        execute(transactionPda, [
+          { pubkey: otherLookupTable.key, isSigner: false, isWritable: false },
+          ...remainingAccounts,
+        ]),
+      /Address lookup table does not match the transaction message/
+    );
+  });
+});
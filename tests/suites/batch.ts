@@ -0,0 +1,291 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  getTestProgramId,
+  getEventCpiAccounts,
+  getVaultTransactionIndex,
+  sendTransaction,
+  createTestVault,
+  createTransferMessage,
+  airdrop,
+} from '../utils';
+
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import {
+  VaultBatch,
+  VaultBatchStatus,
+  VaultBatchTransaction,
+  createActivateBatchInstruction,
+  createAddBatchTransactionInstruction,
+  createApproveBatchInstruction,
+  createCloseBatchInstruction,
+  createCloseBatchTransactionInstruction,
+  createCreateBatchInstruction,
+  createExecuteBatchTransactionInstruction,
+} from '../../vault-x-sdk/src/generated';
+import { populateVaultTransactionExecuteRemainingAccounts } from '../../vault-x-sdk/src/utils';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Batch', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let vaultPda: PublicKey;
+  let fundPda: PublicKey;
+  let executedBatchPda: PublicKey;
+  const recipients = Array.from({ length: 3 }).map(
+    () => Keypair.generate().publicKey
+  );
+
+  async function createBatch() {
+    const transactionIndex = await getVaultTransactionIndex(
+      connection,
+      vaultPda
+    );
+    const [batchPda] = sdk.getBatchPda({
+      vaultPda,
+      index: Number(transactionIndex),
+      programId,
+    });
+
+    const createBatchIx = createCreateBatchInstruction(
+      {
+        batch: batchPda,
+        vault: vaultPda,
+        creator: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [createBatchIx], founder.publicKey, [
+      founder,
+    ]);
+
+    return batchPda;
+  }
+
+  function createActivateIx(batchPda: PublicKey) {
+    return createActivateBatchInstruction(
+      {
+        batch: batchPda,
+        vault: vaultPda,
+        creator: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+  }
+
+  async function createExecuteIx(batchPda: PublicKey, index: number) {
+    const [transactionPda] = sdk.getBatchTransactionPda({
+      batchPda,
+      index,
+      programId,
+    });
+    const { message, ephemeralSignerBumps } =
+      await VaultBatchTransaction.fromAccountAddress(
+        connection,
+        transactionPda
+      );
+
+    return createExecuteBatchTransactionInstruction(
+      {
+        batch: batchPda,
+        transaction: transactionPda,
+        vault: vaultPda,
+        founder: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+        anchorRemainingAccounts:
+          populateVaultTransactionExecuteRemainingAccounts(
+            message,
+            [...ephemeralSignerBumps],
+            fundPda,
+            transactionPda,
+            sdk.getFounderAuthorityPda({ vaultPda, programId })[0]
+          ),
+      },
+      programId
+    );
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      initialFounders: [founder.publicKey],
+    }));
+
+    [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    await airdrop(connection, fundPda, LAMPORTS_PER_SOL);
+  });
+
+  it('error: activate an empty batch', async () => {
+    const batchPda = await createBatch();
+
+    await assert.rejects(
+      () =>
+        sendTransaction(
+          connection,
+          [createActivateIx(batchPda)],
+          founder.publicKey,
+          [founder]
+        ).catch(sdk.errors.translateAndThrowAnchorError),
+      /Batch has no transactions/
+    );
+  });
+
+  it('execute a batch one transaction at a time', async () => {
+    const lamports = LAMPORTS_PER_SOL / 10;
+    const batchPda = await createBatch();
+
+    for (const [i, recipient] of recipients.entries()) {
+      const [transactionPda] = sdk.getBatchTransactionPda({
+        batchPda,
+        index: i + 1,
+        programId,
+      });
+      const { transactionMessage } = await createTransferMessage(
+        connection,
+        fundPda,
+        recipient,
+        lamports
+      );
+
+      const addBatchTransactionIx = createAddBatchTransactionInstruction(
+        {
+          batch: batchPda,
+          transaction: transactionPda,
+          vault: vaultPda,
+          creator: founder.publicKey,
+          ...getEventCpiAccounts(programId),
+        },
+        {
+          args: {
+            ephemeralSigners: 0,
+            transactionMessage,
+          },
+        },
+        programId
+      );
+
+      await sendTransaction(
+        connection,
+        [addBatchTransactionIx],
+        founder.publicKey,
+        [founder]
+      );
+    }
+
+    const approveBatchIx = createApproveBatchInstruction(
+      {
+        batch: batchPda,
+        vault: vaultPda,
+        founder: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [createActivateIx(batchPda), approveBatchIx],
+      founder.publicKey,
+      [founder]
+    );
+
+    let batch = await VaultBatch.fromAccountAddress(connection, batchPda);
+    assert.strictEqual(batch.status, VaultBatchStatus.Approved);
+    assert.strictEqual(batch.size, recipients.length);
+
+    // Transactions are executed in the order they were added
+    await assert.rejects(async () =>
+      sendTransaction(
+        connection,
+        [await createExecuteIx(batchPda, 2)],
+        founder.publicKey,
+        [founder]
+      )
+    );
+
+    for (let index = 1; index <= recipients.length; index++) {
+      await sendTransaction(
+        connection,
+        [await createExecuteIx(batchPda, index)],
+        founder.publicKey,
+        [founder]
+      );
+
+      batch = await VaultBatch.fromAccountAddress(connection, batchPda);
+      assert.strictEqual(batch.executedIndex, index);
+    }
+
+    assert.strictEqual(batch.status, VaultBatchStatus.Executed);
+    executedBatchPda = batchPda;
+
+    for (const recipient of recipients) {
+      assert.strictEqual(await connection.getBalance(recipient), lamports);
+    }
+  });
+
+  it('close an executed batch and its transactions', async () => {
+    const batchPda = executedBatchPda;
+    const transactionPdas = recipients.map(
+      (_, i) =>
+        sdk.getBatchTransactionPda({ batchPda, index: i + 1, programId })[0]
+    );
+
+    const closeBatchIx = createCloseBatchInstruction(
+      {
+        batch: batchPda,
+        vault: vaultPda,
+        creator: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    // Closing is permissionless
+    const payer = await generateFundedKeypair(connection);
+
+    // The batch can only be closed once all its transactions are
+    await assert.rejects(() =>
+      sendTransaction(connection, [closeBatchIx], payer.publicKey, [payer])
+    );
+
+    const closeBatchTransactionIxs = transactionPdas.map(transactionPda =>
+      createCloseBatchTransactionInstruction(
+        {
+          batch: batchPda,
+          transaction: transactionPda,
+          vault: vaultPda,
+          creator: founder.publicKey,
+          ...getEventCpiAccounts(programId),
+        },
+        programId
+      )
+    );
+
+    await sendTransaction(
+      connection,
+      [...closeBatchTransactionIxs, closeBatchIx],
+      payer.publicKey,
+      [payer]
+    );
+
+    for (const pda of [batchPda, ...transactionPdas]) {
+      assert.strictEqual(await connection.getAccountInfo(pda), null);
+    }
+  });
+});
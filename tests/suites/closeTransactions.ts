@@ -0,0 +1,180 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  generateFundedKeypairs,
+  getTestProgramId,
+  getEventCpiAccounts,
+  sendTransaction,
+  createTestVault,
+  createTestFounderTransaction,
+} from '../utils';
+
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  createCloseFounderTransactionInstruction,
+  createCloseTransactionsInstruction,
+  createRejectFounderTransactionInstruction,
+} from '../../vault-x-sdk/src/generated';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Close transactions', () => {
+  let administrator: Keypair;
+  let founders: Keypair[];
+  let vaultPda: PublicKey;
+
+  async function rejectFounderTransaction(transactionPda: PublicKey) {
+    const rejecter = founders[1];
+    const rejectIx = createRejectFounderTransactionInstruction(
+      {
+        founder: rejecter.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [rejectIx], rejecter.publicKey, [
+      rejecter,
+    ]);
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founders = await generateFundedKeypairs(connection, 3);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      founderThreshold: 2,
+      initialFounders: founders.map(f => f.publicKey),
+    }));
+  });
+
+  it('error: close an active founder transaction', async () => {
+    const creator = founders[0];
+    const { transactionPda } = await createTestFounderTransaction(
+      connection,
+      vaultPda,
+      creator
+    );
+
+    const closeIx = createCloseFounderTransactionInstruction(
+      {
+        creator: creator.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [closeIx], creator.publicKey, [
+          creator,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Invalid transaction status for voting/
+    );
+
+    // Reject it so the following tests aren't affected by an active transaction
+    await rejectFounderTransaction(transactionPda);
+  });
+
+  it('close a rejected founder transaction and refund its creator', async () => {
+    const creator = founders[0];
+    const { transactionPda } = await createTestFounderTransaction(
+      connection,
+      vaultPda,
+      creator
+    );
+    await rejectFounderTransaction(transactionPda);
+
+    const rent = await connection.getBalance(transactionPda);
+    const creatorBalance = await connection.getBalance(creator.publicKey);
+
+    // Closing is permissionless, anyone can pay for it
+    const closer = await generateFundedKeypair(connection);
+    const closeIx = createCloseFounderTransactionInstruction(
+      {
+        creator: creator.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [closeIx], closer.publicKey, [closer]);
+
+    assert.strictEqual(await connection.getAccountInfo(transactionPda), null);
+    assert.strictEqual(
+      await connection.getBalance(creator.publicKey),
+      creatorBalance + rent
+    );
+  });
+
+  it('close many terminal transactions at once', async () => {
+    const creators = [founders[0], founders[2]];
+    const transactionPdas: PublicKey[] = [];
+
+    for (const creator of creators) {
+      const { transactionPda } = await createTestFounderTransaction(
+        connection,
+        vaultPda,
+        creator
+      );
+      await rejectFounderTransaction(transactionPda);
+      transactionPdas.push(transactionPda);
+    }
+
+    const closeTransactionsIx = createCloseTransactionsInstruction(
+      {
+        vault: vaultPda,
+        ...getEventCpiAccounts(programId),
+        anchorRemainingAccounts: transactionPdas.flatMap((pubkey, i) => [
+          { pubkey, isSigner: false, isWritable: true },
+          { pubkey: creators[i].publicKey, isSigner: false, isWritable: true },
+        ]),
+      },
+      programId
+    );
+
+    const payer = founders[1];
+    await sendTransaction(connection, [closeTransactionsIx], payer.publicKey, [
+      payer,
+    ]);
+
+    for (const transactionPda of transactionPdas) {
+      assert.strictEqual(await connection.getAccountInfo(transactionPda), null);
+    }
+  });
+
+  it('error: close transactions without any account', async () => {
+    const closeTransactionsIx = createCloseTransactionsInstruction(
+      {
+        vault: vaultPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    const payer = founders[1];
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [closeTransactionsIx], payer.publicKey, [
+          payer,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Wrong number of accounts provided/
+    );
+  });
+});
@@ -0,0 +1,413 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  generateFundedKeypairs,
+  getTestProgramId,
+  getEventCpiAccounts,
+  sendTransaction,
+  createTestVault,
+  createTransferMessage,
+  addTestMembers,
+  airdrop,
+} from '../utils';
+
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import {
+  VaultCommittee,
+  VaultCommitteeTransaction,
+  VaultTransactionStatus,
+  createApproveCommitteeTransactionInstruction,
+  createCreateCommitteeInstruction,
+  createCreateCommitteeTransactionInstruction,
+  createDissolveCommitteeInstruction,
+  createExecuteCommitteeTransactionInstruction,
+  createLeaveVaultInstruction,
+  createSweepCommitteeFundInstruction,
+} from '../../vault-x-sdk/src/generated';
+import { populateVaultTransactionExecuteRemainingAccounts } from '../../vault-x-sdk/src/utils';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Committee', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let members: Keypair[];
+  let vaultPda: PublicKey;
+  let committeePda: PublicKey;
+
+  async function getNextCommitteePda() {
+    const { committeeIndex } = await sdk.accounts.Vault.fromAccountAddress(
+      connection,
+      vaultPda
+    );
+
+    return sdk.getCommitteePda({
+      vaultPda,
+      index: committeeIndex + 1,
+      programId,
+    })[0];
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+    members = await generateFundedKeypairs(connection, 3);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      initialFounders: [founder.publicKey],
+    }));
+
+    await addTestMembers(
+      connection,
+      vaultPda,
+      founder,
+      members.map(m => m.publicKey)
+    );
+  });
+
+  it('error: committee members must be vault members', async () => {
+    const outsider = Keypair.generate();
+
+    const createCommitteeIx = createCreateCommitteeInstruction(
+      {
+        committee: await getNextCommitteePda(),
+        vault: vaultPda,
+        founder: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          members: [members[0].publicKey, outsider.publicKey],
+          threshold: 1,
+        },
+      },
+      programId
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [createCommitteeIx], founder.publicKey, [
+          founder,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Member does not exist/
+    );
+  });
+
+  it('error: invalid committee threshold (> members)', async () => {
+    const createCommitteeIx = createCreateCommitteeInstruction(
+      {
+        committee: await getNextCommitteePda(),
+        vault: vaultPda,
+        founder: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          members: members.map(m => m.publicKey),
+          threshold: 4,
+        },
+      },
+      programId
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [createCommitteeIx], founder.publicKey, [
+          founder,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Invalid committee threshold/
+    );
+  });
+
+  it('create a committee', async () => {
+    committeePda = await getNextCommitteePda();
+
+    const createCommitteeIx = createCreateCommitteeInstruction(
+      {
+        committee: committeePda,
+        vault: vaultPda,
+        founder: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          members: members.map(m => m.publicKey),
+          threshold: 2,
+        },
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [createCommitteeIx], founder.publicKey, [
+      founder,
+    ]);
+
+    const committee = await VaultCommittee.fromAccountAddress(
+      connection,
+      committeePda
+    );
+
+    assert.strictEqual(committee.vault.toBase58(), vaultPda.toBase58());
+    assert.strictEqual(committee.threshold, 2);
+    assert.strictEqual(committee.transactionIndex, 0);
+    assert.deepEqual(
+      committee.members,
+      members
+        .map(m => m.publicKey)
+        .sort((a, b) => a.toBuffer().compare(b.toBuffer()))
+    );
+  });
+
+  it('transfer from the committee fund once its threshold approves', async () => {
+    const [committeeFundPda] = sdk.getCommitteeFundPda({
+      committeePda,
+      programId,
+    });
+    const [transactionPda] = sdk.getCommitteeTransactionPda({
+      committeePda,
+      index: 1,
+      programId,
+    });
+
+    await airdrop(connection, committeeFundPda, LAMPORTS_PER_SOL);
+
+    const recipient = members[2];
+    const lamports = LAMPORTS_PER_SOL / 10;
+    const { transactionMessage } = await createTransferMessage(
+      connection,
+      committeeFundPda,
+      recipient.publicKey,
+      lamports
+    );
+
+    const creator = members[0];
+    const createTransactionIx = createCreateCommitteeTransactionInstruction(
+      {
+        transaction: transactionPda,
+        committee: committeePda,
+        vault: vaultPda,
+        creator: creator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          ephemeralSigners: 0,
+          transactionMessage,
+        },
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [createTransactionIx],
+      creator.publicKey,
+      [creator]
+    );
+
+    for (const voter of members.slice(0, 2)) {
+      const approveIx = createApproveCommitteeTransactionInstruction(
+        {
+          transaction: transactionPda,
+          committee: committeePda,
+          vault: vaultPda,
+          member: voter.publicKey,
+          ...getEventCpiAccounts(programId),
+        },
+        programId
+      );
+
+      await sendTransaction(connection, [approveIx], voter.publicKey, [voter]);
+    }
+
+    const { message, ephemeralSignerBumps, status } =
+      await VaultCommitteeTransaction.fromAccountAddress(
+        connection,
+        transactionPda
+      );
+    assert.strictEqual(status, VaultTransactionStatus.Approved);
+
+    const executor = members[1];
+    const executeIx = createExecuteCommitteeTransactionInstruction(
+      {
+        transaction: transactionPda,
+        committee: committeePda,
+        vault: vaultPda,
+        member: executor.publicKey,
+        ...getEventCpiAccounts(programId),
+        anchorRemainingAccounts:
+          populateVaultTransactionExecuteRemainingAccounts(
+            message,
+            [...ephemeralSignerBumps],
+            committeeFundPda,
+            transactionPda
+          ),
+      },
+      programId
+    );
+
+    const recipientBalance = await connection.getBalance(recipient.publicKey);
+    await sendTransaction(connection, [executeIx], executor.publicKey, [
+      executor,
+    ]);
+
+    assert.strictEqual(
+      await connection.getBalance(recipient.publicKey),
+      recipientBalance + lamports
+    );
+
+    const transaction = await VaultCommitteeTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+    assert.strictEqual(transaction.status, VaultTransactionStatus.Executed);
+  });
+
+  it('error: vote in a committee after leaving the vault', async () => {
+    const [transactionPda] = sdk.getCommitteeTransactionPda({
+      committeePda,
+      index: 2,
+      programId,
+    });
+    const { transactionMessage } = await createTransferMessage(
+      connection,
+      sdk.getCommitteeFundPda({ committeePda, programId })[0],
+      members[0].publicKey,
+      LAMPORTS_PER_SOL / 10
+    );
+
+    const creator = members[0];
+    const createTransactionIx = createCreateCommitteeTransactionInstruction(
+      {
+        transaction: transactionPda,
+        committee: committeePda,
+        vault: vaultPda,
+        creator: creator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          ephemeralSigners: 0,
+          transactionMessage,
+        },
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [createTransactionIx],
+      creator.publicKey,
+      [creator]
+    );
+
+    // The committee still lists the member once it left the vault
+    const leaver = members[2];
+    const leaveIx = createLeaveVaultInstruction(
+      {
+        vault: vaultPda,
+        member: leaver.publicKey,
+        rentCollector: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [leaveIx], leaver.publicKey, [leaver]);
+
+    const approveIx = createApproveCommitteeTransactionInstruction(
+      {
+        transaction: transactionPda,
+        committee: committeePda,
+        vault: vaultPda,
+        member: leaver.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [approveIx], leaver.publicKey, [
+          leaver,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Member does not exist/
+    );
+  });
+
+  it('sweep the fund of a dissolved committee', async () => {
+    const [committeeFundPda] = sdk.getCommitteeFundPda({
+      committeePda,
+      programId,
+    });
+    const [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    const { committeeIndex } = await VaultCommittee.fromAccountAddress(
+      connection,
+      committeePda
+    );
+
+    const sweepIx = createSweepCommitteeFundInstruction(
+      {
+        vault: vaultPda,
+        founder: founder.publicKey,
+        committee: committeePda,
+        committeeFund: committeeFundPda,
+        fund: fundPda,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: { committeeIndex },
+      },
+      programId
+    );
+
+    // The fund of an active committee belongs to its transactions
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [sweepIx], founder.publicKey, [
+          founder,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Invalid account provided/
+    );
+
+    const dissolveIx = createDissolveCommitteeInstruction(
+      {
+        committee: committeePda,
+        vault: vaultPda,
+        founder: founder.publicKey,
+        creator: founder.publicKey,
+        committeeFund: committeeFundPda,
+        fund: fundPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [dissolveIx], founder.publicKey, [
+      founder,
+    ]);
+
+    // Anything the committee fund receives afterwards can still be recovered
+    const lamports = LAMPORTS_PER_SOL / 10;
+    await airdrop(connection, committeeFundPda, lamports);
+    const fundBalance = await connection.getBalance(fundPda);
+
+    await sendTransaction(connection, [sweepIx], founder.publicKey, [founder]);
+
+    assert.strictEqual(await connection.getBalance(committeeFundPda), 0);
+    assert.strictEqual(
+      await connection.getBalance(fundPda),
+      fundBalance + lamports
+    );
+  });
+});
@@ -0,0 +1,257 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  generateFundedKeypairs,
+  getTestProgramId,
+  getEventCpiAccounts,
+  getVaultTransactionIndex,
+  sendTransaction,
+  createTestVault,
+  createTestMemberTransaction,
+  createTestLookupTable,
+  createTestExecuteFounderTransactionIx,
+  createTestExecuteMemberTransactionIx,
+  addTestMembers,
+  airdrop,
+} from '../utils';
+
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from '@solana/web3.js';
+import {
+  VaultFounderTransaction,
+  VaultTransactionStatus,
+  createApproveFounderTransactionInstruction,
+  createEscalateMemberTransactionInstruction,
+} from '../../vault-x-sdk/src/generated';
+import {
+  serializeVaultTransactionMessage,
+  toBigInt,
+} from '../../vault-x-sdk/src/utils';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Member transaction escalation', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let members: Keypair[];
+  let vaultPda: PublicKey;
+  let merkleTree: Keypair;
+  let allowList: PublicKey[];
+
+  function createTransaction(member: Keypair) {
+    return createTestMemberTransaction(
+      connection,
+      vaultPda,
+      member,
+      merkleTree.publicKey,
+      allowList,
+      { lamports: LAMPORTS_PER_SOL / 10 }
+    );
+  }
+
+  async function escalate(memberTransactionPda: PublicKey, creator: Keypair) {
+    const transactionIndex = await getVaultTransactionIndex(
+      connection,
+      vaultPda
+    );
+    const [founderTransactionPda] = sdk.getFounderTransactionPda({
+      vaultPda,
+      index: Number(transactionIndex),
+      programId,
+    });
+
+    const escalateIx = createEscalateMemberTransactionInstruction(
+      {
+        memberTransaction: memberTransactionPda,
+        founderTransaction: founderTransactionPda,
+        vault: vaultPda,
+        creator: creator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [escalateIx], creator.publicKey, [
+      creator,
+    ]).catch(sdk.errors.translateAndThrowAnchorError);
+
+    return founderTransactionPda;
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+    members = await generateFundedKeypairs(connection, 2);
+
+    ({ vaultPda, merkleTree, allowList } = await createTestVault(
+      connection,
+      administrator,
+      { initialFounders: [founder.publicKey] }
+    ));
+
+    await addTestMembers(
+      connection,
+      vaultPda,
+      founder,
+      members.map(m => m.publicKey)
+    );
+
+    const [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    await airdrop(connection, fundPda, LAMPORTS_PER_SOL);
+  });
+
+  it('escalate a member transaction to the founders', async () => {
+    const member = members[0];
+    const { transactionPda: memberTransactionPda, transactionIndex } =
+      await createTransaction(member);
+
+    const founderTransactionPda = await escalate(memberTransactionPda, member);
+
+    // The member transaction is closed and its message moved to the founders
+    assert.strictEqual(
+      await connection.getAccountInfo(memberTransactionPda),
+      null
+    );
+
+    const founderTransaction = await VaultFounderTransaction.fromAccountAddress(
+      connection,
+      founderTransactionPda
+    );
+    assert.strictEqual(
+      founderTransaction.status,
+      VaultTransactionStatus.Active
+    );
+    assert.strictEqual(
+      founderTransaction.creator.toBase58(),
+      member.publicKey.toBase58()
+    );
+    assert.ok(founderTransaction.escalatedFrom !== null);
+    assert.strictEqual(
+      toBigInt(founderTransaction.escalatedFrom),
+      transactionIndex
+    );
+
+    const approveIx = createApproveFounderTransactionInstruction(
+      {
+        founder: founder.publicKey,
+        vault: vaultPda,
+        transaction: founderTransactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+    const executeIx = await createTestExecuteFounderTransactionIx(
+      connection,
+      vaultPda,
+      founderTransactionPda,
+      founder.publicKey
+    );
+
+    const memberBalance = await connection.getBalance(member.publicKey);
+    await sendTransaction(
+      connection,
+      [approveIx, executeIx],
+      founder.publicKey,
+      [founder]
+    );
+
+    assert.strictEqual(
+      await connection.getBalance(member.publicKey),
+      memberBalance + LAMPORTS_PER_SOL / 10
+    );
+  });
+
+  it('escalate a transaction loading its accounts from a lookup table', async () => {
+    const member = members[0];
+    const recipients = Array.from({ length: 3 }).map(
+      () => Keypair.generate().publicKey
+    );
+    const lookupTable = await createTestLookupTable(
+      connection,
+      member,
+      recipients
+    );
+
+    const [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    const message = new TransactionMessage({
+      payerKey: fundPda,
+      recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+      instructions: recipients.map(recipient =>
+        SystemProgram.transfer({
+          fromPubkey: fundPda,
+          toPubkey: recipient,
+          lamports: LAMPORTS_PER_SOL / 100,
+        })
+      ),
+    }).compileToV0Message([lookupTable]);
+
+    const { transactionPda: memberTransactionPda } =
+      await createTestMemberTransaction(
+        connection,
+        vaultPda,
+        member,
+        merkleTree.publicKey,
+        allowList,
+        { transactionMessage: serializeVaultTransactionMessage(message) }
+      );
+
+    // The founder transaction must have room for the message's lookups too
+    const founderTransactionPda = await escalate(memberTransactionPda, member);
+
+    const founderTransaction = await VaultFounderTransaction.fromAccountAddress(
+      connection,
+      founderTransactionPda
+    );
+    assert.strictEqual(
+      founderTransaction.message.addressTableLookups.length,
+      1
+    );
+    assert.ok(
+      founderTransaction.message.addressTableLookups[0].accountKey.equals(
+        lookupTable.key
+      )
+    );
+  });
+
+  it('error: escalate a transaction created by another member', async () => {
+    const { transactionPda } = await createTransaction(members[0]);
+
+    await assert.rejects(
+      () => escalate(transactionPda, members[1]),
+      /Invalid account provided/
+    );
+  });
+
+  it('error: escalate an executed transaction', async () => {
+    const member = members[1];
+    const { transactionPda } = await createTransaction(member);
+
+    const executeIx = await createTestExecuteMemberTransactionIx(
+      connection,
+      vaultPda,
+      transactionPda,
+      member.publicKey
+    );
+    await sendTransaction(connection, [executeIx], member.publicKey, [member]);
+
+    await assert.rejects(
+      () => escalate(transactionPda, member),
+      /Invalid transaction status for voting/
+    );
+  });
+});
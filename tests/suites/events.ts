@@ -0,0 +1,180 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  getTestProgramId,
+  getEventCpiAccounts,
+  getVaultTransactionIndex,
+  sendTransaction,
+  createTestVault,
+  createTestExecuteFounderTransactionIx,
+  createTransferMessage,
+  airdrop,
+  fetchEvents,
+} from '../utils';
+
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  TransactionInstruction,
+} from '@solana/web3.js';
+import {
+  createApproveFounderTransactionInstruction,
+  createCreateFounderTransactionInstruction,
+} from '../../vault-x-sdk/src/generated';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+type TransactionEvent = {
+  vault: PublicKey;
+  actor: PublicKey;
+  transactionIndex: number;
+  kind: object;
+  transaction: PublicKey;
+  vote?: object;
+  status?: object;
+};
+
+describe('Instructions / Events', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let vaultPda: PublicKey;
+  let fundPda: PublicKey;
+
+  async function send(instruction: TransactionInstruction) {
+    const signature = await sendTransaction(
+      connection,
+      [instruction],
+      founder.publicKey,
+      [founder]
+    );
+    const events = await fetchEvents(connection, signature);
+
+    return events.map(({ name, data }) => ({
+      name,
+      data: data as unknown as TransactionEvent,
+    }));
+  }
+
+  function assertTransactionEvent(
+    event: TransactionEvent,
+    transactionPda: PublicKey,
+    transactionIndex: number
+  ) {
+    assert.ok(event.vault.equals(vaultPda));
+    assert.ok(event.actor.equals(founder.publicKey));
+    assert.strictEqual(event.transactionIndex, transactionIndex);
+    assert.deepStrictEqual(event.kind, { founder: {} });
+    assert.ok(event.transaction.equals(transactionPda));
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      initialFounders: [founder.publicKey],
+    }));
+
+    [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    await airdrop(connection, fundPda, LAMPORTS_PER_SOL);
+  });
+
+  it('emit events through the founder transaction lifecycle', async () => {
+    const transactionIndex = Number(
+      await getVaultTransactionIndex(connection, vaultPda)
+    );
+    const [transactionPda] = sdk.getFounderTransactionPda({
+      vaultPda,
+      index: transactionIndex,
+      programId,
+    });
+    const { transactionMessage } = await createTransferMessage(
+      connection,
+      fundPda,
+      founder.publicKey,
+      LAMPORTS_PER_SOL / 10
+    );
+
+    const createdEvents = await send(
+      createCreateFounderTransactionInstruction(
+        {
+          creator: founder.publicKey,
+          vault: vaultPda,
+          transaction: transactionPda,
+          ...getEventCpiAccounts(programId),
+        },
+        {
+          args: {
+            ephemeralSigners: 0,
+            transactionMessage,
+            expiry: null,
+          },
+        },
+        programId
+      )
+    );
+
+    assert.strictEqual(createdEvents.length, 1);
+    const [created] = createdEvents;
+    assert.strictEqual(created.name, 'TransactionCreatedEvent');
+    assertTransactionEvent(created.data, transactionPda, transactionIndex);
+
+    // Reaching the threshold changes the status along with the vote
+    const approvedEvents = await send(
+      createApproveFounderTransactionInstruction(
+        {
+          founder: founder.publicKey,
+          vault: vaultPda,
+          transaction: transactionPda,
+          ...getEventCpiAccounts(programId),
+        },
+        programId
+      )
+    );
+
+    const voteCast = approvedEvents.find(
+      e => e.name === 'TransactionVoteCastEvent'
+    );
+    assert.ok(voteCast);
+    assertTransactionEvent(voteCast.data, transactionPda, transactionIndex);
+    assert.deepStrictEqual(voteCast.data.vote, { approve: {} });
+
+    const statusChanged = approvedEvents.find(
+      e => e.name === 'TransactionStatusChangedEvent'
+    );
+    assert.ok(statusChanged);
+    assertTransactionEvent(
+      statusChanged.data,
+      transactionPda,
+      transactionIndex
+    );
+    assert.deepStrictEqual(statusChanged.data.status, { approved: {} });
+
+    const executedEvents = await send(
+      await createTestExecuteFounderTransactionIx(
+        connection,
+        vaultPda,
+        transactionPda,
+        founder.publicKey
+      )
+    );
+
+    const executed = executedEvents.find(
+      e => e.name === 'TransactionExecutedEvent'
+    );
+    assert.ok(executed);
+    assertTransactionEvent(executed.data, transactionPda, transactionIndex);
+    assert.ok(executedEvents.some(e => e.name === 'ExecutionReceiptEvent'));
+  });
+});
@@ -0,0 +1,191 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  generateFundedKeypairs,
+  getTestProgramId,
+  getEventCpiAccounts,
+  sendTransaction,
+  createTestVault,
+  createTransferMessage,
+  addTestMembers,
+  airdrop,
+  fetchProof,
+} from '../utils';
+
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import {
+  MerkleTree,
+  SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+} from '@solana/spl-account-compression';
+import {
+  createExecuteMemberMessageInstruction,
+  createUpdateMemberThresholdInstruction,
+  vaultTransactionMessageBeet,
+} from '../../vault-x-sdk/src/generated';
+import { populateVaultTransactionExecuteRemainingAccounts } from '../../vault-x-sdk/src/utils';
+
+const { Vault } = sdk.accounts;
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Execute member message', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let members: Keypair[];
+  let vaultPda: PublicKey;
+  let fundPda: PublicKey;
+  let merkleTree: Keypair;
+  let allowList: PublicKey[];
+
+  async function executeMessage(member: Keypair, lamports: number) {
+    const { transactionMessage } = await createTransferMessage(
+      connection,
+      fundPda,
+      member.publicKey,
+      lamports
+    );
+
+    const { leaf, index, root, remainingAccounts } = await fetchProof(
+      connection,
+      merkleTree.publicKey,
+      new MerkleTree(allowList.map(a => a.toBuffer())),
+      0
+    );
+
+    const [txMessage] =
+      vaultTransactionMessageBeet.deserialize(transactionMessage);
+    const [rateLimit] = sdk.getMemberRateLimitPda({
+      vaultPda,
+      member: member.publicKey,
+      programId,
+    });
+
+    const executeMessageIx = createExecuteMemberMessageInstruction(
+      {
+        vault: vaultPda,
+        member: member.publicKey,
+        rateLimit,
+        merkleTree: merkleTree.publicKey,
+        accountCompressionProgram: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+        ...getEventCpiAccounts(programId),
+        // The proof accounts come first, followed by the message accounts
+        anchorRemainingAccounts: [
+          ...remainingAccounts,
+          // Nothing is persisted, so the message has no ephemeral signers
+          ...populateVaultTransactionExecuteRemainingAccounts(
+            txMessage,
+            [],
+            fundPda,
+            PublicKey.default
+          ),
+        ],
+      },
+      {
+        args: {
+          transactionMessage,
+          allowListRoot: root,
+          allowListLeaf: leaf,
+          allowListLeafIndex: index,
+          allowListProofLength: remainingAccounts.length,
+        },
+      },
+      programId
+    );
+
+    return sendTransaction(connection, [executeMessageIx], member.publicKey, [
+      member,
+    ]).catch(sdk.errors.translateAndThrowAnchorError);
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+    members = await generateFundedKeypairs(connection, 1);
+
+    ({ vaultPda, merkleTree, allowList } = await createTestVault(
+      connection,
+      administrator,
+      { initialFounders: [founder.publicKey] }
+    ));
+
+    await addTestMembers(
+      connection,
+      vaultPda,
+      founder,
+      members.map(m => m.publicKey)
+    );
+
+    [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    await airdrop(connection, fundPda, LAMPORTS_PER_SOL);
+  });
+
+  it('execute a message without creating a transaction', async () => {
+    const member = members[0];
+    const lamports = LAMPORTS_PER_SOL / 10;
+
+    const { transactionIndex } = await Vault.fromAccountAddress(
+      connection,
+      vaultPda
+    );
+    const fundBalance = await connection.getBalance(fundPda);
+
+    await executeMessage(member, lamports);
+
+    assert.strictEqual(
+      await connection.getBalance(fundPda),
+      fundBalance - lamports
+    );
+
+    // No transaction account is created
+    const vault = await Vault.fromAccountAddress(connection, vaultPda);
+    assert.strictEqual(
+      vault.transactionIndex.toString(),
+      transactionIndex.toString()
+    );
+  });
+
+  it('error: execute a message as a non member', async () => {
+    const outsider = await generateFundedKeypair(connection);
+
+    await assert.rejects(
+      () => executeMessage(outsider, LAMPORTS_PER_SOL / 10),
+      /Member does not exist/
+    );
+  });
+
+  it('error: execute a message while member voting is enabled', async () => {
+    const updateMemberThresholdIx = createUpdateMemberThresholdInstruction(
+      {
+        vault: vaultPda,
+        administrator: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: { newMemberThreshold: 1 },
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [updateMemberThresholdIx],
+      administrator.publicKey,
+      [administrator]
+    );
+
+    await assert.rejects(
+      () => executeMessage(members[0], LAMPORTS_PER_SOL / 10),
+      /Member voting is enabled, member transactions must be approved/
+    );
+  });
+});
@@ -0,0 +1,235 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  getTestProgramId,
+  getEventCpiAccounts,
+  getVaultTransactionIndex,
+  sendTransaction,
+  createTestVault,
+  createTestExecuteFounderTransactionIx,
+  createTestMemberTransaction,
+  createTestExecuteMemberTransactionIx,
+  addTestMembers,
+  airdrop,
+  fetchEvents,
+} from '../utils';
+
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from '@solana/web3.js';
+import {
+  TOKEN_PROGRAM_ID,
+  createGetAccountDataSizeInstruction,
+  createMint,
+} from '@solana/spl-token';
+import {
+  ExecutionReceipt,
+  createApproveFounderTransactionInstruction,
+  createCreateFounderTransactionInstruction,
+} from '../../vault-x-sdk/src/generated';
+import {
+  serializeVaultTransactionMessage,
+  toBigInt,
+} from '../../vault-x-sdk/src/utils';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+type ExecutionReceiptEvent = {
+  vault: PublicKey;
+  actor: PublicKey;
+  transactionIndex: number;
+  kind: object;
+  transaction: PublicKey | null;
+  receipt: ExecutionReceipt;
+};
+
+describe('Instructions / Execution receipt', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let member: Keypair;
+  let vaultPda: PublicKey;
+  let fundPda: PublicKey;
+  let merkleTree: Keypair;
+  let allowList: PublicKey[];
+
+  async function fetchReceiptEvent(signature: string) {
+    const events = await fetchEvents(connection, signature);
+    const event = events.find(e => e.name === 'ExecutionReceiptEvent');
+    assert.ok(event);
+
+    return event.data as unknown as ExecutionReceiptEvent;
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+    member = await generateFundedKeypair(connection);
+
+    ({ vaultPda, merkleTree, allowList } = await createTestVault(
+      connection,
+      administrator,
+      { initialFounders: [founder.publicKey] }
+    ));
+
+    await addTestMembers(connection, vaultPda, founder, [member.publicKey]);
+
+    [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    await airdrop(connection, fundPda, LAMPORTS_PER_SOL);
+  });
+
+  it('receipt of a founder transaction with return data', async () => {
+    const lamports = LAMPORTS_PER_SOL / 10;
+    const mint = await createMint(
+      connection,
+      founder,
+      founder.publicKey,
+      null,
+      0
+    );
+
+    // Only the second instruction sets return data, the size of a token account
+    const message = new TransactionMessage({
+      payerKey: fundPda,
+      recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+      instructions: [
+        SystemProgram.transfer({
+          fromPubkey: fundPda,
+          toPubkey: founder.publicKey,
+          lamports,
+        }),
+        createGetAccountDataSizeInstruction(mint, []),
+      ],
+    }).compileToV0Message();
+
+    const transactionIndex = await getVaultTransactionIndex(
+      connection,
+      vaultPda
+    );
+    const [transactionPda] = sdk.getFounderTransactionPda({
+      vaultPda,
+      index: Number(transactionIndex),
+      programId,
+    });
+
+    const createTransactionIx = createCreateFounderTransactionInstruction(
+      {
+        creator: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          ephemeralSigners: 0,
+          transactionMessage: serializeVaultTransactionMessage(message),
+          expiry: null,
+        },
+      },
+      programId
+    );
+    const approveIx = createApproveFounderTransactionInstruction(
+      {
+        founder: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [createTransactionIx, approveIx],
+      founder.publicKey,
+      [founder]
+    );
+
+    const executeIx = await createTestExecuteFounderTransactionIx(
+      connection,
+      vaultPda,
+      transactionPda,
+      founder.publicKey
+    );
+    const signature = await sendTransaction(
+      connection,
+      [executeIx],
+      founder.publicKey,
+      [founder]
+    );
+
+    const event = await fetchReceiptEvent(signature);
+    assert.ok(event.vault.equals(vaultPda));
+    assert.ok(event.actor.equals(founder.publicKey));
+    assert.strictEqual(event.transactionIndex, Number(transactionIndex));
+    assert.deepStrictEqual(event.kind, { founder: {} });
+    assert.ok(event.transaction?.equals(transactionPda));
+
+    const { fundLamportsBefore, fundLamportsAfter, returnData } = event.receipt;
+    assert.strictEqual(
+      toBigInt(fundLamportsBefore!) - toBigInt(fundLamportsAfter!),
+      BigInt(lamports)
+    );
+
+    assert.strictEqual(returnData.length, 1);
+    assert.strictEqual(returnData[0].instructionIndex, 1);
+    assert.ok(returnData[0].programId.equals(TOKEN_PROGRAM_ID));
+    // A token account is 165 bytes, returned as a little endian u64
+    assert.strictEqual(
+      Buffer.from(returnData[0].data).readBigUInt64LE(),
+      BigInt(165)
+    );
+  });
+
+  it('receipt of a member transaction', async () => {
+    const lamports = LAMPORTS_PER_SOL / 10;
+    const { transactionPda, transactionIndex } =
+      await createTestMemberTransaction(
+        connection,
+        vaultPda,
+        member,
+        merkleTree.publicKey,
+        allowList,
+        { lamports }
+      );
+
+    const executeIx = await createTestExecuteMemberTransactionIx(
+      connection,
+      vaultPda,
+      transactionPda,
+      member.publicKey
+    );
+    const signature = await sendTransaction(
+      connection,
+      [executeIx],
+      member.publicKey,
+      [member]
+    );
+
+    const event = await fetchReceiptEvent(signature);
+    assert.ok(event.actor.equals(member.publicKey));
+    assert.strictEqual(event.transactionIndex, Number(transactionIndex));
+    assert.deepStrictEqual(event.kind, { member: {} });
+    assert.ok(event.transaction?.equals(transactionPda));
+
+    const { fundLamportsBefore, fundLamportsAfter, returnData } = event.receipt;
+    assert.strictEqual(
+      toBigInt(fundLamportsBefore!) - toBigInt(fundLamportsAfter!),
+      BigInt(lamports)
+    );
+    assert.deepStrictEqual(returnData, []);
+  });
+});
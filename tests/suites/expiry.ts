@@ -0,0 +1,199 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  generateFundedKeypairs,
+  getTestProgramId,
+  getEventCpiAccounts,
+  sendTransaction,
+  createTestVault,
+  createTestFounderTransaction,
+  sleep,
+  fetchEvents,
+} from '../utils';
+
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  VaultFounderTransaction,
+  VaultTransactionStatus,
+  createApproveFounderTransactionInstruction,
+  createExpireFounderTransactionInstruction,
+  createUpdateMaxExpiryInstruction,
+  isVaultTransactionExpiryTimestamp,
+} from '../../vault-x-sdk/src/generated';
+import { toBigInt } from '../../vault-x-sdk/src/utils';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Founder transaction expiry', () => {
+  let administrator: Keypair;
+  let founders: Keypair[];
+  let vaultPda: PublicKey;
+
+  async function getClusterTime() {
+    const blockTime = await connection.getBlockTime(await connection.getSlot());
+    return blockTime ?? Math.floor(Date.now() / 1000);
+  }
+
+  function createExpireIx(transactionPda: PublicKey, caller: PublicKey) {
+    return createExpireFounderTransactionInstruction(
+      {
+        transaction: transactionPda,
+        vault: vaultPda,
+        caller,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founders = await generateFundedKeypairs(connection, 2);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      founderThreshold: 2,
+      initialFounders: founders.map(f => f.publicKey),
+    }));
+  });
+
+  it('error: expiry in the past', async () => {
+    const now = await getClusterTime();
+
+    await assert.rejects(
+      () =>
+        createTestFounderTransaction(connection, vaultPda, founders[0], {
+          expiry: { __kind: 'Timestamp', fields: [now - 10] },
+        }).catch(sdk.errors.translateAndThrowAnchorError),
+      /Transaction expiry is in the past or exceeds the vault's maximum/
+    );
+  });
+
+  it('expire a transaction once its expiry is reached', async () => {
+    const founder = founders[0];
+    const now = await getClusterTime();
+
+    const { transactionPda } = await createTestFounderTransaction(
+      connection,
+      vaultPda,
+      founder,
+      { expiry: { __kind: 'Timestamp', fields: [now + 3] } }
+    );
+
+    // The transaction can't be expired before its expiry
+    await assert.rejects(
+      () =>
+        sendTransaction(
+          connection,
+          [createExpireIx(transactionPda, founder.publicKey)],
+          founder.publicKey,
+          [founder]
+        ).catch(sdk.errors.translateAndThrowAnchorError),
+      /Transaction expiry is in the past or exceeds the vault's maximum/
+    );
+
+    await sleep(5000);
+
+    const approveIx = createApproveFounderTransactionInstruction(
+      {
+        founder: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [approveIx], founder.publicKey, [
+          founder,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Invalid transaction status for voting/
+    );
+
+    // Expiring is permissionless
+    const payer = await generateFundedKeypair(connection);
+    const signature = await sendTransaction(
+      connection,
+      [createExpireIx(transactionPda, payer.publicKey)],
+      payer.publicKey,
+      [payer]
+    );
+
+    const events = await fetchEvents(connection, signature);
+    const statusChanged = events.find(
+      e => e.name === 'TransactionStatusChangedEvent'
+    );
+    assert.ok(statusChanged);
+    assert.ok((statusChanged.data.actor as PublicKey).equals(payer.publicKey));
+
+    const transaction = await VaultFounderTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+    assert.strictEqual(transaction.status, VaultTransactionStatus.Expired);
+  });
+
+  it('cap transactions by the vault maximum expiry', async () => {
+    const maxExpirySeconds = 60;
+    const updateMaxExpiryIx = createUpdateMaxExpiryInstruction(
+      {
+        vault: vaultPda,
+        administrator: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          newMaxExpirySeconds: maxExpirySeconds,
+          newMaxExpirySlots: 0,
+        },
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [updateMaxExpiryIx],
+      administrator.publicKey,
+      [administrator]
+    );
+
+    const founder = founders[1];
+    const now = await getClusterTime();
+
+    // An expiry beyond the maximum is rejected
+    await assert.rejects(
+      () =>
+        createTestFounderTransaction(connection, vaultPda, founder, {
+          expiry: { __kind: 'Timestamp', fields: [now + 2 * maxExpirySeconds] },
+        }).catch(sdk.errors.translateAndThrowAnchorError),
+      /Transaction expiry is in the past or exceeds the vault's maximum/
+    );
+
+    // Transactions without an expiry default to the maximum
+    const { transactionPda } = await createTestFounderTransaction(
+      connection,
+      vaultPda,
+      founder
+    );
+
+    const { expiry } = await VaultFounderTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+    assert.ok(expiry && isVaultTransactionExpiryTimestamp(expiry));
+
+    const expiresAt = Number(toBigInt(expiry.fields[0]));
+    assert.ok(expiresAt > now && expiresAt <= now + maxExpirySeconds + 5);
+  });
+});
@@ -11,6 +11,7 @@ import {
   createLocalhostConnection,
   generateFundedKeypair,
   getTestProgramId,
+  getEventCpiAccounts,
   sendTransaction,
   generateFundedKeypairs,
   createAndAddLeavesToMerkleTreeIx,
@@ -85,11 +86,15 @@ describe('Instructions / Founder', () => {
         vaultConfig: vaultConfigPda,
         merkleTree: merkleTree.publicKey,
         treasury: programTreasury,
+        ...getEventCpiAccounts(programId),
       },
       {
         args: {
           founderThreshold: 2,
+          timeLockSeconds: 0,
           initialFounders: founders.map(f => f.publicKey),
+          initialFounderWeights: null,
+          metadata: null,
         },
       }
     );
@@ -177,11 +182,13 @@ describe('Instructions / Founder', () => {
           creator: initiator.publicKey,
           vault: vaultPda,
           transaction: founderTransactionPda,
+          ...getEventCpiAccounts(programId),
         },
         {
           args: {
             ephemeralSigners: 1,
             transactionMessage,
+            expiry: null,
           },
         }
       );
@@ -201,6 +208,7 @@ describe('Instructions / Founder', () => {
       founder: firstVoter.publicKey,
       vault: vaultPda,
       transaction: founderTransactionPda,
+      ...getEventCpiAccounts(programId),
     });
 
     await sendTransaction(connection, [approve1Ix], firstVoter.publicKey, [
@@ -214,6 +222,7 @@ describe('Instructions / Founder', () => {
       founder: secondVoter.publicKey,
       vault: vaultPda,
       transaction: founderTransactionPda,
+      ...getEventCpiAccounts(programId),
     });
 
     await sendTransaction(connection, [approve2Ix], secondVoter.publicKey, [
@@ -235,6 +244,7 @@ describe('Instructions / Founder', () => {
         founder: executor.publicKey,
         vault: vaultPda,
         transaction: founderTransactionPda,
+        ...getEventCpiAccounts(programId),
         anchorRemainingAccounts:
           populateVaultTransactionExecuteRemainingAccounts(
             txMessage,
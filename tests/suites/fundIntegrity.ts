@@ -0,0 +1,205 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  getTestProgramId,
+  getEventCpiAccounts,
+  getVaultTransactionIndex,
+  sendTransaction,
+  createTestVault,
+  createTestExecuteFounderTransactionIx,
+  airdrop,
+} from '../utils';
+
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  TransactionInstruction,
+  TransactionMessage,
+} from '@solana/web3.js';
+import {
+  AuthorityType,
+  createAccount,
+  createCloseAccountInstruction,
+  createMint,
+  createSetAuthorityInstruction,
+} from '@solana/spl-token';
+import {
+  createApproveFounderTransactionInstruction,
+  createCreateFounderTransactionInstruction,
+} from '../../vault-x-sdk/src/generated';
+import { serializeVaultTransactionMessage } from '../../vault-x-sdk/src/utils';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Fund integrity', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let vaultPda: PublicKey;
+  let fundPda: PublicKey;
+  let mint: PublicKey;
+
+  function createFundTokenAccount() {
+    return createAccount(
+      connection,
+      founder,
+      mint,
+      fundPda,
+      Keypair.generate()
+    );
+  }
+
+  async function executeInstructions(instructions: TransactionInstruction[]) {
+    const message = new TransactionMessage({
+      payerKey: fundPda,
+      recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+      instructions,
+    }).compileToV0Message();
+
+    const transactionIndex = await getVaultTransactionIndex(
+      connection,
+      vaultPda
+    );
+    const [transactionPda] = sdk.getFounderTransactionPda({
+      vaultPda,
+      index: Number(transactionIndex),
+      programId,
+    });
+
+    const createTransactionIx = createCreateFounderTransactionInstruction(
+      {
+        creator: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          ephemeralSigners: 0,
+          transactionMessage: serializeVaultTransactionMessage(message),
+          expiry: null,
+        },
+      },
+      programId
+    );
+    const approveIx = createApproveFounderTransactionInstruction(
+      {
+        founder: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [createTransactionIx, approveIx],
+      founder.publicKey,
+      [founder]
+    );
+
+    const executeIx = await createTestExecuteFounderTransactionIx(
+      connection,
+      vaultPda,
+      transactionPda,
+      founder.publicKey
+    );
+
+    return sendTransaction(connection, [executeIx], founder.publicKey, [
+      founder,
+    ]).catch(sdk.errors.translateAndThrowAnchorError);
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      initialFounders: [founder.publicKey],
+    }));
+
+    [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    await airdrop(connection, fundPda, LAMPORTS_PER_SOL);
+
+    mint = await createMint(connection, founder, founder.publicKey, null, 0);
+  });
+
+  it('error: assign the fund to another program', async () => {
+    await assert.rejects(
+      () =>
+        executeInstructions([
+          SystemProgram.assign({
+            accountPubkey: fundPda,
+            programId: Keypair.generate().publicKey,
+          }),
+        ]),
+      /Execution changed the owner of the fund or of its token accounts/
+    );
+  });
+
+  it('error: allocate data to the fund', async () => {
+    await assert.rejects(
+      () =>
+        executeInstructions([
+          SystemProgram.allocate({ accountPubkey: fundPda, space: 8 }),
+        ]),
+      /Execution changed the owner of the fund or of its token accounts/
+    );
+  });
+
+  it('error: hand off the ownership of a fund token account', async () => {
+    const tokenAccount = await createFundTokenAccount();
+
+    await assert.rejects(
+      () =>
+        executeInstructions([
+          createSetAuthorityInstruction(
+            tokenAccount,
+            fundPda,
+            AuthorityType.AccountOwner,
+            founder.publicKey
+          ),
+        ]),
+      /Execution changed the owner of the fund or of its token accounts/
+    );
+  });
+
+  it('error: hand off the close authority of a fund token account', async () => {
+    const tokenAccount = await createFundTokenAccount();
+
+    await assert.rejects(
+      () =>
+        executeInstructions([
+          createSetAuthorityInstruction(
+            tokenAccount,
+            fundPda,
+            AuthorityType.CloseAccount,
+            founder.publicKey
+          ),
+        ]),
+      /Execution changed the owner of the fund or of its token accounts/
+    );
+  });
+
+  it('close a fund token account', async () => {
+    const tokenAccount = await createFundTokenAccount();
+
+    await executeInstructions([
+      createCloseAccountInstruction(tokenAccount, fundPda, fundPda),
+    ]);
+
+    assert.strictEqual(await connection.getAccountInfo(tokenAccount), null);
+  });
+});
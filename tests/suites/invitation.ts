@@ -0,0 +1,204 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  getTestProgramId,
+  getEventCpiAccounts,
+  sendTransaction,
+  createTestVault,
+} from '../utils';
+
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  VaultRole,
+  createAcceptInvitationInstruction,
+  createCreateInvitationInstruction,
+  createRevokeInvitationInstruction,
+} from '../../vault-x-sdk/src/generated';
+
+const { Vault } = sdk.accounts;
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Invitation', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let vaultPda: PublicKey;
+
+  function getInvitationPda(invitee: PublicKey) {
+    return sdk.getInvitationPda({ vaultPda, invitee, programId })[0];
+  }
+
+  async function invite(
+    inviter: Keypair,
+    invitee: PublicKey,
+    role: VaultRole,
+    founderWeight: number | null = null
+  ) {
+    const createInvitationIx = createCreateInvitationInstruction(
+      {
+        invitation: getInvitationPda(invitee),
+        vault: vaultPda,
+        inviter: inviter.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          invitee,
+          role,
+          founderWeight,
+          expiresAt: Math.floor(Date.now() / 1000) + 3600,
+        },
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [createInvitationIx], inviter.publicKey, [
+      inviter,
+    ]);
+  }
+
+  async function accept(invitee: Keypair, inviter: PublicKey) {
+    const acceptInvitationIx = createAcceptInvitationInstruction(
+      {
+        invitation: getInvitationPda(invitee.publicKey),
+        vault: vaultPda,
+        invitee: invitee.publicKey,
+        inviter,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [acceptInvitationIx], invitee.publicKey, [
+      invitee,
+    ]);
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      initialFounders: [founder.publicKey],
+    }));
+  });
+
+  it('invite a member who accepts', async () => {
+    const invitee = await generateFundedKeypair(connection);
+
+    await invite(founder, invitee.publicKey, VaultRole.Member);
+    await accept(invitee, founder.publicKey);
+
+    const vault = await Vault.fromAccountAddress(connection, vaultPda);
+    assert.ok(vault.members.some(m => m.equals(invitee.publicKey)));
+    assert.strictEqual(
+      await connection.getAccountInfo(getInvitationPda(invitee.publicKey)),
+      null
+    );
+  });
+
+  it('invite a weighted founder who accepts', async () => {
+    const invitee = await generateFundedKeypair(connection);
+
+    await invite(administrator, invitee.publicKey, VaultRole.Founder, 2);
+    await accept(invitee, administrator.publicKey);
+
+    const vault = await Vault.fromAccountAddress(connection, vaultPda);
+    const founderIndex = vault.founders.findIndex(f =>
+      f.equals(invitee.publicKey)
+    );
+    assert.notStrictEqual(founderIndex, -1);
+    assert.strictEqual(vault.founderWeights[founderIndex], 2);
+  });
+
+  it('error: founder inviting a founder', async () => {
+    const invitee = Keypair.generate();
+
+    await assert.rejects(
+      () =>
+        invite(founder, invitee.publicKey, VaultRole.Founder).catch(
+          sdk.errors.translateAndThrowAnchorError
+        ),
+      /Unauthorized action attempted/
+    );
+  });
+
+  it('error: accept an invitation meant for another key', async () => {
+    const invitee = Keypair.generate();
+    const impostor = await generateFundedKeypair(connection);
+
+    await invite(founder, invitee.publicKey, VaultRole.Member);
+
+    const acceptInvitationIx = createAcceptInvitationInstruction(
+      {
+        invitation: getInvitationPda(invitee.publicKey),
+        vault: vaultPda,
+        invitee: impostor.publicKey,
+        inviter: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await assert.rejects(() =>
+      sendTransaction(
+        connection,
+        [acceptInvitationIx],
+        impostor.publicKey,
+        [impostor]
+      )
+    );
+  });
+
+  it('revoke an invitation as its invitee', async () => {
+    const invitee = await generateFundedKeypair(connection);
+    await invite(founder, invitee.publicKey, VaultRole.Member);
+
+    const outsider = await generateFundedKeypair(connection);
+    const createRevokeIx = (authority: PublicKey) =>
+      createRevokeInvitationInstruction(
+        {
+          invitation: getInvitationPda(invitee.publicKey),
+          vault: vaultPda,
+          inviter: founder.publicKey,
+          authority,
+          ...getEventCpiAccounts(programId),
+        },
+        programId
+      );
+
+    // Only the inviter or the invitee can revoke a pending invitation
+    await assert.rejects(
+      () =>
+        sendTransaction(
+          connection,
+          [createRevokeIx(outsider.publicKey)],
+          outsider.publicKey,
+          [outsider]
+        ).catch(sdk.errors.translateAndThrowAnchorError),
+      /Unauthorized action attempted/
+    );
+
+    await sendTransaction(
+      connection,
+      [createRevokeIx(invitee.publicKey)],
+      invitee.publicKey,
+      [invitee]
+    );
+
+    assert.strictEqual(
+      await connection.getAccountInfo(getInvitationPda(invitee.publicKey)),
+      null
+    );
+  });
+});
@@ -0,0 +1,169 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  generateFundedKeypairs,
+  getTestProgramId,
+  getEventCpiAccounts,
+  sendTransaction,
+  createTestVault,
+  addTestMembers,
+} from '../utils';
+
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  createLeaveVaultInstruction,
+  createResignFounderInstruction,
+} from '../../vault-x-sdk/src/generated';
+
+const { Vault } = sdk.accounts;
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Leave and resign', () => {
+  let administrator: Keypair;
+  let founders: Keypair[];
+  let members: Keypair[];
+  let vaultPda: PublicKey;
+
+  function resign(
+    founder: Keypair,
+    newFounderThreshold: number | null,
+    vault = vaultPda
+  ) {
+    const resignFounderIx = createResignFounderInstruction(
+      {
+        vault,
+        founder: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: { newFounderThreshold },
+      },
+      programId
+    );
+
+    return sendTransaction(connection, [resignFounderIx], founder.publicKey, [
+      founder,
+    ]).catch(sdk.errors.translateAndThrowAnchorError);
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founders = await generateFundedKeypairs(connection, 3);
+    members = await generateFundedKeypairs(connection, 2);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      founderThreshold: 3,
+      initialFounders: founders.map(f => f.publicKey),
+    }));
+
+    await addTestMembers(
+      connection,
+      vaultPda,
+      founders[0],
+      members.map(m => m.publicKey)
+    );
+  });
+
+  it('leave the vault as a member', async () => {
+    const member = members[0];
+
+    const leaveIx = createLeaveVaultInstruction(
+      {
+        vault: vaultPda,
+        member: member.publicKey,
+        rentCollector: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [leaveIx], member.publicKey, [member]);
+
+    const vault = await Vault.fromAccountAddress(connection, vaultPda);
+    assert.ok(!vault.members.some(m => m.equals(member.publicKey)));
+    assert.ok(vault.members.some(m => m.equals(members[1].publicKey)));
+  });
+
+  it('error: leave the vault as a non member', async () => {
+    const outsider = await generateFundedKeypair(connection);
+
+    const leaveIx = createLeaveVaultInstruction(
+      {
+        vault: vaultPda,
+        member: outsider.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [leaveIx], outsider.publicKey, [
+          outsider,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Member does not exist/
+    );
+  });
+
+  it('error: resign leaving the threshold unreachable', async () => {
+    await assert.rejects(
+      () => resign(founders[2], null),
+      /Invalid founder threshold/
+    );
+  });
+
+  it('error: resign with a threshold above the remaining weight', async () => {
+    await assert.rejects(
+      () => resign(founders[2], 4),
+      /Invalid founder threshold/
+    );
+  });
+
+  it('resign as a founder lowering the threshold', async () => {
+    await resign(founders[2], 2);
+
+    const vault = await Vault.fromAccountAddress(connection, vaultPda);
+    assert.strictEqual(vault.founderThreshold, 2);
+    assert.strictEqual(vault.founders.length, 2);
+    assert.ok(!vault.founders.some(f => f.equals(founders[2].publicKey)));
+  });
+
+  it('error: resign lowering a threshold the remaining founders reach', async () => {
+    const { vaultPda: otherVaultPda } = await createTestVault(
+      connection,
+      administrator,
+      {
+        founderThreshold: 2,
+        initialFounders: founders.map(f => f.publicKey),
+      }
+    );
+
+    await assert.rejects(
+      () => resign(founders[2], 1, otherVaultPda),
+      /Invalid founder threshold/
+    );
+
+    await resign(founders[2], null, otherVaultPda);
+
+    const vault = await Vault.fromAccountAddress(connection, otherVaultPda);
+    assert.strictEqual(vault.founderThreshold, 2);
+    assert.strictEqual(vault.founders.length, 2);
+  });
+
+  it('error: the last founder resigns', async () => {
+    await resign(founders[1], 1);
+
+    await assert.rejects(() => resign(founders[0], null), /Invalid role count/);
+  });
+});
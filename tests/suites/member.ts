@@ -11,6 +11,7 @@ import {
   createLocalhostConnection,
   generateFundedKeypair,
   getTestProgramId,
+  getEventCpiAccounts,
   sendTransaction,
   generateFundedKeypairs,
   createAndAddLeavesToMerkleTreeIx,
@@ -96,11 +97,15 @@ describe('Instructions / Member', () => {
         vaultConfig: vaultConfigPda,
         merkleTree: merkleTree.publicKey,
         treasury: programTreasury,
+        ...getEventCpiAccounts(programId),
       },
       {
         args: {
           founderThreshold: 1,
+          timeLockSeconds: 0,
           initialFounders: founders.map(f => f.publicKey),
+          initialFounderWeights: null,
+          metadata: null,
         },
       }
     );
@@ -122,6 +127,7 @@ describe('Instructions / Member', () => {
           vault: vaultPda,
           rentPayer: founder.publicKey,
           systemProgram: SystemProgram.programId,
+          ...getEventCpiAccounts(programId),
         },
         {
           args: {
@@ -217,13 +223,20 @@ describe('Instructions / Member', () => {
     );
 
     const member = members[0];
+    const [rateLimit] = sdk.getMemberRateLimitPda({
+      vaultPda,
+      member: member.publicKey,
+      programId,
+    });
     const createMemberTransactionIx = createCreateMemberTransactionInstruction(
       {
         accountCompressionProgram: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
         creator: member.publicKey,
         vault: vaultPda,
+        rateLimit,
         merkleTree: merkleTree.publicKey,
         transaction: memberTransactionPda,
+        ...getEventCpiAccounts(programId),
         anchorRemainingAccounts: remainingAccounts,
       },
       {
@@ -233,6 +246,8 @@ describe('Instructions / Member', () => {
           allowListRoot,
           ephemeralSigners: 1,
           transactionMessage,
+          maxExecutions: null,
+          cooldownSeconds: null,
         },
       }
     );
@@ -244,7 +259,9 @@ describe('Instructions / Member', () => {
       {
         member: member.publicKey,
         vault: vaultPda,
+        rateLimit,
         transaction: memberTransactionPda,
+        ...getEventCpiAccounts(programId),
         anchorRemainingAccounts:
           populateVaultTransactionExecuteRemainingAccounts(
             txMessage,
@@ -367,13 +384,20 @@ describe('Instructions / Member', () => {
     /** ENDREGION */
 
     const member = members[0];
+    const [rateLimit] = sdk.getMemberRateLimitPda({
+      vaultPda,
+      member: member.publicKey,
+      programId,
+    });
     const createMemberTransactionIx = createCreateMemberTransactionInstruction(
       {
         accountCompressionProgram: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
         creator: member.publicKey,
         vault: vaultPda,
+        rateLimit,
         merkleTree: merkleTree.publicKey,
         transaction: memberTransactionPda,
+        ...getEventCpiAccounts(programId),
         anchorRemainingAccounts: remainingAccounts,
       },
       {
@@ -383,6 +407,8 @@ describe('Instructions / Member', () => {
           allowListRoot,
           ephemeralSigners: 1,
           transactionMessage,
+          maxExecutions: null,
+          cooldownSeconds: null,
         },
       }
     );
@@ -394,7 +420,9 @@ describe('Instructions / Member', () => {
       createExecuteMemberTransactionInstruction({
         member: member.publicKey,
         vault: vaultPda,
+        rateLimit,
         transaction: memberTransactionPda,
+        ...getEventCpiAccounts(programId),
         anchorRemainingAccounts:
           populateVaultTransactionExecuteRemainingAccounts(
             txMessage,
@@ -0,0 +1,129 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  getTestProgramId,
+  getEventCpiAccounts,
+  sendTransaction,
+  createTestVault,
+  addTestMembers,
+} from '../utils';
+
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { createRemoveMembersInstruction } from '../../vault-x-sdk/src/generated';
+
+const { Vault } = sdk.accounts;
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Batch members', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let vaultPda: PublicKey;
+  const members = Array.from({ length: 5 }).map(
+    () => Keypair.generate().publicKey
+  );
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      initialFounders: [founder.publicKey],
+    }));
+  });
+
+  it('add many members at once', async () => {
+    await addTestMembers(connection, vaultPda, founder, members);
+
+    const vault = await Vault.fromAccountAddress(connection, vaultPda);
+    assert.deepEqual(
+      vault.members,
+      [...members].sort((a, b) => a.toBuffer().compare(b.toBuffer()))
+    );
+  });
+
+  it('error: add an existing member', async () => {
+    await assert.rejects(
+      () =>
+        addTestMembers(connection, vaultPda, founder, [
+          Keypair.generate().publicKey,
+          members[0],
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Member already exists/
+    );
+  });
+
+  it('error: add the administrator as a member', async () => {
+    await assert.rejects(
+      () =>
+        addTestMembers(connection, vaultPda, founder, [
+          administrator.publicKey,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Administrator cannot be a member/
+    );
+  });
+
+  it('remove many members at once', async () => {
+    const removeMembersIx = createRemoveMembersInstruction(
+      {
+        vault: vaultPda,
+        founder: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          members: members.slice(0, 3),
+        },
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [removeMembersIx], founder.publicKey, [
+      founder,
+    ]);
+
+    const vault = await Vault.fromAccountAddress(connection, vaultPda);
+    assert.deepEqual(
+      vault.members,
+      members.slice(3).sort((a, b) => a.toBuffer().compare(b.toBuffer()))
+    );
+    assert.strictEqual(
+      vault.staleTransactionIndex.toString(),
+      vault.transactionIndex.toString()
+    );
+  });
+
+  it('error: remove a member that is not part of the vault', async () => {
+    const removeMembersIx = createRemoveMembersInstruction(
+      {
+        vault: vaultPda,
+        founder: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          members: [members[4], Keypair.generate().publicKey],
+        },
+      },
+      programId
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [removeMembersIx], founder.publicKey, [
+          founder,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Member does not exist/
+    );
+  });
+});
@@ -0,0 +1,191 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  generateFundedKeypairs,
+  getTestProgramId,
+  getEventCpiAccounts,
+  sendTransaction,
+  createTestVault,
+  createTestMemberTransaction,
+  createTestExecuteMemberTransactionIx,
+  addTestMembers,
+  airdrop,
+  sleep,
+} from '../utils';
+
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import {
+  VaultMemberTransaction,
+  VaultTransactionStatus,
+  createCloseMemberTransactionInstruction,
+} from '../../vault-x-sdk/src/generated';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+const COOLDOWN_SECONDS = 3;
+
+describe('Instructions / Member transaction lifecycle', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let members: Keypair[];
+  let vaultPda: PublicKey;
+  let merkleTree: Keypair;
+  let allowList: PublicKey[];
+
+  function createTransaction(
+    member: Keypair,
+    maxExecutions: number | null = null,
+    cooldownSeconds: number | null = null
+  ) {
+    return createTestMemberTransaction(
+      connection,
+      vaultPda,
+      member,
+      merkleTree.publicKey,
+      allowList,
+      { lamports: LAMPORTS_PER_SOL / 100, maxExecutions, cooldownSeconds }
+    );
+  }
+
+  // Any member can execute a transaction, not only its creator
+  async function execute(transactionPda: PublicKey, member = members[1]) {
+    const executeIx = await createTestExecuteMemberTransactionIx(
+      connection,
+      vaultPda,
+      transactionPda,
+      member.publicKey
+    );
+
+    return sendTransaction(connection, [executeIx], member.publicKey, [
+      member,
+    ]).catch(sdk.errors.translateAndThrowAnchorError);
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+    members = await generateFundedKeypairs(connection, 2);
+
+    ({ vaultPda, merkleTree, allowList } = await createTestVault(
+      connection,
+      administrator,
+      { initialFounders: [founder.publicKey] }
+    ));
+
+    await addTestMembers(
+      connection,
+      vaultPda,
+      founder,
+      members.map(m => m.publicKey)
+    );
+
+    const [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    await airdrop(connection, fundPda, LAMPORTS_PER_SOL);
+  });
+
+  it('execute a member transaction only once by default', async () => {
+    const { transactionPda } = await createTransaction(members[0]);
+
+    await execute(transactionPda);
+
+    const transaction = await VaultMemberTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+    assert.strictEqual(transaction.status, VaultTransactionStatus.Executed);
+    assert.strictEqual(transaction.executionCount, 1);
+    assert.strictEqual(transaction.maxExecutions, 1);
+
+    await assert.rejects(
+      () => execute(transactionPda, members[0]),
+      /Invalid transaction status for voting/
+    );
+  });
+
+  it('execute a member transaction up to its max executions', async () => {
+    const { transactionPda } = await createTransaction(members[0], 2);
+
+    await execute(transactionPda);
+
+    let transaction = await VaultMemberTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+    assert.strictEqual(transaction.status, VaultTransactionStatus.Approved);
+    assert.strictEqual(transaction.executionCount, 1);
+
+    await execute(transactionPda, members[0]);
+
+    transaction = await VaultMemberTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+    assert.strictEqual(transaction.status, VaultTransactionStatus.Executed);
+    assert.strictEqual(transaction.executionCount, 2);
+  });
+
+  it('execute a standing order once its cooldown elapsed', async () => {
+    const { transactionPda } = await createTransaction(
+      members[0],
+      0,
+      COOLDOWN_SECONDS
+    );
+
+    await execute(transactionPda);
+
+    await assert.rejects(
+      () => execute(transactionPda, members[0]),
+      /Cooldown has not elapsed since the transaction was last executed/
+    );
+
+    await sleep((COOLDOWN_SECONDS + 1) * 1000);
+    await execute(transactionPda);
+
+    // Standing orders are never spent
+    const transaction = await VaultMemberTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+    assert.strictEqual(transaction.status, VaultTransactionStatus.Approved);
+    assert.strictEqual(transaction.executionCount, 2);
+  });
+
+  it('close an executed member transaction as anyone', async () => {
+    const creator = members[0];
+    const { transactionPda } = await createTransaction(creator);
+    await execute(transactionPda);
+
+    const creatorBalance = await connection.getBalance(creator.publicKey);
+    const transactionBalance = await connection.getBalance(transactionPda);
+
+    const closer = await generateFundedKeypair(connection);
+    const closeIx = createCloseMemberTransactionInstruction(
+      {
+        transaction: transactionPda,
+        vault: vaultPda,
+        creator: creator.publicKey,
+        closer: closer.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [closeIx], closer.publicKey, [closer]);
+
+    assert.strictEqual(await connection.getAccountInfo(transactionPda), null);
+    assert.strictEqual(
+      await connection.getBalance(creator.publicKey),
+      creatorBalance + transactionBalance
+    );
+  });
+});
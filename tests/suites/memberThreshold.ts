@@ -0,0 +1,241 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  generateFundedKeypairs,
+  getTestProgramId,
+  getEventCpiAccounts,
+  sendTransaction,
+  createTestVault,
+  createTestMemberTransaction,
+  createTestExecuteMemberTransactionIx,
+  addTestMembers,
+  airdrop,
+} from '../utils';
+
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import {
+  VaultMemberTransaction,
+  VaultTransactionStatus,
+  createApproveMemberTransactionInstruction,
+  createRejectMemberTransactionInstruction,
+  createUpdateMemberThresholdInstruction,
+} from '../../vault-x-sdk/src/generated';
+
+const { Vault } = sdk.accounts;
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Member threshold', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let members: Keypair[];
+  let vaultPda: PublicKey;
+  let merkleTree: Keypair;
+  let allowList: PublicKey[];
+  let autoApprovedTransactionPda: PublicKey;
+
+  function createTransaction(member: Keypair) {
+    return createTestMemberTransaction(
+      connection,
+      vaultPda,
+      member,
+      merkleTree.publicKey,
+      allowList,
+      { lamports: LAMPORTS_PER_SOL / 10 }
+    );
+  }
+
+  async function getStatus(transactionPda: PublicKey) {
+    const { status } = await VaultMemberTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+    return status;
+  }
+
+  function updateMemberThreshold(newMemberThreshold: number) {
+    const updateMemberThresholdIx = createUpdateMemberThresholdInstruction(
+      {
+        vault: vaultPda,
+        administrator: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: { newMemberThreshold },
+      },
+      programId
+    );
+
+    return sendTransaction(
+      connection,
+      [updateMemberThresholdIx],
+      administrator.publicKey,
+      [administrator]
+    ).catch(sdk.errors.translateAndThrowAnchorError);
+  }
+
+  async function approve(transactionPda: PublicKey, member: Keypair) {
+    const approveIx = createApproveMemberTransactionInstruction(
+      {
+        transaction: transactionPda,
+        vault: vaultPda,
+        member: member.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [approveIx], member.publicKey, [member]);
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+    members = await generateFundedKeypairs(connection, 3);
+
+    ({ vaultPda, merkleTree, allowList } = await createTestVault(
+      connection,
+      administrator,
+      { initialFounders: [founder.publicKey] }
+    ));
+
+    await addTestMembers(
+      connection,
+      vaultPda,
+      founder,
+      members.map(m => m.publicKey)
+    );
+
+    const [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    await airdrop(connection, fundPda, LAMPORTS_PER_SOL);
+  });
+
+  it('approve member transactions on creation without member voting', async () => {
+    ({ transactionPda: autoApprovedTransactionPda } = await createTransaction(
+      members[0]
+    ));
+
+    assert.strictEqual(
+      await getStatus(autoApprovedTransactionPda),
+      VaultTransactionStatus.Approved
+    );
+  });
+
+  it('error: member threshold above the member count', async () => {
+    await assert.rejects(
+      () => updateMemberThreshold(members.length + 1),
+      /Invalid member threshold/
+    );
+  });
+
+  it('enable member voting', async () => {
+    await updateMemberThreshold(2);
+
+    const vault = await Vault.fromAccountAddress(connection, vaultPda);
+    assert.strictEqual(vault.memberThreshold, 2);
+
+    // Transactions approved without votes are no longer executable
+    const executeIx = await createTestExecuteMemberTransactionIx(
+      connection,
+      vaultPda,
+      autoApprovedTransactionPda,
+      members[0].publicKey
+    );
+
+    await assert.rejects(() =>
+      sendTransaction(connection, [executeIx], members[0].publicKey, [
+        members[0],
+      ])
+    );
+  });
+
+  it('execute a member transaction once the member threshold is reached', async () => {
+    const member = members[0];
+    const { transactionPda } = await createTransaction(member);
+    assert.strictEqual(
+      await getStatus(transactionPda),
+      VaultTransactionStatus.Active
+    );
+
+    const executeIx = await createTestExecuteMemberTransactionIx(
+      connection,
+      vaultPda,
+      transactionPda,
+      member.publicKey
+    );
+
+    await assert.rejects(() =>
+      sendTransaction(connection, [executeIx], member.publicKey, [member])
+    );
+
+    await approve(transactionPda, members[0]);
+    assert.strictEqual(
+      await getStatus(transactionPda),
+      VaultTransactionStatus.Active
+    );
+
+    await approve(transactionPda, members[1]);
+    assert.strictEqual(
+      await getStatus(transactionPda),
+      VaultTransactionStatus.Approved
+    );
+
+    await sendTransaction(connection, [executeIx], member.publicKey, [member]);
+    assert.strictEqual(
+      await getStatus(transactionPda),
+      VaultTransactionStatus.Executed
+    );
+  });
+
+  it('reject a member transaction once the threshold is unreachable', async () => {
+    const { transactionPda } = await createTransaction(members[1]);
+
+    const rejectIx = createRejectMemberTransactionInstruction(
+      {
+        transaction: transactionPda,
+        vault: vaultPda,
+        member: members[2].publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    // With 3 members and a threshold of 2, a single rejection is enough
+    await sendTransaction(connection, [rejectIx], members[2].publicKey, [
+      members[2],
+    ]);
+
+    assert.strictEqual(
+      await getStatus(transactionPda),
+      VaultTransactionStatus.Rejected
+    );
+  });
+
+  it('disable member voting', async () => {
+    await updateMemberThreshold(0);
+
+    const { transactionPda } = await createTransaction(members[2]);
+    assert.strictEqual(
+      await getStatus(transactionPda),
+      VaultTransactionStatus.Approved
+    );
+
+    await assert.rejects(
+      () =>
+        approve(transactionPda, members[1]).catch(
+          sdk.errors.translateAndThrowAnchorError
+        ),
+      /Invalid transaction status for voting/
+    );
+  });
+});
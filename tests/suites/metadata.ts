@@ -0,0 +1,194 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  generateFundedKeypairs,
+  getTestProgramId,
+  getEventCpiAccounts,
+  sendTransaction,
+  createTestVault,
+} from '../utils';
+
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  VaultMetadata,
+  VaultSetMetadataArgs,
+  createSetVaultMetadataInstruction,
+} from '../../vault-x-sdk/src/generated';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Vault metadata', () => {
+  let administrator: Keypair;
+  let founders: Keypair[];
+  let vaultPda: PublicKey;
+  let vaultMetadataPda: PublicKey;
+
+  const metadata: VaultSetMetadataArgs = {
+    name: 'Treasury',
+    description: 'Main treasury of the project',
+    imageUri: 'https://example.com/logo.png',
+    website: 'https://example.com',
+    tags: ['dao', 'treasury'],
+  };
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founders = await generateFundedKeypairs(connection, 2);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      initialFounders: founders.map(f => f.publicKey),
+      metadata,
+    }));
+    [vaultMetadataPda] = sdk.getVaultMetadataPda({ vaultPda, programId });
+  });
+
+  it('create the metadata along with the vault', async () => {
+    const vaultMetadata = await VaultMetadata.fromAccountAddress(
+      connection,
+      vaultMetadataPda
+    );
+
+    assert.strictEqual(vaultMetadata.vault.toBase58(), vaultPda.toBase58());
+    assert.strictEqual(vaultMetadata.name, metadata.name);
+    assert.strictEqual(vaultMetadata.description, metadata.description);
+    assert.strictEqual(vaultMetadata.imageUri, metadata.imageUri);
+    assert.strictEqual(vaultMetadata.website, metadata.website);
+    assert.deepEqual(vaultMetadata.tags, metadata.tags);
+  });
+
+  it('update the metadata as a founder', async () => {
+    const founder = founders[1];
+    const newMetadata: VaultSetMetadataArgs = {
+      ...metadata,
+      description: '',
+      tags: ['dao'],
+    };
+
+    const setMetadataIx = createSetVaultMetadataInstruction(
+      {
+        vault: vaultPda,
+        vaultMetadata: vaultMetadataPda,
+        founder: founder.publicKey,
+        rentPayer: founder.publicKey,
+        rentCollector: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: newMetadata,
+      },
+      programId
+    );
+
+    const rentCollectorBalance = await connection.getBalance(
+      administrator.publicKey
+    );
+    await sendTransaction(connection, [setMetadataIx], founder.publicKey, [
+      founder,
+    ]);
+
+    const vaultMetadata = await VaultMetadata.fromAccountAddress(
+      connection,
+      vaultMetadataPda
+    );
+
+    assert.strictEqual(vaultMetadata.description, '');
+    assert.deepEqual(vaultMetadata.tags, ['dao']);
+
+    // The metadata shrank, its excess rent goes to the rent collector
+    assert.ok(
+      (await connection.getBalance(administrator.publicKey)) >
+        rentCollectorBalance
+    );
+  });
+
+  it('error: refund the metadata rent to another account than the rent collector', async () => {
+    const founder = founders[0];
+
+    const setMetadataIx = createSetVaultMetadataInstruction(
+      {
+        vault: vaultPda,
+        vaultMetadata: vaultMetadataPda,
+        founder: founder.publicKey,
+        rentPayer: founder.publicKey,
+        rentCollector: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: { ...metadata, tags: [] },
+      },
+      programId
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [setMetadataIx], founder.publicKey, [
+          founder,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Invalid account provided/
+    );
+  });
+
+  it('error: update the metadata as a non founder', async () => {
+    const outsider = await generateFundedKeypair(connection);
+
+    const setMetadataIx = createSetVaultMetadataInstruction(
+      {
+        vault: vaultPda,
+        vaultMetadata: vaultMetadataPda,
+        founder: outsider.publicKey,
+        rentPayer: outsider.publicKey,
+        rentCollector: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: metadata,
+      },
+      programId
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [setMetadataIx], outsider.publicKey, [
+          outsider,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Founder does not exist/
+    );
+  });
+
+  it('error: metadata name exceeds its maximum length', async () => {
+    const founder = founders[0];
+
+    const setMetadataIx = createSetVaultMetadataInstruction(
+      {
+        vault: vaultPda,
+        vaultMetadata: vaultMetadataPda,
+        founder: founder.publicKey,
+        rentPayer: founder.publicKey,
+        rentCollector: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: { ...metadata, name: 'x'.repeat(65) },
+      },
+      programId
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [setMetadataIx], founder.publicKey, [
+          founder,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Vault metadata exceeds the allowed length/
+    );
+  });
+});
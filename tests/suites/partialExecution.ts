@@ -0,0 +1,269 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  getTestProgramId,
+  getEventCpiAccounts,
+  getVaultTransactionIndex,
+  sendTransaction,
+  createTestVault,
+  createTestExecuteFounderTransactionIx,
+  airdrop,
+} from '../utils';
+
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from '@solana/web3.js';
+import {
+  VaultFounderTransaction,
+  VaultTransactionStatus,
+  createApproveFounderTransactionInstruction,
+  createCancelFounderTransactionInstruction,
+  createCloseFounderTransactionInstruction,
+  createCreateFounderTransactionInstruction,
+  createExecuteFounderTransactionPartialInstruction,
+} from '../../vault-x-sdk/src/generated';
+import {
+  populateVaultTransactionExecuteRemainingAccounts,
+  serializeVaultTransactionMessage,
+} from '../../vault-x-sdk/src/utils';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Partial execution', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let vaultPda: PublicKey;
+  let fundPda: PublicKey;
+  let transactionPda: PublicKey;
+  const lamports = LAMPORTS_PER_SOL / 10;
+  const recipients = Array.from({ length: 4 }).map(
+    () => Keypair.generate().publicKey
+  );
+
+  async function executePartial(
+    instructionCount: number,
+    transaction = transactionPda
+  ) {
+    const { message, ephemeralSignerBumps } =
+      await VaultFounderTransaction.fromAccountAddress(connection, transaction);
+
+    // Every message account is passed, whichever instructions are executed
+    const executePartialIx = createExecuteFounderTransactionPartialInstruction(
+      {
+        founder: founder.publicKey,
+        vault: vaultPda,
+        transaction,
+        ...getEventCpiAccounts(programId),
+        anchorRemainingAccounts:
+          populateVaultTransactionExecuteRemainingAccounts(
+            message,
+            [...ephemeralSignerBumps],
+            fundPda,
+            transaction,
+            sdk.getFounderAuthorityPda({ vaultPda, programId })[0]
+          ),
+      },
+      {
+        args: { instructionCount },
+      },
+      programId
+    );
+
+    return sendTransaction(
+      connection,
+      [executePartialIx],
+      founder.publicKey,
+      [founder]
+    ).catch(sdk.errors.translateAndThrowAnchorError);
+  }
+
+  async function getBalances() {
+    return Promise.all(recipients.map(r => connection.getBalance(r)));
+  }
+
+  async function createApprovedTransaction() {
+    const message = new TransactionMessage({
+      payerKey: fundPda,
+      recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+      instructions: recipients.map(recipient =>
+        SystemProgram.transfer({
+          fromPubkey: fundPda,
+          toPubkey: recipient,
+          lamports,
+        })
+      ),
+    }).compileToV0Message();
+
+    const transactionIndex = await getVaultTransactionIndex(
+      connection,
+      vaultPda
+    );
+    const [transactionPda] = sdk.getFounderTransactionPda({
+      vaultPda,
+      index: Number(transactionIndex),
+      programId,
+    });
+
+    const createTransactionIx = createCreateFounderTransactionInstruction(
+      {
+        creator: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          ephemeralSigners: 0,
+          transactionMessage: serializeVaultTransactionMessage(message),
+          expiry: null,
+        },
+      },
+      programId
+    );
+    const approveIx = createApproveFounderTransactionInstruction(
+      {
+        founder: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [createTransactionIx, approveIx],
+      founder.publicKey,
+      [founder]
+    );
+
+    return transactionPda;
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      initialFounders: [founder.publicKey],
+    }));
+
+    [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    await airdrop(connection, fundPda, LAMPORTS_PER_SOL);
+
+    transactionPda = await createApprovedTransaction();
+  });
+
+  it('error: execute no instructions', async () => {
+    await assert.rejects(
+      () => executePartial(0),
+      /Invalid number of instructions to execute/
+    );
+  });
+
+  it('execute a transaction a few instructions at a time', async () => {
+    await executePartial(1);
+
+    let transaction = await VaultFounderTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+    assert.strictEqual(
+      transaction.status,
+      VaultTransactionStatus.ExecutingPartial
+    );
+    assert.strictEqual(transaction.executedInstructions, 1);
+    assert.deepStrictEqual(await getBalances(), [lamports, 0, 0, 0]);
+
+    // Execution resumes from the first instruction not yet executed
+    await executePartial(2);
+
+    transaction = await VaultFounderTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+    assert.strictEqual(
+      transaction.status,
+      VaultTransactionStatus.ExecutingPartial
+    );
+    assert.strictEqual(transaction.executedInstructions, 3);
+    assert.deepStrictEqual(await getBalances(), [
+      lamports,
+      lamports,
+      lamports,
+      0,
+    ]);
+  });
+
+  it('finish a partially executed transaction with a regular execute', async () => {
+    const executeIx = await createTestExecuteFounderTransactionIx(
+      connection,
+      vaultPda,
+      transactionPda,
+      founder.publicKey
+    );
+
+    await sendTransaction(connection, [executeIx], founder.publicKey, [
+      founder,
+    ]);
+
+    const transaction = await VaultFounderTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+    assert.strictEqual(transaction.status, VaultTransactionStatus.Executed);
+    assert.strictEqual(transaction.executedInstructions, recipients.length);
+    assert.deepStrictEqual(await getBalances(), recipients.map(() => lamports));
+  });
+
+  it('abandon a partially executed transaction', async () => {
+    const abandonedTransactionPda = await createApprovedTransaction();
+    await executePartial(1, abandonedTransactionPda);
+
+    // Cancelling keeps the executed instructions and lets it be closed
+    const cancelIx = createCancelFounderTransactionInstruction(
+      {
+        founder: founder.publicKey,
+        vault: vaultPda,
+        transaction: abandonedTransactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+    const closeIx = createCloseFounderTransactionInstruction(
+      {
+        transaction: abandonedTransactionPda,
+        vault: vaultPda,
+        creator: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [cancelIx, closeIx],
+      founder.publicKey,
+      [founder]
+    );
+
+    assert.strictEqual(
+      await connection.getAccountInfo(abandonedTransactionPda),
+      null
+    );
+  });
+});
@@ -0,0 +1,164 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  getTestProgramId,
+  getEventCpiAccounts,
+  sendTransaction,
+  createTestVault,
+  createTestMemberTransaction,
+  createTestExecuteMemberTransactionIx,
+  addTestMembers,
+  airdrop,
+  sleep,
+} from '../utils';
+
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import {
+  VaultMemberRateLimit,
+  createSetMemberRateLimitInstruction,
+} from '../../vault-x-sdk/src/generated';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+const WINDOW_SECONDS = 5;
+
+describe('Instructions / Member rate limit', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let member: Keypair;
+  let vaultPda: PublicKey;
+  let rateLimitPda: PublicKey;
+  let merkleTree: Keypair;
+  let allowList: PublicKey[];
+
+  function createTransaction() {
+    return createTestMemberTransaction(
+      connection,
+      vaultPda,
+      member,
+      merkleTree.publicKey,
+      allowList,
+      { lamports: LAMPORTS_PER_SOL / 100 }
+    ).catch(sdk.errors.translateAndThrowAnchorError);
+  }
+
+  function setRateLimit(
+    signer: Keypair,
+    maxTransactions: number,
+    windowSeconds: number
+  ) {
+    const setMemberRateLimitIx = createSetMemberRateLimitInstruction(
+      {
+        vault: vaultPda,
+        rateLimit: rateLimitPda,
+        founder: signer.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          member: member.publicKey,
+          maxTransactions,
+          windowSeconds,
+        },
+      },
+      programId
+    );
+
+    return sendTransaction(
+      connection,
+      [setMemberRateLimitIx],
+      signer.publicKey,
+      [signer]
+    ).catch(sdk.errors.translateAndThrowAnchorError);
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+    member = await generateFundedKeypair(connection);
+
+    ({ vaultPda, merkleTree, allowList } = await createTestVault(
+      connection,
+      administrator,
+      { initialFounders: [founder.publicKey] }
+    ));
+
+    await addTestMembers(connection, vaultPda, founder, [member.publicKey]);
+
+    [rateLimitPda] = sdk.getMemberRateLimitPda({
+      vaultPda,
+      member: member.publicKey,
+      programId,
+    });
+
+    const [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    await airdrop(connection, fundPda, LAMPORTS_PER_SOL);
+  });
+
+  it('error: rate limit without a window', async () => {
+    await assert.rejects(
+      () => setRateLimit(founder, 2, 0),
+      /Invalid rate limit/
+    );
+  });
+
+  it('error: set a rate limit as a non founder', async () => {
+    await assert.rejects(
+      () => setRateLimit(member, 2, WINDOW_SECONDS),
+      /Founder does not exist/
+    );
+  });
+
+  it('limit member transactions per window', async () => {
+    await setRateLimit(founder, 2, WINDOW_SECONDS);
+
+    // Creating and executing a transaction both count against the limit
+    const { transactionPda } = await createTransaction();
+    const executeIx = await createTestExecuteMemberTransactionIx(
+      connection,
+      vaultPda,
+      transactionPda,
+      member.publicKey
+    );
+    await sendTransaction(connection, [executeIx], member.publicKey, [member]);
+
+    const rateLimit = await VaultMemberRateLimit.fromAccountAddress(
+      connection,
+      rateLimitPda
+    );
+    assert.strictEqual(rateLimit.transactionCount, 2);
+
+    await assert.rejects(
+      () => createTransaction(),
+      /Member has reached its transaction rate limit/
+    );
+
+    // A new window resets the count
+    await sleep((WINDOW_SECONDS + 1) * 1000);
+    await createTransaction();
+  });
+
+  it('lift the rate limit', async () => {
+    await setRateLimit(founder, 0, 0);
+
+    for (let i = 0; i < 3; i++) {
+      await createTransaction();
+    }
+
+    const rateLimit = await VaultMemberRateLimit.fromAccountAddress(
+      connection,
+      rateLimitPda
+    );
+    assert.strictEqual(rateLimit.maxTransactions, 0);
+  });
+});
@@ -0,0 +1,239 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  generateFundedKeypairs,
+  getTestProgramId,
+  getEventCpiAccounts,
+  sendTransaction,
+  createTestVault,
+  sleep,
+} from '../utils';
+
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  VaultRecovery,
+  createApproveRecoveryInstruction,
+  createCancelRecoveryInstruction,
+  createExecuteRecoveryInstruction,
+  createProposeRecoveryInstruction,
+  createSetVaultRecoveryInstruction,
+} from '../../vault-x-sdk/src/generated';
+
+const { Vault } = sdk.accounts;
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Vault recovery', () => {
+  const inactivityPeriodSeconds = 5;
+  const timeLockSeconds = 2;
+
+  let administrator: Keypair;
+  let founder: Keypair;
+  let guardians: Keypair[];
+  let vaultPda: PublicKey;
+  let vaultRecoveryPda: PublicKey;
+  const newFounder = Keypair.generate().publicKey;
+
+  function propose(guardian: Keypair) {
+    const proposeRecoveryIx = createProposeRecoveryInstruction(
+      {
+        vault: vaultPda,
+        vaultRecovery: vaultRecoveryPda,
+        guardian: guardian.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          newFounders: [newFounder],
+          newFounderWeights: null,
+          newFounderThreshold: 1,
+        },
+      },
+      programId
+    );
+
+    return sendTransaction(
+      connection,
+      [proposeRecoveryIx],
+      guardian.publicKey,
+      [guardian]
+    ).catch(sdk.errors.translateAndThrowAnchorError);
+  }
+
+  function approve(guardian: Keypair) {
+    const approveRecoveryIx = createApproveRecoveryInstruction(
+      {
+        vault: vaultPda,
+        vaultRecovery: vaultRecoveryPda,
+        guardian: guardian.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    return sendTransaction(
+      connection,
+      [approveRecoveryIx],
+      guardian.publicKey,
+      [guardian]
+    ).catch(sdk.errors.translateAndThrowAnchorError);
+  }
+
+  function execute(guardian: Keypair, proposer: PublicKey) {
+    const executeRecoveryIx = createExecuteRecoveryInstruction(
+      {
+        vault: vaultPda,
+        vaultRecovery: vaultRecoveryPda,
+        guardian: guardian.publicKey,
+        proposer,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    return sendTransaction(
+      connection,
+      [executeRecoveryIx],
+      guardian.publicKey,
+      [guardian]
+    ).catch(sdk.errors.translateAndThrowAnchorError);
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+    guardians = await generateFundedKeypairs(connection, 3);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      initialFounders: [founder.publicKey],
+    }));
+
+    [vaultRecoveryPda] = sdk.getRecoveryPda({ vaultPda, programId });
+  });
+
+  it('register the recovery guardians', async () => {
+    const setVaultRecoveryIx = createSetVaultRecoveryInstruction(
+      {
+        vault: vaultPda,
+        vaultRecovery: vaultRecoveryPda,
+        administrator: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          guardians: guardians.map(g => g.publicKey),
+          guardianThreshold: 2,
+          inactivityPeriodSeconds,
+          timeLockSeconds,
+        },
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [setVaultRecoveryIx],
+      administrator.publicKey,
+      [administrator]
+    );
+
+    const vaultRecovery = await VaultRecovery.fromAccountAddress(
+      connection,
+      vaultRecoveryPda
+    );
+    assert.strictEqual(vaultRecovery.guardians.length, 3);
+    assert.strictEqual(vaultRecovery.guardianThreshold, 2);
+    assert.strictEqual(vaultRecovery.proposal, null);
+  });
+
+  it('error: propose a recovery while the founders are active', async () => {
+    await assert.rejects(
+      () => propose(guardians[0]),
+      /Vault founders have not been inactive for long enough/
+    );
+  });
+
+  it('error: propose a recovery as a non guardian', async () => {
+    const outsider = await generateFundedKeypair(connection);
+
+    await assert.rejects(() => propose(outsider), /Guardian does not exist/);
+  });
+
+  it('cancel a recovery as a founder', async () => {
+    await sleep((inactivityPeriodSeconds + 1) * 1000);
+    await propose(guardians[0]);
+
+    const cancelRecoveryIx = createCancelRecoveryInstruction(
+      {
+        vault: vaultPda,
+        vaultRecovery: vaultRecoveryPda,
+        founder: founder.publicKey,
+        proposer: guardians[0].publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [cancelRecoveryIx], founder.publicKey, [
+      founder,
+    ]);
+
+    const vaultRecovery = await VaultRecovery.fromAccountAddress(
+      connection,
+      vaultRecoveryPda
+    );
+    assert.strictEqual(vaultRecovery.proposal, null);
+
+    // Cancelling counts as founder activity
+    await assert.rejects(
+      () => propose(guardians[0]),
+      /Vault founders have not been inactive for long enough/
+    );
+  });
+
+  it('recover the vault once the guardian threshold and time lock are reached', async () => {
+    await sleep((inactivityPeriodSeconds + 1) * 1000);
+    await propose(guardians[0]);
+
+    await assert.rejects(
+      () => execute(guardians[0], guardians[0].publicKey),
+      /Recovery has not reached the guardian threshold/
+    );
+
+    await approve(guardians[1]);
+
+    await assert.rejects(
+      () => approve(guardians[1]),
+      /Guardian has already approved the recovery/
+    );
+
+    await assert.rejects(
+      () => execute(guardians[1], guardians[0].publicKey),
+      /Time lock has not elapsed since the transaction was approved/
+    );
+
+    await sleep((timeLockSeconds + 1) * 1000);
+    await execute(guardians[1], guardians[0].publicKey);
+
+    const vault = await Vault.fromAccountAddress(connection, vaultPda);
+    assert.deepEqual(vault.founders, [newFounder]);
+    assert.deepEqual(vault.founderWeights, [1]);
+    assert.strictEqual(vault.founderThreshold, 1);
+
+    const vaultRecovery = await VaultRecovery.fromAccountAddress(
+      connection,
+      vaultRecoveryPda
+    );
+    assert.strictEqual(vaultRecovery.proposal, null);
+  });
+});
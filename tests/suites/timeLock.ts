@@ -0,0 +1,191 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  getTestProgramId,
+  getEventCpiAccounts,
+  sendTransaction,
+  createTestVault,
+  createTestFounderTransaction,
+  createTestExecuteFounderTransactionIx,
+  airdrop,
+  sleep,
+} from '../utils';
+
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import {
+  VaultFounderTransaction,
+  VaultTransactionStatus,
+  createApproveFounderTransactionInstruction,
+  createUpdateTimeLockInstruction,
+} from '../../vault-x-sdk/src/generated';
+import { toBigInt } from '../../vault-x-sdk/src/utils';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+const TIME_LOCK_SECONDS = 3;
+
+describe('Instructions / Time lock', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let vaultPda: PublicKey;
+
+  async function createApprovedTransaction() {
+    const { transactionPda } = await createTestFounderTransaction(
+      connection,
+      vaultPda,
+      founder,
+      { lamports: LAMPORTS_PER_SOL / 10 }
+    );
+
+    const approveIx = createApproveFounderTransactionInstruction(
+      {
+        founder: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [approveIx], founder.publicKey, [
+      founder,
+    ]);
+
+    return transactionPda;
+  }
+
+  async function updateTimeLock(newTimeLockSeconds: number) {
+    const updateTimeLockIx = createUpdateTimeLockInstruction(
+      {
+        vault: vaultPda,
+        administrator: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: { newTimeLockSeconds },
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [updateTimeLockIx],
+      administrator.publicKey,
+      [administrator]
+    );
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      initialFounders: [founder.publicKey],
+      timeLockSeconds: TIME_LOCK_SECONDS,
+    }));
+
+    const [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    await airdrop(connection, fundPda, LAMPORTS_PER_SOL);
+  });
+
+  it('execute an approved transaction only once the time lock elapsed', async () => {
+    const transactionPda = await createApprovedTransaction();
+
+    const { status, approvedAt, executableAt } =
+      await VaultFounderTransaction.fromAccountAddress(
+        connection,
+        transactionPda
+      );
+    assert.strictEqual(status, VaultTransactionStatus.Approved);
+    assert.strictEqual(
+      toBigInt(executableAt) - toBigInt(approvedAt),
+      BigInt(TIME_LOCK_SECONDS)
+    );
+
+    const executeIx = await createTestExecuteFounderTransactionIx(
+      connection,
+      vaultPda,
+      transactionPda,
+      founder.publicKey
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [executeIx], founder.publicKey, [
+          founder,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Time lock has not elapsed since the transaction was approved/
+    );
+
+    await sleep((TIME_LOCK_SECONDS + 2) * 1000);
+
+    await sendTransaction(connection, [executeIx], founder.publicKey, [
+      founder,
+    ]);
+
+    const transaction = await VaultFounderTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+    assert.strictEqual(transaction.status, VaultTransactionStatus.Executed);
+  });
+
+  it('keep the time lock of approved transactions when it is lowered', async () => {
+    const transactionPda = await createApprovedTransaction();
+
+    await updateTimeLock(0);
+
+    const vault = await sdk.accounts.Vault.fromAccountAddress(
+      connection,
+      vaultPda
+    );
+    assert.strictEqual(vault.timeLockSeconds, 0);
+
+    const executeIx = await createTestExecuteFounderTransactionIx(
+      connection,
+      vaultPda,
+      transactionPda,
+      founder.publicKey
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [executeIx], founder.publicKey, [
+          founder,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Time lock has not elapsed since the transaction was approved/
+    );
+  });
+
+  it('error: update the time lock as a non administrator', async () => {
+    const updateTimeLockIx = createUpdateTimeLockInstruction(
+      {
+        vault: vaultPda,
+        administrator: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: { newTimeLockSeconds: 0 },
+      },
+      programId
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [updateTimeLockIx], founder.publicKey, [
+          founder,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Unauthorized action attempted/
+    );
+  });
+});
@@ -0,0 +1,297 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+import { createHash } from 'crypto';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  getTestProgramId,
+  getEventCpiAccounts,
+  getVaultTransactionIndex,
+  sendTransaction,
+  createTestVault,
+  createTestExecuteFounderTransactionIx,
+  createTransferMessage,
+  airdrop,
+  fetchEvents,
+} from '../utils';
+
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import {
+  createApproveFounderTransactionInstruction,
+  createCloseTransactionBufferInstruction,
+  createCreateFounderTransactionInstruction,
+  createCreateTransactionBufferInstruction,
+  createExtendTransactionBufferInstruction,
+} from '../../vault-x-sdk/src/generated';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Transaction buffer', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let vaultPda: PublicKey;
+  let fundPda: PublicKey;
+
+  function getTransactionBufferPda(bufferIndex: number) {
+    return sdk.getTransactionBufferPda({
+      vaultPda,
+      creator: founder.publicKey,
+      bufferIndex,
+      programId,
+    })[0];
+  }
+
+  function createCreateBufferIx(
+    bufferIndex: number,
+    finalBuffer: Uint8Array,
+    buffer: Uint8Array,
+    finalBufferHash = createHash('sha256').update(finalBuffer).digest()
+  ) {
+    return createCreateTransactionBufferInstruction(
+      {
+        transactionBuffer: getTransactionBufferPda(bufferIndex),
+        vault: vaultPda,
+        creator: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          bufferIndex,
+          finalBufferHash: Array.from(finalBufferHash),
+          finalBufferSize: finalBuffer.length,
+          buffer,
+        },
+      },
+      programId
+    );
+  }
+
+  function createExtendBufferIx(bufferIndex: number, buffer: Uint8Array) {
+    return createExtendTransactionBufferInstruction(
+      {
+        transactionBuffer: getTransactionBufferPda(bufferIndex),
+        vault: vaultPda,
+        creator: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: { buffer },
+      },
+      programId
+    );
+  }
+
+  async function createTransactionFromBuffer(bufferIndex: number) {
+    const transactionIndex = await getVaultTransactionIndex(
+      connection,
+      vaultPda
+    );
+    const [transactionPda] = sdk.getFounderTransactionPda({
+      vaultPda,
+      index: Number(transactionIndex),
+      programId,
+    });
+
+    const createTransactionIx = createCreateFounderTransactionInstruction(
+      {
+        creator: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        transactionBuffer: getTransactionBufferPda(bufferIndex),
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          ephemeralSigners: 0,
+          // The message is read from the buffer
+          transactionMessage: new Uint8Array(),
+          expiry: null,
+        },
+      },
+      programId
+    );
+
+    const signature = await sendTransaction(
+      connection,
+      [createTransactionIx],
+      founder.publicKey,
+      [founder]
+    ).catch(sdk.errors.translateAndThrowAnchorError);
+
+    return {
+      transactionPda,
+      transactionIndex: Number(transactionIndex),
+      signature,
+    };
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      initialFounders: [founder.publicKey],
+    }));
+
+    [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    await airdrop(connection, fundPda, LAMPORTS_PER_SOL);
+  });
+
+  it('create a founder transaction from a buffer uploaded in chunks', async () => {
+    const lamports = LAMPORTS_PER_SOL / 10;
+    const recipient = Keypair.generate().publicKey;
+    const bufferIndex = 0;
+
+    const { transactionMessage } = await createTransferMessage(
+      connection,
+      fundPda,
+      recipient,
+      lamports
+    );
+    const chunkSize = Math.ceil(transactionMessage.length / 2);
+
+    await sendTransaction(
+      connection,
+      [
+        createCreateBufferIx(
+          bufferIndex,
+          transactionMessage,
+          transactionMessage.slice(0, chunkSize)
+        ),
+      ],
+      founder.publicKey,
+      [founder]
+    );
+    await sendTransaction(
+      connection,
+      [createExtendBufferIx(bufferIndex, transactionMessage.slice(chunkSize))],
+      founder.publicKey,
+      [founder]
+    );
+
+    const { transactionPda, transactionIndex, signature } =
+      await createTransactionFromBuffer(bufferIndex);
+
+    // The buffer is closed once used, reported with the new transaction index
+    assert.strictEqual(
+      await connection.getAccountInfo(getTransactionBufferPda(bufferIndex)),
+      null
+    );
+    const events = await fetchEvents(connection, signature);
+    const bufferClosed = events.find(
+      e => e.name === 'TransactionBufferClosedEvent'
+    );
+    assert.ok(bufferClosed);
+    assert.strictEqual(bufferClosed.data.transactionIndex, transactionIndex);
+
+    const approveIx = createApproveFounderTransactionInstruction(
+      {
+        founder: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+    const executeIx = await createTestExecuteFounderTransactionIx(
+      connection,
+      vaultPda,
+      transactionPda,
+      founder.publicKey
+    );
+
+    await sendTransaction(
+      connection,
+      [approveIx, executeIx],
+      founder.publicKey,
+      [founder]
+    );
+
+    assert.strictEqual(await connection.getBalance(recipient), lamports);
+  });
+
+  it('error: extend a buffer beyond its final size', async () => {
+    const bufferIndex = 1;
+    const finalBuffer = new Uint8Array(8);
+
+    await sendTransaction(
+      connection,
+      [createCreateBufferIx(bufferIndex, finalBuffer, finalBuffer)],
+      founder.publicKey,
+      [founder]
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(
+          connection,
+          [createExtendBufferIx(bufferIndex, new Uint8Array(1))],
+          founder.publicKey,
+          [founder]
+        ).catch(sdk.errors.translateAndThrowAnchorError),
+      /Invalid transaction buffer size/
+    );
+  });
+
+  it('error: create a transaction from a buffer not matching its hash', async () => {
+    const bufferIndex = 2;
+    const { transactionMessage } = await createTransferMessage(
+      connection,
+      fundPda,
+      founder.publicKey,
+      LAMPORTS_PER_SOL / 10
+    );
+
+    await sendTransaction(
+      connection,
+      [
+        createCreateBufferIx(
+          bufferIndex,
+          transactionMessage,
+          transactionMessage,
+          Buffer.alloc(32)
+        ),
+      ],
+      founder.publicKey,
+      [founder]
+    );
+
+    await assert.rejects(
+      () => createTransactionFromBuffer(bufferIndex),
+      /Transaction buffer does not match its hash/
+    );
+  });
+
+  it('close a buffer without creating a transaction', async () => {
+    const bufferIndex = 2;
+    const closeTransactionBufferIx = createCloseTransactionBufferInstruction(
+      {
+        transactionBuffer: getTransactionBufferPda(bufferIndex),
+        vault: vaultPda,
+        creator: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [closeTransactionBufferIx],
+      founder.publicKey,
+      [founder]
+    );
+
+    assert.strictEqual(
+      await connection.getAccountInfo(getTransactionBufferPda(bufferIndex)),
+      null
+    );
+  });
+});
@@ -0,0 +1,187 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  getTestProgramId,
+  createTestVault,
+  createTestFounderTransaction,
+  createTestMemberTransaction,
+  addTestMembers,
+} from '../utils';
+
+import {
+  AccountMeta,
+  Keypair,
+  PublicKey,
+  TransactionMessage,
+  VersionedTransaction,
+} from '@solana/web3.js';
+import {
+  VaultFounderTransaction,
+  VaultMemberTransaction,
+  createValidateTransactionInstruction,
+  vaultTransactionValidationReportBeet,
+} from '../../vault-x-sdk/src/generated';
+import { populateVaultTransactionExecuteRemainingAccounts } from '../../vault-x-sdk/src/utils';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Validate transaction', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let member: Keypair;
+  let vaultPda: PublicKey;
+  let fundPda: PublicKey;
+  let merkleTree: Keypair;
+  let allowList: PublicKey[];
+
+  // The instruction is meant to be simulated, its report is the return data
+  async function simulateValidate(
+    transactionPda: PublicKey,
+    anchorRemainingAccounts: AccountMeta[]
+  ) {
+    const validateIx = createValidateTransactionInstruction(
+      {
+        vault: vaultPda,
+        transaction: transactionPda,
+        anchorRemainingAccounts,
+      },
+      programId
+    );
+
+    const message = new TransactionMessage({
+      payerKey: founder.publicKey,
+      recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+      instructions: [validateIx],
+    }).compileToV0Message();
+
+    const { value } = await connection.simulateTransaction(
+      new VersionedTransaction(message),
+      { sigVerify: false }
+    );
+    assert.strictEqual(value.err, null);
+    assert.ok(value.returnData);
+    assert.strictEqual(value.returnData.programId, programId.toBase58());
+
+    const [report] = vaultTransactionValidationReportBeet.deserialize(
+      Buffer.from(value.returnData.data[0], 'base64')
+    );
+    return report;
+  }
+
+  async function getFounderRemainingAccounts(transactionPda: PublicKey) {
+    const { message, ephemeralSignerBumps } =
+      await VaultFounderTransaction.fromAccountAddress(
+        connection,
+        transactionPda
+      );
+
+    return populateVaultTransactionExecuteRemainingAccounts(
+      message,
+      [...ephemeralSignerBumps],
+      fundPda,
+      transactionPda,
+      sdk.getFounderAuthorityPda({ vaultPda, programId })[0]
+    );
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+    member = await generateFundedKeypair(connection);
+
+    ({ vaultPda, merkleTree, allowList } = await createTestVault(
+      connection,
+      administrator,
+      { initialFounders: [founder.publicKey] }
+    ));
+
+    await addTestMembers(connection, vaultPda, founder, [member.publicKey]);
+
+    [fundPda] = sdk.getFundPda({ vaultPda, programId });
+  });
+
+  it('validate a founder transaction', async () => {
+    const { transactionPda, transactionIndex } =
+      await createTestFounderTransaction(connection, vaultPda, founder);
+    const remainingAccounts = await getFounderRemainingAccounts(transactionPda);
+
+    const report = await simulateValidate(transactionPda, remainingAccounts);
+
+    assert.strictEqual(report.transactionIndex, Number(transactionIndex));
+    assert.strictEqual(report.numInstructions, 1);
+    assert.strictEqual(report.numAccountInfos, remainingAccounts.length);
+    assert.strictEqual(report.errorCode, null);
+  });
+
+  it('validate a member transaction', async () => {
+    const { transactionPda } = await createTestMemberTransaction(
+      connection,
+      vaultPda,
+      member,
+      merkleTree.publicKey,
+      allowList
+    );
+    const { message, ephemeralSignerBumps } =
+      await VaultMemberTransaction.fromAccountAddress(
+        connection,
+        transactionPda
+      );
+
+    const report = await simulateValidate(
+      transactionPda,
+      populateVaultTransactionExecuteRemainingAccounts(
+        message,
+        [...ephemeralSignerBumps],
+        fundPda,
+        transactionPda
+      )
+    );
+
+    assert.strictEqual(report.errorCode, null);
+  });
+
+  it('report mis-ordered accounts without failing', async () => {
+    const { transactionPda } = await createTestFounderTransaction(
+      connection,
+      vaultPda,
+      founder
+    );
+    const [first, second, ...rest] =
+      await getFounderRemainingAccounts(transactionPda);
+
+    const report = await simulateValidate(transactionPda, [
+      second,
+      first,
+      ...rest,
+    ]);
+
+    assert.notStrictEqual(report.errorCode, null);
+  });
+
+  it('report missing accounts without failing', async () => {
+    const { transactionPda } = await createTestFounderTransaction(
+      connection,
+      vaultPda,
+      founder
+    );
+    const remainingAccounts = await getFounderRemainingAccounts(transactionPda);
+
+    const report = await simulateValidate(
+      transactionPda,
+      remainingAccounts.slice(0, -1)
+    );
+
+    assert.strictEqual(report.numAccountInfos, remainingAccounts.length - 1);
+    assert.notStrictEqual(report.errorCode, null);
+  });
+});
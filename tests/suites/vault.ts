@@ -11,6 +11,7 @@ import {
   generateFundedKeypairs,
   generateFundedKeypair,
   getTestProgramId,
+  getEventCpiAccounts,
   createComputeLimitAndFeeIx,
   createAndAddLeavesToMerkleTreeIx,
   sendTransaction,
@@ -70,11 +71,15 @@ describe('Instructions / Vault', () => {
         vaultConfig: vaultConfigPda,
         merkleTree: merkleTree.publicKey,
         treasury: programTreasury,
+        ...getEventCpiAccounts(programId),
       },
       {
         args: {
           founderThreshold: 2,
+          timeLockSeconds: 0,
           initialFounders: founders.map(f => f.publicKey),
+          initialFounderWeights: null,
+          metadata: null,
         },
       }
     );
@@ -116,11 +121,15 @@ describe('Instructions / Vault', () => {
         vaultConfig: vaultConfigPda,
         merkleTree: merkleTree.publicKey,
         treasury: programTreasury,
+        ...getEventCpiAccounts(programId),
       },
       {
         args: {
           founderThreshold: 2,
+          timeLockSeconds: 0,
           initialFounders: [],
+          initialFounderWeights: null,
+          metadata: null,
         },
       }
     );
@@ -164,11 +173,15 @@ describe('Instructions / Vault', () => {
         vaultConfig: vaultConfigPda,
         merkleTree: merkleTree.publicKey,
         treasury: programTreasury,
+        ...getEventCpiAccounts(programId),
       },
       {
         args: {
           founderThreshold: 0,
+          timeLockSeconds: 0,
           initialFounders: founders.map(f => f.publicKey),
+          initialFounderWeights: null,
+          metadata: null,
         },
       }
     );
@@ -211,11 +224,15 @@ describe('Instructions / Vault', () => {
         vaultConfig: vaultConfigPda,
         merkleTree: merkleTree.publicKey,
         treasury: programTreasury,
+        ...getEventCpiAccounts(programId),
       },
       {
         args: {
           founderThreshold: 5,
+          timeLockSeconds: 0,
           initialFounders: founders.map(f => f.publicKey),
+          initialFounderWeights: null,
+          metadata: null,
         },
       }
     );
@@ -258,11 +275,15 @@ describe('Instructions / Vault', () => {
         vaultConfig: vaultConfigPda,
         merkleTree: merkleTree.publicKey,
         treasury: programTreasury,
+        ...getEventCpiAccounts(programId),
       },
       {
         args: {
           founderThreshold: 2,
+          timeLockSeconds: 0,
           initialFounders: founders.map(f => f.publicKey),
+          initialFounderWeights: null,
+          metadata: null,
         },
       }
     );
@@ -288,6 +309,7 @@ describe('Instructions / Vault', () => {
         .map(f => f.publicKey)
         .sort((a, b) => a.toBuffer().compare(b.toBuffer()))
     );
+    assert.deepEqual(vaultAccount.founderWeights, [1, 1, 1, 1]);
     assert.strictEqual(vaultAccount.transactionIndex.toString(), '0');
     assert.strictEqual(vaultAccount.staleTransactionIndex.toString(), '0');
     assert.strictEqual(
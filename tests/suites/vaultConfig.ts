@@ -11,6 +11,7 @@ import {
   getTestVaultConfigAuthority,
   getTestVaultConfigInitializer,
   getTestProgramId,
+  getEventCpiAccounts,
   getTestProgramTreasury,
 } from '../utils';
 
@@ -57,6 +58,7 @@ describe('Instructions / VaultConfig', () => {
         initializer: fakeInitializer.publicKey,
         authority: vaultConfigAuthority.publicKey,
         treasury: programTreasury,
+        ...getEventCpiAccounts(programId),
       },
       {
         creationFee: 0,
@@ -89,6 +91,7 @@ describe('Instructions / VaultConfig', () => {
         initializer: vaultConfigInitializer.publicKey,
         authority: PublicKey.default,
         treasury: programTreasury,
+        ...getEventCpiAccounts(programId),
       },
       {
         creationFee: 0,
@@ -121,6 +124,7 @@ describe('Instructions / VaultConfig', () => {
         initializer: vaultConfigInitializer.publicKey,
         authority: vaultConfigAuthority.publicKey,
         treasury: PublicKey.default,
+        ...getEventCpiAccounts(programId),
       },
       {
         creationFee: 0,
@@ -153,6 +157,7 @@ describe('Instructions / VaultConfig', () => {
         initializer: vaultConfigInitializer.publicKey,
         authority: vaultConfigAuthority.publicKey,
         treasury: programTreasury,
+        ...getEventCpiAccounts(programId),
       },
       {
         creationFee: 0.1 * LAMPORTS_PER_SOL,
@@ -203,6 +208,7 @@ describe('Instructions / VaultConfig', () => {
         initializer: vaultConfigInitializer.publicKey,
         authority: vaultConfigAuthority.publicKey,
         treasury: programTreasury,
+        ...getEventCpiAccounts(programId),
       },
       {
         creationFee: 0,
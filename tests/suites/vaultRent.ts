@@ -0,0 +1,196 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  getTestProgramId,
+  getEventCpiAccounts,
+  sendTransaction,
+  createTestVault,
+  addTestMembers,
+} from '../utils';
+
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  createCompactVaultInstruction,
+  createRemoveMemberInstruction,
+  createRemoveMembersInstruction,
+  createUpdateRentCollectorInstruction,
+} from '../../vault-x-sdk/src/generated';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Vault rent', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let vaultPda: PublicKey;
+  const members = Array.from({ length: 4 }).map(
+    () => Keypair.generate().publicKey
+  );
+
+  async function getVaultSize() {
+    const vaultInfo = await connection.getAccountInfo(vaultPda);
+    return vaultInfo!.data.length;
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      initialFounders: [founder.publicKey],
+    }));
+
+    await addTestMembers(connection, vaultPda, founder, members);
+  });
+
+  it('keep the freed space without a rent collector', async () => {
+    const vaultSize = await getVaultSize();
+
+    const removeMembersIx = createRemoveMembersInstruction(
+      {
+        vault: vaultPda,
+        founder: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          members: members.slice(0, 2),
+        },
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [removeMembersIx], founder.publicKey, [
+      founder,
+    ]);
+
+    assert.strictEqual(await getVaultSize(), vaultSize);
+  });
+
+  it('compact the vault and refund its rent collector', async () => {
+    const vaultSize = await getVaultSize();
+    const vaultBalance = await connection.getBalance(vaultPda);
+    const collectorBalance = await connection.getBalance(
+      administrator.publicKey
+    );
+
+    // Compacting is permissionless
+    const payer = await generateFundedKeypair(connection);
+    const compactIx = createCompactVaultInstruction(
+      {
+        vault: vaultPda,
+        rentCollector: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [compactIx], payer.publicKey, [payer]);
+
+    const newVaultSize = await getVaultSize();
+    assert.strictEqual(newVaultSize, vaultSize - 2 * 32);
+
+    const refund =
+      vaultBalance -
+      (await connection.getMinimumBalanceForRentExemption(newVaultSize));
+    assert.strictEqual(
+      await connection.getBalance(vaultPda),
+      vaultBalance - refund
+    );
+    assert.strictEqual(
+      await connection.getBalance(administrator.publicKey),
+      collectorBalance + refund
+    );
+  });
+
+  it('shrink the vault when removing a member with the rent collector', async () => {
+    const vaultSize = await getVaultSize();
+
+    const removeMemberIx = createRemoveMemberInstruction(
+      {
+        vault: vaultPda,
+        founder: founder.publicKey,
+        rentCollector: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          member: members[2],
+        },
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [removeMemberIx], founder.publicKey, [
+      founder,
+    ]);
+
+    assert.strictEqual(await getVaultSize(), vaultSize - 32);
+  });
+
+  it('error: refund a rent collector other than the vault one', async () => {
+    const removeMemberIx = createRemoveMemberInstruction(
+      {
+        vault: vaultPda,
+        founder: founder.publicKey,
+        rentCollector: founder.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          member: members[3],
+        },
+      },
+      programId
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(connection, [removeMemberIx], founder.publicKey, [
+          founder,
+        ]).catch(sdk.errors.translateAndThrowAnchorError),
+      /Invalid account provided/
+    );
+  });
+
+  it('update the rent collector', async () => {
+    const updateRentCollectorIx = createUpdateRentCollectorInstruction(
+      {
+        vault: vaultPda,
+        administrator: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          newRentCollector: founder.publicKey,
+        },
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [updateRentCollectorIx],
+      administrator.publicKey,
+      [administrator]
+    );
+
+    const vault = await sdk.accounts.Vault.fromAccountAddress(
+      connection,
+      vaultPda
+    );
+    assert.strictEqual(
+      vault.rentCollector.toBase58(),
+      founder.publicKey.toBase58()
+    );
+  });
+});
@@ -0,0 +1,316 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  generateFundedKeypairs,
+  getTestProgramId,
+  getEventCpiAccounts,
+  getVaultTransactionIndex,
+  sendTransaction,
+  createTestVault,
+  createTestFounderTransaction,
+  createTestExecuteFounderTransactionIx,
+  airdrop,
+  sleep,
+} from '../utils';
+
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  TransactionMessage,
+} from '@solana/web3.js';
+import {
+  VaultFounderTransaction,
+  VaultTransactionStatus,
+  VaultVetoCouncil,
+  createApproveFounderTransactionInstruction,
+  createCreateFounderTransactionInstruction,
+  createSetVetoCouncilInstruction,
+  createUpdateTimeLockInstruction,
+  createVetoFounderTransactionInstruction,
+} from '../../vault-x-sdk/src/generated';
+import { serializeVaultTransactionMessage } from '../../vault-x-sdk/src/utils';
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+const TIME_LOCK_SECONDS = 3;
+const GUARDIAN_THRESHOLD = 2;
+
+describe('Instructions / Veto council', () => {
+  let administrator: Keypair;
+  let founder: Keypair;
+  let guardians: Keypair[];
+  let vaultPda: PublicKey;
+  let fundPda: PublicKey;
+  let vetoCouncilPda: PublicKey;
+
+  async function approve(transactionPda: PublicKey) {
+    const approveIx = createApproveFounderTransactionInstruction(
+      {
+        founder: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [approveIx], founder.publicKey, [
+      founder,
+    ]);
+  }
+
+  // The first guardian signs as `guardian`, the others co-sign the veto
+  function veto(
+    transactionPda: PublicKey,
+    [guardian, ...coSigners]: Keypair[]
+  ) {
+    const vetoIx = createVetoFounderTransactionInstruction(
+      {
+        transaction: transactionPda,
+        vault: vaultPda,
+        vetoCouncil: vetoCouncilPda,
+        guardian: guardian.publicKey,
+        ...getEventCpiAccounts(programId),
+        anchorRemainingAccounts: coSigners.map(coSigner => ({
+          pubkey: coSigner.publicKey,
+          isSigner: true,
+          isWritable: false,
+        })),
+      },
+      programId
+    );
+
+    return sendTransaction(connection, [vetoIx], guardian.publicKey, [
+      guardian,
+      ...coSigners,
+    ]).catch(sdk.errors.translateAndThrowAnchorError);
+  }
+
+  // Creates and approves a founder transaction setting the council
+  async function createSetVetoCouncilTransaction() {
+    // Only the founder authority can set the council, so founders vote on it
+    const [founderAuthority] = sdk.getFounderAuthorityPda({
+      vaultPda,
+      programId,
+    });
+    const setVetoCouncilIx = createSetVetoCouncilInstruction(
+      {
+        vault: vaultPda,
+        vetoCouncil: vetoCouncilPda,
+        founderAuthority,
+        rentPayer: fundPda,
+        rentCollector: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          guardians: guardians.map(g => g.publicKey),
+          guardianThreshold: GUARDIAN_THRESHOLD,
+        },
+      },
+      programId
+    );
+
+    const message = new TransactionMessage({
+      payerKey: fundPda,
+      recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+      instructions: [setVetoCouncilIx],
+    }).compileToV0Message();
+
+    const transactionIndex = await getVaultTransactionIndex(
+      connection,
+      vaultPda
+    );
+    const [transactionPda] = sdk.getFounderTransactionPda({
+      vaultPda,
+      index: Number(transactionIndex),
+      programId,
+    });
+
+    const createTransactionIx = createCreateFounderTransactionInstruction(
+      {
+        creator: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          ephemeralSigners: 0,
+          transactionMessage: serializeVaultTransactionMessage(message),
+          expiry: null,
+        },
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [createTransactionIx],
+      founder.publicKey,
+      [founder]
+    );
+    await approve(transactionPda);
+
+    return transactionPda;
+  }
+
+  async function createApprovedTransaction() {
+    const { transactionPda } = await createTestFounderTransaction(
+      connection,
+      vaultPda,
+      founder,
+      { lamports: LAMPORTS_PER_SOL / 10 }
+    );
+    await approve(transactionPda);
+
+    return transactionPda;
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founder = await generateFundedKeypair(connection);
+    guardians = await generateFundedKeypairs(connection, 3);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      initialFounders: [founder.publicKey],
+    }));
+
+    [fundPda] = sdk.getFundPda({ vaultPda, programId });
+    [vetoCouncilPda] = sdk.getVetoCouncilPda({ vaultPda, programId });
+    await airdrop(connection, fundPda, LAMPORTS_PER_SOL);
+  });
+
+  it('set the veto council through a founder transaction', async () => {
+    const transactionPda = await createSetVetoCouncilTransaction();
+
+    const executeIx = await createTestExecuteFounderTransactionIx(
+      connection,
+      vaultPda,
+      transactionPda,
+      founder.publicKey
+    );
+
+    await sendTransaction(connection, [executeIx], founder.publicKey, [
+      founder,
+    ]);
+
+    const vetoCouncil = await VaultVetoCouncil.fromAccountAddress(
+      connection,
+      vetoCouncilPda
+    );
+    assert.deepEqual(
+      vetoCouncil.guardians,
+      guardians
+        .map(g => g.publicKey)
+        .sort((a, b) => a.toBuffer().compare(b.toBuffer()))
+    );
+    assert.strictEqual(vetoCouncil.guardianThreshold, GUARDIAN_THRESHOLD);
+
+    const updateTimeLockIx = createUpdateTimeLockInstruction(
+      {
+        vault: vaultPda,
+        administrator: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: { newTimeLockSeconds: TIME_LOCK_SECONDS },
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [updateTimeLockIx],
+      administrator.publicKey,
+      [administrator]
+    );
+  });
+
+  it('error: veto as a non guardian', async () => {
+    const transactionPda = await createApprovedTransaction();
+
+    await assert.rejects(
+      () => veto(transactionPda, [founder, guardians[0]]),
+      /Guardian does not exist/
+    );
+  });
+
+  it('error: veto an active transaction', async () => {
+    const { transactionPda } = await createTestFounderTransaction(
+      connection,
+      vaultPda,
+      founder
+    );
+
+    await assert.rejects(
+      () => veto(transactionPda, guardians.slice(0, GUARDIAN_THRESHOLD)),
+      /Invalid transaction status for voting/
+    );
+  });
+
+  it('error: veto with fewer guardians than the threshold', async () => {
+    const transactionPda = await createApprovedTransaction();
+
+    await assert.rejects(
+      () => veto(transactionPda, [guardians[0]]),
+      /Not enough guardians signed the veto/
+    );
+  });
+
+  it('error: veto a transaction replacing the veto council', async () => {
+    const transactionPda = await createSetVetoCouncilTransaction();
+
+    await assert.rejects(
+      () => veto(transactionPda, guardians),
+      /Transactions replacing the veto council can't be vetoed/
+    );
+  });
+
+  it('veto an approved transaction within its time lock', async () => {
+    const transactionPda = await createApprovedTransaction();
+
+    await veto(transactionPda, [guardians[0], guardians[1]]);
+
+    const transaction = await VaultFounderTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+    assert.strictEqual(transaction.status, VaultTransactionStatus.Vetoed);
+
+    await sleep((TIME_LOCK_SECONDS + 2) * 1000);
+
+    const executeIx = await createTestExecuteFounderTransactionIx(
+      connection,
+      vaultPda,
+      transactionPda,
+      founder.publicKey
+    );
+
+    await assert.rejects(() =>
+      sendTransaction(connection, [executeIx], founder.publicKey, [founder])
+    );
+  });
+
+  it('error: veto once the time lock elapsed', async () => {
+    const transactionPda = await createApprovedTransaction();
+
+    await sleep((TIME_LOCK_SECONDS + 2) * 1000);
+
+    await assert.rejects(
+      () => veto(transactionPda, [guardians[1], guardians[2]]),
+      /Time lock has elapsed, the transaction can no longer be vetoed/
+    );
+  });
+});
@@ -0,0 +1,230 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+import * as sdk from '../../vault-x-sdk/src';
+import assert from 'assert';
+
+import {
+  createLocalhostConnection,
+  generateFundedKeypair,
+  generateFundedKeypairs,
+  getTestProgramId,
+  getEventCpiAccounts,
+  sendTransaction,
+  createTestVault,
+  createTestFounderTransaction,
+} from '../utils';
+
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  VaultFounderTransaction,
+  VaultTransactionStatus,
+  createApproveFounderTransactionInstruction,
+  createCloseLegacyTransactionInstruction,
+  createMigrateVaultInstruction,
+  createUpdateFounderWeightInstruction,
+} from '../../vault-x-sdk/src/generated';
+
+const { Vault } = sdk.accounts;
+
+const connection = createLocalhostConnection();
+const programId = getTestProgramId();
+
+describe('Instructions / Weighted founder voting', () => {
+  let administrator: Keypair;
+  let founders: Keypair[];
+  let vaultPda: PublicKey;
+
+  async function getFounderWeight(founder: PublicKey) {
+    const vault = await Vault.fromAccountAddress(connection, vaultPda);
+    const founderIndex = vault.founders.findIndex(f => f.equals(founder));
+    return vault.founderWeights[founderIndex];
+  }
+
+  async function approve(transactionPda: PublicKey, founder: Keypair) {
+    const approveIx = createApproveFounderTransactionInstruction(
+      {
+        founder: founder.publicKey,
+        vault: vaultPda,
+        transaction: transactionPda,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await sendTransaction(connection, [approveIx], founder.publicKey, [
+      founder,
+    ]);
+  }
+
+  before(async () => {
+    administrator = await generateFundedKeypair(connection);
+    founders = await generateFundedKeypairs(connection, 3);
+
+    ({ vaultPda } = await createTestVault(connection, administrator, {
+      founderThreshold: 3,
+      initialFounders: founders.map(f => f.publicKey),
+      initialFounderWeights: [3, 1, 1],
+    }));
+  });
+
+  it('keep each weight with its founder', async () => {
+    assert.strictEqual(await getFounderWeight(founders[0].publicKey), 3);
+    assert.strictEqual(await getFounderWeight(founders[1].publicKey), 1);
+    assert.strictEqual(await getFounderWeight(founders[2].publicKey), 1);
+  });
+
+  it('error: threshold exceeding the total founder weight', async () => {
+    await assert.rejects(
+      () =>
+        createTestVault(connection, administrator, {
+          founderThreshold: 6,
+          initialFounders: founders.map(f => f.publicKey),
+          initialFounderWeights: [3, 1, 1],
+        }).catch(sdk.errors.translateAndThrowAnchorError),
+      /Invalid founder threshold/
+    );
+  });
+
+  it('approve once the approving weight reaches the threshold', async () => {
+    const { transactionPda } = await createTestFounderTransaction(
+      connection,
+      vaultPda,
+      founders[1]
+    );
+
+    // Two light founders only carry a weight of 2
+    await approve(transactionPda, founders[1]);
+    await approve(transactionPda, founders[2]);
+
+    let transaction = await VaultFounderTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+    assert.strictEqual(transaction.status, VaultTransactionStatus.Active);
+
+    await approve(transactionPda, founders[0]);
+
+    transaction = await VaultFounderTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+    assert.strictEqual(transaction.status, VaultTransactionStatus.Approved);
+  });
+
+  it('error: update a founder weight to 0', async () => {
+    const updateWeightIx = createUpdateFounderWeightInstruction(
+      {
+        vault: vaultPda,
+        administrator: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          founder: founders[1].publicKey,
+          newWeight: 0,
+          newFounderThreshold: null,
+        },
+      },
+      programId
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(
+          connection,
+          [updateWeightIx],
+          administrator.publicKey,
+          [administrator]
+        ).catch(sdk.errors.translateAndThrowAnchorError),
+      /Invalid founder vote weight/
+    );
+  });
+
+  it('update a founder weight and the threshold', async () => {
+    const updateWeightIx = createUpdateFounderWeightInstruction(
+      {
+        vault: vaultPda,
+        administrator: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      {
+        args: {
+          founder: founders[1].publicKey,
+          newWeight: 2,
+          newFounderThreshold: 4,
+        },
+      },
+      programId
+    );
+
+    await sendTransaction(
+      connection,
+      [updateWeightIx],
+      administrator.publicKey,
+      [administrator]
+    );
+
+    const vault = await Vault.fromAccountAddress(connection, vaultPda);
+    assert.strictEqual(vault.founderThreshold, 4);
+    assert.strictEqual(await getFounderWeight(founders[1].publicKey), 2);
+    assert.strictEqual(
+      vault.staleTransactionIndex.toString(),
+      vault.transactionIndex.toString()
+    );
+  });
+
+  it('error: migrate a vault already using the current layout', async () => {
+    const migrateVaultIx = createMigrateVaultInstruction(
+      {
+        vault: vaultPda,
+        administrator: administrator.publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(
+          connection,
+          [migrateVaultIx],
+          administrator.publicKey,
+          [administrator]
+        ).catch(sdk.errors.translateAndThrowAnchorError),
+      /Vault already uses the current layout/
+    );
+  });
+
+  it('error: close a transaction created after the migration as legacy', async () => {
+    const { transactionPda } = await createTestFounderTransaction(
+      connection,
+      vaultPda,
+      founders[0]
+    );
+
+    const closeLegacyTransactionIx = createCloseLegacyTransactionInstruction(
+      {
+        transaction: transactionPda,
+        vault: vaultPda,
+        creator: founders[0].publicKey,
+        ...getEventCpiAccounts(programId),
+      },
+      programId
+    );
+
+    await assert.rejects(
+      () =>
+        sendTransaction(
+          connection,
+          [closeLegacyTransactionIx],
+          founders[0].publicKey,
+          [founders[0]]
+        ).catch(sdk.errors.translateAndThrowAnchorError),
+      /Transaction does not use the legacy layout/
+    );
+  });
+});
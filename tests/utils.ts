@@ -5,6 +5,8 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 import {
+  AddressLookupTableAccount,
+  AddressLookupTableProgram,
   Connection,
   Keypair,
   LAMPORTS_PER_SOL,
@@ -15,12 +17,16 @@ import {
   Signer,
   AccountMeta,
   ComputeBudgetProgram,
+  SystemProgram,
 } from '@solana/web3.js';
 
 import testProgramKeypair from '../test-keypairs/test-program-keypair.json';
 import testVaultConfigInitializerKeypair from '../test-keypairs/test-vault-config-initializer-keypair.json';
 import testVaultConfigAuthorityKeypair from '../test-keypairs/test-vault-config-authority-keypair.json';
 import testProgramTreasuryKeypair from '../test-keypairs/test-program-treasury-keypair.json';
+import idl from '../vault-x-sdk/idl/vaultx.json';
+import { BorshEventCoder, Idl } from '@coral-xyz/anchor';
+import { bs58 } from '@coral-xyz/anchor/dist/cjs/utils/bytes';
 import {
   ConcurrentMerkleTreeAccount,
   MerkleTree,
@@ -28,9 +34,38 @@ import {
   createAllocTreeIx,
   createAppendIx,
   createInitEmptyMerkleTreeIx,
+  SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
 } from '@solana/spl-account-compression';
-import { Vault } from '../vault-x-sdk/src/accounts';
-import { toBigInt } from '../vault-x-sdk/src/utils';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Vault, VaultConfig } from '../vault-x-sdk/src/accounts';
+import {
+  populateVaultTransactionExecuteRemainingAccounts,
+  serializeVaultTransactionMessage,
+  toBigInt,
+} from '../vault-x-sdk/src/utils';
+import {
+  getEventAuthorityPda,
+  getFounderAuthorityPda,
+  getFounderTransactionPda,
+  getFundPda,
+  getMemberRateLimitPda,
+  getMemberTransactionPda,
+  getVaultConfigPda,
+  getVaultMetadataPda,
+  getVaultPda,
+} from '../vault-x-sdk/src/pda';
+import {
+  CreateVaultArgs,
+  VaultFounderTransaction,
+  VaultMemberTransaction,
+  VaultTransactionExpiry,
+  createCreateFounderTransactionInstruction,
+  createCreateMemberTransactionInstruction,
+  createCreateVaultInstruction,
+  createAddMembersInstruction,
+  createExecuteFounderTransactionInstruction,
+  createExecuteMemberTransactionInstruction,
+} from '../vault-x-sdk/src/generated';
 
 export function createLocalhostConnection() {
   return new Connection('http://127.0.0.1:8899', 'confirmed');
@@ -41,6 +76,11 @@ export function getTestProgramId() {
   return programKeypair.publicKey;
 }
 
+export function getEventCpiAccounts(programId: PublicKey) {
+  const [eventAuthority] = getEventAuthorityPda({ programId });
+  return { eventAuthority, program: programId };
+}
+
 export function getTestVaultConfigInitializer() {
   return Keypair.fromSecretKey(Buffer.from(testVaultConfigInitializerKeypair));
 }
@@ -83,6 +123,34 @@ export async function sendTransaction(
   return signature;
 }
 
+const eventCoder = new BorshEventCoder(idl as Idl);
+
+/**
+ * Decodes the events a transaction emitted, through self CPIs to the program
+ */
+export async function fetchEvents(connection: Connection, signature: string) {
+  const programId = getTestProgramId();
+  const transaction = await connection.getTransaction(signature, {
+    commitment: 'confirmed',
+    maxSupportedTransactionVersion: 0,
+  });
+  if (!transaction?.meta?.innerInstructions) {
+    return [];
+  }
+
+  const { staticAccountKeys } = transaction.transaction.message;
+  return transaction.meta.innerInstructions
+    .flatMap(({ instructions }) => instructions)
+    .filter(({ programIdIndex }) =>
+      staticAccountKeys[programIdIndex].equals(programId)
+    )
+    .map(({ data }) =>
+      // The event is prefixed with the tag of the event instruction
+      eventCoder.decode(Buffer.from(bs58.decode(data)).toString('base64', 8))
+    )
+    .filter(event => event !== null);
+}
+
 export async function generateFundedKeypair(
   connection: Connection,
   solAmount?: number
@@ -232,3 +300,371 @@ export async function fetchProof(
     root: Array.from(proof.root),
   };
 }
+
+export async function airdrop(
+  connection: Connection,
+  address: PublicKey,
+  lamports: number
+) {
+  const signature = await connection.requestAirdrop(address, lamports);
+
+  await connection.confirmTransaction({
+    ...(await connection.getLatestBlockhash()),
+    signature,
+  });
+}
+
+export async function createTestVault(
+  connection: Connection,
+  administrator: Keypair,
+  args: Partial<CreateVaultArgs> &
+    Pick<CreateVaultArgs, 'initialFounders'>
+) {
+  const programId = getTestProgramId();
+  const createKey = Keypair.generate();
+  const merkleTree = Keypair.generate();
+  const allowList = [TOKEN_PROGRAM_ID, SPL_ACCOUNT_COMPRESSION_PROGRAM_ID];
+
+  const [vaultConfigPda] = getVaultConfigPda({ programId });
+  const { treasury } = await VaultConfig.fromAccountAddress(
+    connection,
+    vaultConfigPda
+  );
+
+  const [vaultPda] = getVaultPda({
+    createKey: createKey.publicKey,
+    programId,
+  });
+
+  const merkleTreeIxs = await createAndAddLeavesToMerkleTreeIx(
+    connection,
+    administrator.publicKey,
+    merkleTree,
+    allowList
+  );
+
+  const createVaultIx = createCreateVaultInstruction(
+    {
+      administrator: administrator.publicKey,
+      createKey: createKey.publicKey,
+      vault: vaultPda,
+      vaultConfig: vaultConfigPda,
+      merkleTree: merkleTree.publicKey,
+      treasury,
+      // The metadata account is only required when metadata is provided
+      vaultMetadata: args.metadata
+        ? getVaultMetadataPda({ vaultPda, programId })[0]
+        : undefined,
+      ...getEventCpiAccounts(programId),
+    },
+    {
+      args: {
+        founderThreshold: 1,
+        timeLockSeconds: 0,
+        initialFounderWeights: null,
+        metadata: null,
+        ...args,
+      },
+    },
+    programId
+  );
+
+  await sendTransaction(
+    connection,
+    [...createComputeLimitAndFeeIx(), ...merkleTreeIxs, createVaultIx],
+    administrator.publicKey,
+    [administrator, merkleTree, createKey]
+  );
+
+  return { vaultPda, createKey, merkleTree, allowList };
+}
+
+export async function createTransferMessage(
+  connection: Connection,
+  fundPda: PublicKey,
+  destination: PublicKey,
+  lamports: number
+) {
+  const message = new TransactionMessage({
+    payerKey: fundPda,
+    recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+    instructions: [
+      SystemProgram.transfer({
+        fromPubkey: fundPda,
+        toPubkey: destination,
+        lamports,
+      }),
+    ],
+  }).compileToV0Message();
+
+  return {
+    message,
+    transactionMessage: serializeVaultTransactionMessage(message),
+  };
+}
+
+export async function createTestLookupTable(
+  connection: Connection,
+  authority: Keypair,
+  addresses: PublicKey[]
+) {
+  const [createLookupTableIx, lookupTablePda] =
+    AddressLookupTableProgram.createLookupTable({
+      authority: authority.publicKey,
+      payer: authority.publicKey,
+      recentSlot: await connection.getSlot('finalized'),
+    });
+  const extendLookupTableIx = AddressLookupTableProgram.extendLookupTable({
+    lookupTable: lookupTablePda,
+    authority: authority.publicKey,
+    payer: authority.publicKey,
+    addresses,
+  });
+
+  await sendTransaction(
+    connection,
+    [createLookupTableIx, extendLookupTableIx],
+    authority.publicKey,
+    [authority]
+  );
+
+  // Extended lookup tables can only be used from the next slot
+  await sleep(1000);
+
+  const { value } = await connection.getAddressLookupTable(lookupTablePda);
+  return value as AddressLookupTableAccount;
+}
+
+export async function createTestFounderTransaction(
+  connection: Connection,
+  vaultPda: PublicKey,
+  creator: Keypair,
+  {
+    lamports = 0,
+    expiry = null,
+  }: { lamports?: number; expiry?: VaultTransactionExpiry | null } = {}
+) {
+  const programId = getTestProgramId();
+  const transactionIndex = await getVaultTransactionIndex(connection, vaultPda);
+
+  const [transactionPda] = getFounderTransactionPda({
+    vaultPda,
+    index: Number(transactionIndex),
+    programId,
+  });
+  const [fundPda] = getFundPda({ vaultPda, programId });
+
+  const { transactionMessage } = await createTransferMessage(
+    connection,
+    fundPda,
+    creator.publicKey,
+    lamports
+  );
+
+  const createFounderTransactionIx = createCreateFounderTransactionInstruction(
+    {
+      creator: creator.publicKey,
+      vault: vaultPda,
+      transaction: transactionPda,
+      ...getEventCpiAccounts(programId),
+    },
+    {
+      args: {
+        ephemeralSigners: 0,
+        transactionMessage,
+        expiry,
+      },
+    },
+    programId
+  );
+
+  await sendTransaction(
+    connection,
+    [createFounderTransactionIx],
+    creator.publicKey,
+    [creator]
+  );
+
+  return { transactionPda, transactionIndex };
+}
+
+export async function createTestExecuteFounderTransactionIx(
+  connection: Connection,
+  vaultPda: PublicKey,
+  transactionPda: PublicKey,
+  founder: PublicKey
+) {
+  const programId = getTestProgramId();
+  const [fundPda] = getFundPda({ vaultPda, programId });
+
+  const { message, ephemeralSignerBumps } =
+    await VaultFounderTransaction.fromAccountAddress(
+      connection,
+      transactionPda
+    );
+
+  return createExecuteFounderTransactionInstruction(
+    {
+      founder,
+      vault: vaultPda,
+      transaction: transactionPda,
+      ...getEventCpiAccounts(programId),
+      anchorRemainingAccounts: populateVaultTransactionExecuteRemainingAccounts(
+        message,
+        [...ephemeralSignerBumps],
+        fundPda,
+        transactionPda,
+        getFounderAuthorityPda({ vaultPda, programId })[0]
+      ),
+    },
+    programId
+  );
+}
+
+export async function addTestMembers(
+  connection: Connection,
+  vaultPda: PublicKey,
+  founder: Keypair,
+  members: PublicKey[]
+) {
+  const programId = getTestProgramId();
+
+  const addMembersIx = createAddMembersInstruction(
+    {
+      vault: vaultPda,
+      founder: founder.publicKey,
+      rentPayer: founder.publicKey,
+      systemProgram: SystemProgram.programId,
+      ...getEventCpiAccounts(programId),
+    },
+    {
+      args: {
+        newMembers: members,
+      },
+    },
+    programId
+  );
+
+  await sendTransaction(connection, [addMembersIx], founder.publicKey, [
+    founder,
+  ]);
+}
+
+export async function createTestMemberTransaction(
+  connection: Connection,
+  vaultPda: PublicKey,
+  creator: Keypair,
+  merkleTree: PublicKey,
+  allowList: PublicKey[],
+  {
+    lamports = 0,
+    maxExecutions = null,
+    cooldownSeconds = null,
+    transactionMessage,
+  }: {
+    lamports?: number;
+    maxExecutions?: number | null;
+    cooldownSeconds?: number | null;
+    transactionMessage?: Uint8Array;
+  } = {}
+) {
+  const programId = getTestProgramId();
+  const transactionIndex = await getVaultTransactionIndex(connection, vaultPda);
+
+  const [transactionPda] = getMemberTransactionPda({
+    vaultPda,
+    index: Number(transactionIndex),
+    programId,
+  });
+  const [fundPda] = getFundPda({ vaultPda, programId });
+  const [rateLimit] = getMemberRateLimitPda({
+    vaultPda,
+    member: creator.publicKey,
+    programId,
+  });
+
+  transactionMessage ??= (
+    await createTransferMessage(
+      connection,
+      fundPda,
+      creator.publicKey,
+      lamports
+    )
+  ).transactionMessage;
+
+  const { leaf, index, root, remainingAccounts } = await fetchProof(
+    connection,
+    merkleTree,
+    new MerkleTree(allowList.map(a => a.toBuffer())),
+    0
+  );
+
+  const createMemberTransactionIx = createCreateMemberTransactionInstruction(
+    {
+      accountCompressionProgram: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+      creator: creator.publicKey,
+      vault: vaultPda,
+      rateLimit,
+      merkleTree,
+      transaction: transactionPda,
+      ...getEventCpiAccounts(programId),
+      anchorRemainingAccounts: remainingAccounts,
+    },
+    {
+      args: {
+        allowListLeaf: leaf,
+        allowListLeafIndex: index,
+        allowListRoot: root,
+        ephemeralSigners: 0,
+        transactionMessage,
+        maxExecutions,
+        cooldownSeconds,
+      },
+    },
+    programId
+  );
+
+  await sendTransaction(
+    connection,
+    [createMemberTransactionIx],
+    creator.publicKey,
+    [creator]
+  );
+
+  return { transactionPda, transactionIndex };
+}
+
+export async function createTestExecuteMemberTransactionIx(
+  connection: Connection,
+  vaultPda: PublicKey,
+  transactionPda: PublicKey,
+  member: PublicKey
+) {
+  const programId = getTestProgramId();
+  const [fundPda] = getFundPda({ vaultPda, programId });
+  const [rateLimit] = getMemberRateLimitPda({ vaultPda, member, programId });
+
+  const { message, ephemeralSignerBumps } =
+    await VaultMemberTransaction.fromAccountAddress(connection, transactionPda);
+
+  return createExecuteMemberTransactionInstruction(
+    {
+      member,
+      vault: vaultPda,
+      rateLimit,
+      transaction: transactionPda,
+      ...getEventCpiAccounts(programId),
+      anchorRemainingAccounts: populateVaultTransactionExecuteRemainingAccounts(
+        message,
+        [...ephemeralSignerBumps],
+        fundPda,
+        transactionPda
+      ),
+    },
+    programId
+  );
+}
+
+export function sleep(ms: number) {
+  return new Promise(resolve => setTimeout(resolve, ms));
+}
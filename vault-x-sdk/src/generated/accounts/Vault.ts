@@ -6,8 +6,8 @@
  */
 
 import * as web3 from '@solana/web3.js'
-import * as beetSolana from '@metaplex-foundation/beet-solana'
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
 
 /**
  * Arguments used to create {@link Vault}
@@ -24,6 +24,16 @@ export type VaultArgs = {
   staleTransactionIndex: number
   founderThreshold: number
   transactionIndex: number
+  version: number
+  founderWeights: number[]
+  committeeIndex: number
+  timeLockSeconds: number
+  maxExpirySeconds: number
+  maxExpirySlots: beet.bignum
+  rentCollector: web3.PublicKey
+  lastActivityAt: beet.bignum
+  memberThreshold: number
+  legacyTransactionIndex: number
 }
 
 export const vaultDiscriminator = [211, 8, 232, 43, 2, 152, 117, 119]
@@ -44,7 +54,17 @@ export class Vault implements VaultArgs {
     readonly members: web3.PublicKey[],
     readonly staleTransactionIndex: number,
     readonly founderThreshold: number,
-    readonly transactionIndex: number
+    readonly transactionIndex: number,
+    readonly version: number,
+    readonly founderWeights: number[],
+    readonly committeeIndex: number,
+    readonly timeLockSeconds: number,
+    readonly maxExpirySeconds: number,
+    readonly maxExpirySlots: beet.bignum,
+    readonly rentCollector: web3.PublicKey,
+    readonly lastActivityAt: beet.bignum,
+    readonly memberThreshold: number,
+    readonly legacyTransactionIndex: number
   ) {}
 
   /**
@@ -60,7 +80,17 @@ export class Vault implements VaultArgs {
       args.members,
       args.staleTransactionIndex,
       args.founderThreshold,
-      args.transactionIndex
+      args.transactionIndex,
+      args.version,
+      args.founderWeights,
+      args.committeeIndex,
+      args.timeLockSeconds,
+      args.maxExpirySeconds,
+      args.maxExpirySlots,
+      args.rentCollector,
+      args.lastActivityAt,
+      args.memberThreshold,
+      args.legacyTransactionIndex
     )
   }
 
@@ -178,6 +208,36 @@ export class Vault implements VaultArgs {
       staleTransactionIndex: this.staleTransactionIndex,
       founderThreshold: this.founderThreshold,
       transactionIndex: this.transactionIndex,
+      version: this.version,
+      founderWeights: this.founderWeights,
+      committeeIndex: this.committeeIndex,
+      timeLockSeconds: this.timeLockSeconds,
+      maxExpirySeconds: this.maxExpirySeconds,
+      maxExpirySlots: (() => {
+        const x = <{ toNumber: () => number }>this.maxExpirySlots
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      rentCollector: this.rentCollector.toBase58(),
+      lastActivityAt: (() => {
+        const x = <{ toNumber: () => number }>this.lastActivityAt
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      memberThreshold: this.memberThreshold,
+      legacyTransactionIndex: this.legacyTransactionIndex,
     }
   }
 }
@@ -203,6 +263,16 @@ export const vaultBeet = new beet.FixableBeetStruct<
     ['staleTransactionIndex', beet.u32],
     ['founderThreshold', beet.u16],
     ['transactionIndex', beet.u32],
+    ['version', beet.u8],
+    ['founderWeights', beet.array(beet.u16)],
+    ['committeeIndex', beet.u32],
+    ['timeLockSeconds', beet.u32],
+    ['maxExpirySeconds', beet.u32],
+    ['maxExpirySlots', beet.u64],
+    ['rentCollector', beetSolana.publicKey],
+    ['lastActivityAt', beet.i64],
+    ['memberThreshold', beet.u16],
+    ['legacyTransactionIndex', beet.u32],
   ],
   Vault.fromArgs,
   'Vault'
@@ -0,0 +1,258 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as web3 from '@solana/web3.js'
+import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+import {
+  VaultBatchStatus,
+  vaultBatchStatusBeet,
+} from '../types/VaultBatchStatus'
+
+/**
+ * Arguments used to create {@link VaultBatch}
+ * @category Accounts
+ * @category generated
+ */
+export type VaultBatchArgs = {
+  creator: web3.PublicKey
+  vault: web3.PublicKey
+  transactionIndex: number
+  status: VaultBatchStatus
+  bump: number
+  fundBump: number
+  size: number
+  executedIndex: number
+  closedCount: number
+  approved: web3.PublicKey[]
+  rejected: web3.PublicKey[]
+  cancelled: web3.PublicKey[]
+  approvedAt: beet.bignum
+  executableAt: beet.bignum
+}
+
+export const vaultBatchDiscriminator = [109, 218, 225, 68, 36, 160, 200, 165]
+/**
+ * Holds the data for the {@link VaultBatch} Account and provides de/serialization
+ * functionality for that data
+ *
+ * @category Accounts
+ * @category generated
+ */
+export class VaultBatch implements VaultBatchArgs {
+  private constructor(
+    readonly creator: web3.PublicKey,
+    readonly vault: web3.PublicKey,
+    readonly transactionIndex: number,
+    readonly status: VaultBatchStatus,
+    readonly bump: number,
+    readonly fundBump: number,
+    readonly size: number,
+    readonly executedIndex: number,
+    readonly closedCount: number,
+    readonly approved: web3.PublicKey[],
+    readonly rejected: web3.PublicKey[],
+    readonly cancelled: web3.PublicKey[],
+    readonly approvedAt: beet.bignum,
+    readonly executableAt: beet.bignum
+  ) {}
+
+  /**
+   * Creates a {@link VaultBatch} instance from the provided args.
+   */
+  static fromArgs(args: VaultBatchArgs) {
+    return new VaultBatch(
+      args.creator,
+      args.vault,
+      args.transactionIndex,
+      args.status,
+      args.bump,
+      args.fundBump,
+      args.size,
+      args.executedIndex,
+      args.closedCount,
+      args.approved,
+      args.rejected,
+      args.cancelled,
+      args.approvedAt,
+      args.executableAt
+    )
+  }
+
+  /**
+   * Deserializes the {@link VaultBatch} from the data of the provided {@link web3.AccountInfo}.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static fromAccountInfo(
+    accountInfo: web3.AccountInfo<Buffer>,
+    offset = 0
+  ): [VaultBatch, number] {
+    return VaultBatch.deserialize(accountInfo.data, offset)
+  }
+
+  /**
+   * Retrieves the account info from the provided address and deserializes
+   * the {@link VaultBatch} from its data.
+   *
+   * @throws Error if no account info is found at the address or if deserialization fails
+   */
+  static async fromAccountAddress(
+    connection: web3.Connection,
+    address: web3.PublicKey,
+    commitmentOrConfig?: web3.Commitment | web3.GetAccountInfoConfig
+  ): Promise<VaultBatch> {
+    const accountInfo = await connection.getAccountInfo(
+      address,
+      commitmentOrConfig
+    )
+    if (accountInfo == null) {
+      throw new Error(`Unable to find VaultBatch account at ${address}`)
+    }
+    return VaultBatch.fromAccountInfo(accountInfo, 0)[0]
+  }
+
+  /**
+   * Provides a {@link web3.Connection.getProgramAccounts} config builder,
+   * to fetch accounts matching filters that can be specified via that builder.
+   *
+   * @param programId - the program that owns the accounts we are filtering
+   */
+  static gpaBuilder(
+    programId: web3.PublicKey = new web3.PublicKey(
+      'GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A'
+    )
+  ) {
+    return beetSolana.GpaBuilder.fromStruct(programId, vaultBatchBeet)
+  }
+
+  /**
+   * Deserializes the {@link VaultBatch} from the provided data Buffer.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static deserialize(buf: Buffer, offset = 0): [VaultBatch, number] {
+    return vaultBatchBeet.deserialize(buf, offset)
+  }
+
+  /**
+   * Serializes the {@link VaultBatch} into a Buffer.
+   * @returns a tuple of the created Buffer and the offset up to which the buffer was written to store it.
+   */
+  serialize(): [Buffer, number] {
+    return vaultBatchBeet.serialize({
+      accountDiscriminator: vaultBatchDiscriminator,
+      ...this,
+    })
+  }
+
+  /**
+   * Returns the byteSize of a {@link Buffer} holding the serialized data of
+   * {@link VaultBatch} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
+   */
+  static byteSize(args: VaultBatchArgs) {
+    const instance = VaultBatch.fromArgs(args)
+    return vaultBatchBeet.toFixedFromValue({
+      accountDiscriminator: vaultBatchDiscriminator,
+      ...instance,
+    }).byteSize
+  }
+
+  /**
+   * Fetches the minimum balance needed to exempt an account holding
+   * {@link VaultBatch} data from rent
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
+   * @param connection used to retrieve the rent exemption information
+   */
+  static async getMinimumBalanceForRentExemption(
+    args: VaultBatchArgs,
+    connection: web3.Connection,
+    commitment?: web3.Commitment
+  ): Promise<number> {
+    return connection.getMinimumBalanceForRentExemption(
+      VaultBatch.byteSize(args),
+      commitment
+    )
+  }
+
+  /**
+   * Returns a readable version of {@link VaultBatch} properties
+   * and can be used to convert to JSON and/or logging
+   */
+  pretty() {
+    return {
+      creator: this.creator.toBase58(),
+      vault: this.vault.toBase58(),
+      transactionIndex: this.transactionIndex,
+      status: 'VaultBatchStatus.' + VaultBatchStatus[this.status],
+      bump: this.bump,
+      fundBump: this.fundBump,
+      size: this.size,
+      executedIndex: this.executedIndex,
+      closedCount: this.closedCount,
+      approved: this.approved,
+      rejected: this.rejected,
+      cancelled: this.cancelled,
+      approvedAt: (() => {
+        const x = <{ toNumber: () => number }>this.approvedAt
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      executableAt: (() => {
+        const x = <{ toNumber: () => number }>this.executableAt
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+    }
+  }
+}
+
+/**
+ * @category Accounts
+ * @category generated
+ */
+export const vaultBatchBeet = new beet.FixableBeetStruct<
+  VaultBatch,
+  VaultBatchArgs & {
+    accountDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['creator', beetSolana.publicKey],
+    ['vault', beetSolana.publicKey],
+    ['transactionIndex', beet.u32],
+    ['status', vaultBatchStatusBeet],
+    ['bump', beet.u8],
+    ['fundBump', beet.u8],
+    ['size', beet.u32],
+    ['executedIndex', beet.u32],
+    ['closedCount', beet.u32],
+    ['approved', beet.array(beetSolana.publicKey)],
+    ['rejected', beet.array(beetSolana.publicKey)],
+    ['cancelled', beet.array(beetSolana.publicKey)],
+    ['approvedAt', beet.i64],
+    ['executableAt', beet.i64],
+  ],
+  VaultBatch.fromArgs,
+  'VaultBatch'
+)
@@ -0,0 +1,204 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as web3 from '@solana/web3.js'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+import * as beet from '@metaplex-foundation/beet'
+
+/**
+ * Arguments used to create {@link VaultCommittee}
+ * @category Accounts
+ * @category generated
+ */
+export type VaultCommitteeArgs = {
+  vault: web3.PublicKey
+  creator: web3.PublicKey
+  committeeIndex: number
+  bump: number
+  fundBump: number
+  members: web3.PublicKey[]
+  threshold: number
+  transactionIndex: number
+}
+
+export const vaultCommitteeDiscriminator = [8, 103, 157, 121, 161, 24, 144, 4]
+/**
+ * Holds the data for the {@link VaultCommittee} Account and provides de/serialization
+ * functionality for that data
+ *
+ * @category Accounts
+ * @category generated
+ */
+export class VaultCommittee implements VaultCommitteeArgs {
+  private constructor(
+    readonly vault: web3.PublicKey,
+    readonly creator: web3.PublicKey,
+    readonly committeeIndex: number,
+    readonly bump: number,
+    readonly fundBump: number,
+    readonly members: web3.PublicKey[],
+    readonly threshold: number,
+    readonly transactionIndex: number
+  ) {}
+
+  /**
+   * Creates a {@link VaultCommittee} instance from the provided args.
+   */
+  static fromArgs(args: VaultCommitteeArgs) {
+    return new VaultCommittee(
+      args.vault,
+      args.creator,
+      args.committeeIndex,
+      args.bump,
+      args.fundBump,
+      args.members,
+      args.threshold,
+      args.transactionIndex
+    )
+  }
+
+  /**
+   * Deserializes the {@link VaultCommittee} from the data of the provided {@link web3.AccountInfo}.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static fromAccountInfo(
+    accountInfo: web3.AccountInfo<Buffer>,
+    offset = 0
+  ): [VaultCommittee, number] {
+    return VaultCommittee.deserialize(accountInfo.data, offset)
+  }
+
+  /**
+   * Retrieves the account info from the provided address and deserializes
+   * the {@link VaultCommittee} from its data.
+   *
+   * @throws Error if no account info is found at the address or if deserialization fails
+   */
+  static async fromAccountAddress(
+    connection: web3.Connection,
+    address: web3.PublicKey,
+    commitmentOrConfig?: web3.Commitment | web3.GetAccountInfoConfig
+  ): Promise<VaultCommittee> {
+    const accountInfo = await connection.getAccountInfo(
+      address,
+      commitmentOrConfig
+    )
+    if (accountInfo == null) {
+      throw new Error(`Unable to find VaultCommittee account at ${address}`)
+    }
+    return VaultCommittee.fromAccountInfo(accountInfo, 0)[0]
+  }
+
+  /**
+   * Provides a {@link web3.Connection.getProgramAccounts} config builder,
+   * to fetch accounts matching filters that can be specified via that builder.
+   *
+   * @param programId - the program that owns the accounts we are filtering
+   */
+  static gpaBuilder(
+    programId: web3.PublicKey = new web3.PublicKey(
+      'GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A'
+    )
+  ) {
+    return beetSolana.GpaBuilder.fromStruct(programId, vaultCommitteeBeet)
+  }
+
+  /**
+   * Deserializes the {@link VaultCommittee} from the provided data Buffer.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static deserialize(buf: Buffer, offset = 0): [VaultCommittee, number] {
+    return vaultCommitteeBeet.deserialize(buf, offset)
+  }
+
+  /**
+   * Serializes the {@link VaultCommittee} into a Buffer.
+   * @returns a tuple of the created Buffer and the offset up to which the buffer was written to store it.
+   */
+  serialize(): [Buffer, number] {
+    return vaultCommitteeBeet.serialize({
+      accountDiscriminator: vaultCommitteeDiscriminator,
+      ...this,
+    })
+  }
+
+  /**
+   * Returns the byteSize of a {@link Buffer} holding the serialized data of
+   * {@link VaultCommittee} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
+   */
+  static byteSize(args: VaultCommitteeArgs) {
+    const instance = VaultCommittee.fromArgs(args)
+    return vaultCommitteeBeet.toFixedFromValue({
+      accountDiscriminator: vaultCommitteeDiscriminator,
+      ...instance,
+    }).byteSize
+  }
+
+  /**
+   * Fetches the minimum balance needed to exempt an account holding
+   * {@link VaultCommittee} data from rent
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
+   * @param connection used to retrieve the rent exemption information
+   */
+  static async getMinimumBalanceForRentExemption(
+    args: VaultCommitteeArgs,
+    connection: web3.Connection,
+    commitment?: web3.Commitment
+  ): Promise<number> {
+    return connection.getMinimumBalanceForRentExemption(
+      VaultCommittee.byteSize(args),
+      commitment
+    )
+  }
+
+  /**
+   * Returns a readable version of {@link VaultCommittee} properties
+   * and can be used to convert to JSON and/or logging
+   */
+  pretty() {
+    return {
+      vault: this.vault.toBase58(),
+      creator: this.creator.toBase58(),
+      committeeIndex: this.committeeIndex,
+      bump: this.bump,
+      fundBump: this.fundBump,
+      members: this.members,
+      threshold: this.threshold,
+      transactionIndex: this.transactionIndex,
+    }
+  }
+}
+
+/**
+ * @category Accounts
+ * @category generated
+ */
+export const vaultCommitteeBeet = new beet.FixableBeetStruct<
+  VaultCommittee,
+  VaultCommitteeArgs & {
+    accountDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['vault', beetSolana.publicKey],
+    ['creator', beetSolana.publicKey],
+    ['committeeIndex', beet.u32],
+    ['bump', beet.u8],
+    ['fundBump', beet.u8],
+    ['members', beet.array(beetSolana.publicKey)],
+    ['threshold', beet.u16],
+    ['transactionIndex', beet.u32],
+  ],
+  VaultCommittee.fromArgs,
+  'VaultCommittee'
+)
@@ -0,0 +1,244 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as web3 from '@solana/web3.js'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+import * as beet from '@metaplex-foundation/beet'
+import {
+  VaultTransactionStatus,
+  vaultTransactionStatusBeet,
+} from '../types/VaultTransactionStatus'
+import {
+  VaultTransactionMessage,
+  vaultTransactionMessageBeet,
+} from '../types/VaultTransactionMessage'
+
+/**
+ * Arguments used to create {@link VaultCommitteeTransaction}
+ * @category Accounts
+ * @category generated
+ */
+export type VaultCommitteeTransactionArgs = {
+  creator: web3.PublicKey
+  vault: web3.PublicKey
+  committee: web3.PublicKey
+  transactionIndex: number
+  status: VaultTransactionStatus
+  bump: number
+  fundBump: number
+  ephemeralSignerBumps: Uint8Array
+  message: VaultTransactionMessage
+  approved: web3.PublicKey[]
+  rejected: web3.PublicKey[]
+  cancelled: web3.PublicKey[]
+}
+
+export const vaultCommitteeTransactionDiscriminator = [
+  238, 193, 213, 126, 8, 160, 123, 38,
+]
+/**
+ * Holds the data for the {@link VaultCommitteeTransaction} Account and provides de/serialization
+ * functionality for that data
+ *
+ * @category Accounts
+ * @category generated
+ */
+export class VaultCommitteeTransaction
+  implements VaultCommitteeTransactionArgs
+{
+  private constructor(
+    readonly creator: web3.PublicKey,
+    readonly vault: web3.PublicKey,
+    readonly committee: web3.PublicKey,
+    readonly transactionIndex: number,
+    readonly status: VaultTransactionStatus,
+    readonly bump: number,
+    readonly fundBump: number,
+    readonly ephemeralSignerBumps: Uint8Array,
+    readonly message: VaultTransactionMessage,
+    readonly approved: web3.PublicKey[],
+    readonly rejected: web3.PublicKey[],
+    readonly cancelled: web3.PublicKey[]
+  ) {}
+
+  /**
+   * Creates a {@link VaultCommitteeTransaction} instance from the provided args.
+   */
+  static fromArgs(args: VaultCommitteeTransactionArgs) {
+    return new VaultCommitteeTransaction(
+      args.creator,
+      args.vault,
+      args.committee,
+      args.transactionIndex,
+      args.status,
+      args.bump,
+      args.fundBump,
+      args.ephemeralSignerBumps,
+      args.message,
+      args.approved,
+      args.rejected,
+      args.cancelled
+    )
+  }
+
+  /**
+   * Deserializes the {@link VaultCommitteeTransaction} from the data of the provided {@link web3.AccountInfo}.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static fromAccountInfo(
+    accountInfo: web3.AccountInfo<Buffer>,
+    offset = 0
+  ): [VaultCommitteeTransaction, number] {
+    return VaultCommitteeTransaction.deserialize(accountInfo.data, offset)
+  }
+
+  /**
+   * Retrieves the account info from the provided address and deserializes
+   * the {@link VaultCommitteeTransaction} from its data.
+   *
+   * @throws Error if no account info is found at the address or if deserialization fails
+   */
+  static async fromAccountAddress(
+    connection: web3.Connection,
+    address: web3.PublicKey,
+    commitmentOrConfig?: web3.Commitment | web3.GetAccountInfoConfig
+  ): Promise<VaultCommitteeTransaction> {
+    const accountInfo = await connection.getAccountInfo(
+      address,
+      commitmentOrConfig
+    )
+    if (accountInfo == null) {
+      throw new Error(
+        `Unable to find VaultCommitteeTransaction account at ${address}`
+      )
+    }
+    return VaultCommitteeTransaction.fromAccountInfo(accountInfo, 0)[0]
+  }
+
+  /**
+   * Provides a {@link web3.Connection.getProgramAccounts} config builder,
+   * to fetch accounts matching filters that can be specified via that builder.
+   *
+   * @param programId - the program that owns the accounts we are filtering
+   */
+  static gpaBuilder(
+    programId: web3.PublicKey = new web3.PublicKey(
+      'GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A'
+    )
+  ) {
+    return beetSolana.GpaBuilder.fromStruct(
+      programId,
+      vaultCommitteeTransactionBeet
+    )
+  }
+
+  /**
+   * Deserializes the {@link VaultCommitteeTransaction} from the provided data Buffer.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static deserialize(
+    buf: Buffer,
+    offset = 0
+  ): [VaultCommitteeTransaction, number] {
+    return vaultCommitteeTransactionBeet.deserialize(buf, offset)
+  }
+
+  /**
+   * Serializes the {@link VaultCommitteeTransaction} into a Buffer.
+   * @returns a tuple of the created Buffer and the offset up to which the buffer was written to store it.
+   */
+  serialize(): [Buffer, number] {
+    return vaultCommitteeTransactionBeet.serialize({
+      accountDiscriminator: vaultCommitteeTransactionDiscriminator,
+      ...this,
+    })
+  }
+
+  /**
+   * Returns the byteSize of a {@link Buffer} holding the serialized data of
+   * {@link VaultCommitteeTransaction} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
+   */
+  static byteSize(args: VaultCommitteeTransactionArgs) {
+    const instance = VaultCommitteeTransaction.fromArgs(args)
+    return vaultCommitteeTransactionBeet.toFixedFromValue({
+      accountDiscriminator: vaultCommitteeTransactionDiscriminator,
+      ...instance,
+    }).byteSize
+  }
+
+  /**
+   * Fetches the minimum balance needed to exempt an account holding
+   * {@link VaultCommitteeTransaction} data from rent
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
+   * @param connection used to retrieve the rent exemption information
+   */
+  static async getMinimumBalanceForRentExemption(
+    args: VaultCommitteeTransactionArgs,
+    connection: web3.Connection,
+    commitment?: web3.Commitment
+  ): Promise<number> {
+    return connection.getMinimumBalanceForRentExemption(
+      VaultCommitteeTransaction.byteSize(args),
+      commitment
+    )
+  }
+
+  /**
+   * Returns a readable version of {@link VaultCommitteeTransaction} properties
+   * and can be used to convert to JSON and/or logging
+   */
+  pretty() {
+    return {
+      creator: this.creator.toBase58(),
+      vault: this.vault.toBase58(),
+      committee: this.committee.toBase58(),
+      transactionIndex: this.transactionIndex,
+      status: 'VaultTransactionStatus.' + VaultTransactionStatus[this.status],
+      bump: this.bump,
+      fundBump: this.fundBump,
+      ephemeralSignerBumps: this.ephemeralSignerBumps,
+      message: this.message,
+      approved: this.approved,
+      rejected: this.rejected,
+      cancelled: this.cancelled,
+    }
+  }
+}
+
+/**
+ * @category Accounts
+ * @category generated
+ */
+export const vaultCommitteeTransactionBeet = new beet.FixableBeetStruct<
+  VaultCommitteeTransaction,
+  VaultCommitteeTransactionArgs & {
+    accountDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['creator', beetSolana.publicKey],
+    ['vault', beetSolana.publicKey],
+    ['committee', beetSolana.publicKey],
+    ['transactionIndex', beet.u32],
+    ['status', vaultTransactionStatusBeet],
+    ['bump', beet.u8],
+    ['fundBump', beet.u8],
+    ['ephemeralSignerBumps', beet.bytes],
+    ['message', vaultTransactionMessageBeet],
+    ['approved', beet.array(beetSolana.publicKey)],
+    ['rejected', beet.array(beetSolana.publicKey)],
+    ['cancelled', beet.array(beetSolana.publicKey)],
+  ],
+  VaultCommitteeTransaction.fromArgs,
+  'VaultCommitteeTransaction'
+)
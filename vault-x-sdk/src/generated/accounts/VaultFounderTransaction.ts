@@ -6,8 +6,8 @@
  */
 
 import * as web3 from '@solana/web3.js'
-import * as beetSolana from '@metaplex-foundation/beet-solana'
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
 import {
   VaultTransactionStatus,
   vaultTransactionStatusBeet,
@@ -16,6 +16,10 @@ import {
   VaultTransactionMessage,
   vaultTransactionMessageBeet,
 } from '../types/VaultTransactionMessage'
+import {
+  VaultTransactionExpiry,
+  vaultTransactionExpiryBeet,
+} from '../types/VaultTransactionExpiry'
 
 /**
  * Arguments used to create {@link VaultFounderTransaction}
@@ -34,6 +38,11 @@ export type VaultFounderTransactionArgs = {
   approved: web3.PublicKey[]
   rejected: web3.PublicKey[]
   cancelled: web3.PublicKey[]
+  approvedAt: beet.bignum
+  executableAt: beet.bignum
+  expiry: beet.COption<VaultTransactionExpiry>
+  escalatedFrom: beet.COption<number>
+  executedInstructions: number
 }
 
 export const vaultFounderTransactionDiscriminator = [
@@ -58,7 +67,12 @@ export class VaultFounderTransaction implements VaultFounderTransactionArgs {
     readonly message: VaultTransactionMessage,
     readonly approved: web3.PublicKey[],
     readonly rejected: web3.PublicKey[],
-    readonly cancelled: web3.PublicKey[]
+    readonly cancelled: web3.PublicKey[],
+    readonly approvedAt: beet.bignum,
+    readonly executableAt: beet.bignum,
+    readonly expiry: beet.COption<VaultTransactionExpiry>,
+    readonly escalatedFrom: beet.COption<number>,
+    readonly executedInstructions: number
   ) {}
 
   /**
@@ -76,7 +90,12 @@ export class VaultFounderTransaction implements VaultFounderTransactionArgs {
       args.message,
       args.approved,
       args.rejected,
-      args.cancelled
+      args.cancelled,
+      args.approvedAt,
+      args.executableAt,
+      args.expiry,
+      args.escalatedFrom,
+      args.executedInstructions
     )
   }
 
@@ -204,6 +223,31 @@ export class VaultFounderTransaction implements VaultFounderTransactionArgs {
       approved: this.approved,
       rejected: this.rejected,
       cancelled: this.cancelled,
+      approvedAt: (() => {
+        const x = <{ toNumber: () => number }>this.approvedAt
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      executableAt: (() => {
+        const x = <{ toNumber: () => number }>this.executableAt
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      expiry: this.expiry,
+      escalatedFrom: this.escalatedFrom,
+      executedInstructions: this.executedInstructions,
     }
   }
 }
@@ -231,6 +275,11 @@ export const vaultFounderTransactionBeet = new beet.FixableBeetStruct<
     ['approved', beet.array(beetSolana.publicKey)],
     ['rejected', beet.array(beetSolana.publicKey)],
     ['cancelled', beet.array(beetSolana.publicKey)],
+    ['approvedAt', beet.i64],
+    ['executableAt', beet.i64],
+    ['expiry', beet.coption(vaultTransactionExpiryBeet)],
+    ['escalatedFrom', beet.coption(beet.u32)],
+    ['executedInstructions', beet.u16],
   ],
   VaultFounderTransaction.fromArgs,
   'VaultFounderTransaction'
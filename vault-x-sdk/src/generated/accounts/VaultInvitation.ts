@@ -0,0 +1,210 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as web3 from '@solana/web3.js'
+import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+import { VaultRole, vaultRoleBeet } from '../types/VaultRole'
+
+/**
+ * Arguments used to create {@link VaultInvitation}
+ * @category Accounts
+ * @category generated
+ */
+export type VaultInvitationArgs = {
+  vault: web3.PublicKey
+  inviter: web3.PublicKey
+  invitee: web3.PublicKey
+  role: VaultRole
+  founderWeight: number
+  expiresAt: beet.bignum
+  bump: number
+}
+
+export const vaultInvitationDiscriminator = [
+  59, 58, 108, 241, 139, 241, 69, 230,
+]
+/**
+ * Holds the data for the {@link VaultInvitation} Account and provides de/serialization
+ * functionality for that data
+ *
+ * @category Accounts
+ * @category generated
+ */
+export class VaultInvitation implements VaultInvitationArgs {
+  private constructor(
+    readonly vault: web3.PublicKey,
+    readonly inviter: web3.PublicKey,
+    readonly invitee: web3.PublicKey,
+    readonly role: VaultRole,
+    readonly founderWeight: number,
+    readonly expiresAt: beet.bignum,
+    readonly bump: number
+  ) {}
+
+  /**
+   * Creates a {@link VaultInvitation} instance from the provided args.
+   */
+  static fromArgs(args: VaultInvitationArgs) {
+    return new VaultInvitation(
+      args.vault,
+      args.inviter,
+      args.invitee,
+      args.role,
+      args.founderWeight,
+      args.expiresAt,
+      args.bump
+    )
+  }
+
+  /**
+   * Deserializes the {@link VaultInvitation} from the data of the provided {@link web3.AccountInfo}.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static fromAccountInfo(
+    accountInfo: web3.AccountInfo<Buffer>,
+    offset = 0
+  ): [VaultInvitation, number] {
+    return VaultInvitation.deserialize(accountInfo.data, offset)
+  }
+
+  /**
+   * Retrieves the account info from the provided address and deserializes
+   * the {@link VaultInvitation} from its data.
+   *
+   * @throws Error if no account info is found at the address or if deserialization fails
+   */
+  static async fromAccountAddress(
+    connection: web3.Connection,
+    address: web3.PublicKey,
+    commitmentOrConfig?: web3.Commitment | web3.GetAccountInfoConfig
+  ): Promise<VaultInvitation> {
+    const accountInfo = await connection.getAccountInfo(
+      address,
+      commitmentOrConfig
+    )
+    if (accountInfo == null) {
+      throw new Error(`Unable to find VaultInvitation account at ${address}`)
+    }
+    return VaultInvitation.fromAccountInfo(accountInfo, 0)[0]
+  }
+
+  /**
+   * Provides a {@link web3.Connection.getProgramAccounts} config builder,
+   * to fetch accounts matching filters that can be specified via that builder.
+   *
+   * @param programId - the program that owns the accounts we are filtering
+   */
+  static gpaBuilder(
+    programId: web3.PublicKey = new web3.PublicKey(
+      'GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A'
+    )
+  ) {
+    return beetSolana.GpaBuilder.fromStruct(programId, vaultInvitationBeet)
+  }
+
+  /**
+   * Deserializes the {@link VaultInvitation} from the provided data Buffer.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static deserialize(buf: Buffer, offset = 0): [VaultInvitation, number] {
+    return vaultInvitationBeet.deserialize(buf, offset)
+  }
+
+  /**
+   * Serializes the {@link VaultInvitation} into a Buffer.
+   * @returns a tuple of the created Buffer and the offset up to which the buffer was written to store it.
+   */
+  serialize(): [Buffer, number] {
+    return vaultInvitationBeet.serialize({
+      accountDiscriminator: vaultInvitationDiscriminator,
+      ...this,
+    })
+  }
+
+  /**
+   * Returns the byteSize of a {@link Buffer} holding the serialized data of
+   * {@link VaultInvitation}
+   */
+  static get byteSize() {
+    return vaultInvitationBeet.byteSize
+  }
+
+  /**
+   * Fetches the minimum balance needed to exempt an account holding
+   * {@link VaultInvitation} data from rent
+   *
+   * @param connection used to retrieve the rent exemption information
+   */
+  static async getMinimumBalanceForRentExemption(
+    connection: web3.Connection,
+    commitment?: web3.Commitment
+  ): Promise<number> {
+    return connection.getMinimumBalanceForRentExemption(
+      VaultInvitation.byteSize,
+      commitment
+    )
+  }
+
+  /**
+   * Determines if the provided {@link Buffer} has the correct byte size to
+   * hold {@link VaultInvitation} data.
+   */
+  static hasCorrectByteSize(buf: Buffer, offset = 0) {
+    return buf.byteLength - offset === VaultInvitation.byteSize
+  }
+
+  /**
+   * Returns a readable version of {@link VaultInvitation} properties
+   * and can be used to convert to JSON and/or logging
+   */
+  pretty() {
+    return {
+      vault: this.vault.toBase58(),
+      inviter: this.inviter.toBase58(),
+      invitee: this.invitee.toBase58(),
+      role: 'VaultRole.' + VaultRole[this.role],
+      founderWeight: this.founderWeight,
+      expiresAt: (() => {
+        const x = <{ toNumber: () => number }>this.expiresAt
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      bump: this.bump,
+    }
+  }
+}
+
+/**
+ * @category Accounts
+ * @category generated
+ */
+export const vaultInvitationBeet = new beet.BeetStruct<
+  VaultInvitation,
+  VaultInvitationArgs & {
+    accountDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['vault', beetSolana.publicKey],
+    ['inviter', beetSolana.publicKey],
+    ['invitee', beetSolana.publicKey],
+    ['role', vaultRoleBeet],
+    ['founderWeight', beet.u16],
+    ['expiresAt', beet.i64],
+    ['bump', beet.u8],
+  ],
+  VaultInvitation.fromArgs,
+  'VaultInvitation'
+)
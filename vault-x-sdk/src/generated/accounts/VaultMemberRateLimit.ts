@@ -0,0 +1,211 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as web3 from '@solana/web3.js'
+import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+
+/**
+ * Arguments used to create {@link VaultMemberRateLimit}
+ * @category Accounts
+ * @category generated
+ */
+export type VaultMemberRateLimitArgs = {
+  vault: web3.PublicKey
+  member: web3.PublicKey
+  bump: number
+  maxTransactions: number
+  windowSeconds: number
+  windowStartedAt: beet.bignum
+  transactionCount: number
+}
+
+export const vaultMemberRateLimitDiscriminator = [
+  57, 222, 73, 224, 17, 237, 125, 56,
+]
+/**
+ * Holds the data for the {@link VaultMemberRateLimit} Account and provides de/serialization
+ * functionality for that data
+ *
+ * @category Accounts
+ * @category generated
+ */
+export class VaultMemberRateLimit implements VaultMemberRateLimitArgs {
+  private constructor(
+    readonly vault: web3.PublicKey,
+    readonly member: web3.PublicKey,
+    readonly bump: number,
+    readonly maxTransactions: number,
+    readonly windowSeconds: number,
+    readonly windowStartedAt: beet.bignum,
+    readonly transactionCount: number
+  ) {}
+
+  /**
+   * Creates a {@link VaultMemberRateLimit} instance from the provided args.
+   */
+  static fromArgs(args: VaultMemberRateLimitArgs) {
+    return new VaultMemberRateLimit(
+      args.vault,
+      args.member,
+      args.bump,
+      args.maxTransactions,
+      args.windowSeconds,
+      args.windowStartedAt,
+      args.transactionCount
+    )
+  }
+
+  /**
+   * Deserializes the {@link VaultMemberRateLimit} from the data of the provided {@link web3.AccountInfo}.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static fromAccountInfo(
+    accountInfo: web3.AccountInfo<Buffer>,
+    offset = 0
+  ): [VaultMemberRateLimit, number] {
+    return VaultMemberRateLimit.deserialize(accountInfo.data, offset)
+  }
+
+  /**
+   * Retrieves the account info from the provided address and deserializes
+   * the {@link VaultMemberRateLimit} from its data.
+   *
+   * @throws Error if no account info is found at the address or if deserialization fails
+   */
+  static async fromAccountAddress(
+    connection: web3.Connection,
+    address: web3.PublicKey,
+    commitmentOrConfig?: web3.Commitment | web3.GetAccountInfoConfig
+  ): Promise<VaultMemberRateLimit> {
+    const accountInfo = await connection.getAccountInfo(
+      address,
+      commitmentOrConfig
+    )
+    if (accountInfo == null) {
+      throw new Error(
+        `Unable to find VaultMemberRateLimit account at ${address}`
+      )
+    }
+    return VaultMemberRateLimit.fromAccountInfo(accountInfo, 0)[0]
+  }
+
+  /**
+   * Provides a {@link web3.Connection.getProgramAccounts} config builder,
+   * to fetch accounts matching filters that can be specified via that builder.
+   *
+   * @param programId - the program that owns the accounts we are filtering
+   */
+  static gpaBuilder(
+    programId: web3.PublicKey = new web3.PublicKey(
+      'GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A'
+    )
+  ) {
+    return beetSolana.GpaBuilder.fromStruct(programId, vaultMemberRateLimitBeet)
+  }
+
+  /**
+   * Deserializes the {@link VaultMemberRateLimit} from the provided data Buffer.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static deserialize(buf: Buffer, offset = 0): [VaultMemberRateLimit, number] {
+    return vaultMemberRateLimitBeet.deserialize(buf, offset)
+  }
+
+  /**
+   * Serializes the {@link VaultMemberRateLimit} into a Buffer.
+   * @returns a tuple of the created Buffer and the offset up to which the buffer was written to store it.
+   */
+  serialize(): [Buffer, number] {
+    return vaultMemberRateLimitBeet.serialize({
+      accountDiscriminator: vaultMemberRateLimitDiscriminator,
+      ...this,
+    })
+  }
+
+  /**
+   * Returns the byteSize of a {@link Buffer} holding the serialized data of
+   * {@link VaultMemberRateLimit}
+   */
+  static get byteSize() {
+    return vaultMemberRateLimitBeet.byteSize
+  }
+
+  /**
+   * Fetches the minimum balance needed to exempt an account holding
+   * {@link VaultMemberRateLimit} data from rent
+   *
+   * @param connection used to retrieve the rent exemption information
+   */
+  static async getMinimumBalanceForRentExemption(
+    connection: web3.Connection,
+    commitment?: web3.Commitment
+  ): Promise<number> {
+    return connection.getMinimumBalanceForRentExemption(
+      VaultMemberRateLimit.byteSize,
+      commitment
+    )
+  }
+
+  /**
+   * Determines if the provided {@link Buffer} has the correct byte size to
+   * hold {@link VaultMemberRateLimit} data.
+   */
+  static hasCorrectByteSize(buf: Buffer, offset = 0) {
+    return buf.byteLength - offset === VaultMemberRateLimit.byteSize
+  }
+
+  /**
+   * Returns a readable version of {@link VaultMemberRateLimit} properties
+   * and can be used to convert to JSON and/or logging
+   */
+  pretty() {
+    return {
+      vault: this.vault.toBase58(),
+      member: this.member.toBase58(),
+      bump: this.bump,
+      maxTransactions: this.maxTransactions,
+      windowSeconds: this.windowSeconds,
+      windowStartedAt: (() => {
+        const x = <{ toNumber: () => number }>this.windowStartedAt
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      transactionCount: this.transactionCount,
+    }
+  }
+}
+
+/**
+ * @category Accounts
+ * @category generated
+ */
+export const vaultMemberRateLimitBeet = new beet.BeetStruct<
+  VaultMemberRateLimit,
+  VaultMemberRateLimitArgs & {
+    accountDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['vault', beetSolana.publicKey],
+    ['member', beetSolana.publicKey],
+    ['bump', beet.u8],
+    ['maxTransactions', beet.u32],
+    ['windowSeconds', beet.u32],
+    ['windowStartedAt', beet.i64],
+    ['transactionCount', beet.u32],
+  ],
+  VaultMemberRateLimit.fromArgs,
+  'VaultMemberRateLimit'
+)
@@ -6,8 +6,12 @@
  */
 
 import * as web3 from '@solana/web3.js'
-import * as beetSolana from '@metaplex-foundation/beet-solana'
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+import {
+  VaultTransactionStatus,
+  vaultTransactionStatusBeet,
+} from '../types/VaultTransactionStatus'
 import {
   VaultTransactionMessage,
   vaultTransactionMessageBeet,
@@ -22,10 +26,18 @@ export type VaultMemberTransactionArgs = {
   creator: web3.PublicKey
   vault: web3.PublicKey
   transactionIndex: number
+  status: VaultTransactionStatus
   bump: number
   fundBump: number
   ephemeralSignerBumps: Uint8Array
   message: VaultTransactionMessage
+  approved: web3.PublicKey[]
+  rejected: web3.PublicKey[]
+  cancelled: web3.PublicKey[]
+  executionCount: number
+  maxExecutions: number
+  cooldownSeconds: number
+  lastExecutedAt: beet.bignum
 }
 
 export const vaultMemberTransactionDiscriminator = [
@@ -43,10 +55,18 @@ export class VaultMemberTransaction implements VaultMemberTransactionArgs {
     readonly creator: web3.PublicKey,
     readonly vault: web3.PublicKey,
     readonly transactionIndex: number,
+    readonly status: VaultTransactionStatus,
     readonly bump: number,
     readonly fundBump: number,
     readonly ephemeralSignerBumps: Uint8Array,
-    readonly message: VaultTransactionMessage
+    readonly message: VaultTransactionMessage,
+    readonly approved: web3.PublicKey[],
+    readonly rejected: web3.PublicKey[],
+    readonly cancelled: web3.PublicKey[],
+    readonly executionCount: number,
+    readonly maxExecutions: number,
+    readonly cooldownSeconds: number,
+    readonly lastExecutedAt: beet.bignum
   ) {}
 
   /**
@@ -57,10 +77,18 @@ export class VaultMemberTransaction implements VaultMemberTransactionArgs {
       args.creator,
       args.vault,
       args.transactionIndex,
+      args.status,
       args.bump,
       args.fundBump,
       args.ephemeralSignerBumps,
-      args.message
+      args.message,
+      args.approved,
+      args.rejected,
+      args.cancelled,
+      args.executionCount,
+      args.maxExecutions,
+      args.cooldownSeconds,
+      args.lastExecutedAt
     )
   }
 
@@ -180,10 +208,28 @@ export class VaultMemberTransaction implements VaultMemberTransactionArgs {
       creator: this.creator.toBase58(),
       vault: this.vault.toBase58(),
       transactionIndex: this.transactionIndex,
+      status: 'VaultTransactionStatus.' + VaultTransactionStatus[this.status],
       bump: this.bump,
       fundBump: this.fundBump,
       ephemeralSignerBumps: this.ephemeralSignerBumps,
       message: this.message,
+      approved: this.approved,
+      rejected: this.rejected,
+      cancelled: this.cancelled,
+      executionCount: this.executionCount,
+      maxExecutions: this.maxExecutions,
+      cooldownSeconds: this.cooldownSeconds,
+      lastExecutedAt: (() => {
+        const x = <{ toNumber: () => number }>this.lastExecutedAt
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
     }
   }
 }
@@ -203,10 +249,18 @@ export const vaultMemberTransactionBeet = new beet.FixableBeetStruct<
     ['creator', beetSolana.publicKey],
     ['vault', beetSolana.publicKey],
     ['transactionIndex', beet.u32],
+    ['status', vaultTransactionStatusBeet],
     ['bump', beet.u8],
     ['fundBump', beet.u8],
     ['ephemeralSignerBumps', beet.bytes],
     ['message', vaultTransactionMessageBeet],
+    ['approved', beet.array(beetSolana.publicKey)],
+    ['rejected', beet.array(beetSolana.publicKey)],
+    ['cancelled', beet.array(beetSolana.publicKey)],
+    ['executionCount', beet.u32],
+    ['maxExecutions', beet.u32],
+    ['cooldownSeconds', beet.u32],
+    ['lastExecutedAt', beet.i64],
   ],
   VaultMemberTransaction.fromArgs,
   'VaultMemberTransaction'
@@ -0,0 +1,199 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as web3 from '@solana/web3.js'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+import * as beet from '@metaplex-foundation/beet'
+
+/**
+ * Arguments used to create {@link VaultMetadata}
+ * @category Accounts
+ * @category generated
+ */
+export type VaultMetadataArgs = {
+  vault: web3.PublicKey
+  bump: number
+  name: string
+  description: string
+  imageUri: string
+  website: string
+  tags: string[]
+}
+
+export const vaultMetadataDiscriminator = [248, 177, 244, 93, 67, 19, 117, 57]
+/**
+ * Holds the data for the {@link VaultMetadata} Account and provides de/serialization
+ * functionality for that data
+ *
+ * @category Accounts
+ * @category generated
+ */
+export class VaultMetadata implements VaultMetadataArgs {
+  private constructor(
+    readonly vault: web3.PublicKey,
+    readonly bump: number,
+    readonly name: string,
+    readonly description: string,
+    readonly imageUri: string,
+    readonly website: string,
+    readonly tags: string[]
+  ) {}
+
+  /**
+   * Creates a {@link VaultMetadata} instance from the provided args.
+   */
+  static fromArgs(args: VaultMetadataArgs) {
+    return new VaultMetadata(
+      args.vault,
+      args.bump,
+      args.name,
+      args.description,
+      args.imageUri,
+      args.website,
+      args.tags
+    )
+  }
+
+  /**
+   * Deserializes the {@link VaultMetadata} from the data of the provided {@link web3.AccountInfo}.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static fromAccountInfo(
+    accountInfo: web3.AccountInfo<Buffer>,
+    offset = 0
+  ): [VaultMetadata, number] {
+    return VaultMetadata.deserialize(accountInfo.data, offset)
+  }
+
+  /**
+   * Retrieves the account info from the provided address and deserializes
+   * the {@link VaultMetadata} from its data.
+   *
+   * @throws Error if no account info is found at the address or if deserialization fails
+   */
+  static async fromAccountAddress(
+    connection: web3.Connection,
+    address: web3.PublicKey,
+    commitmentOrConfig?: web3.Commitment | web3.GetAccountInfoConfig
+  ): Promise<VaultMetadata> {
+    const accountInfo = await connection.getAccountInfo(
+      address,
+      commitmentOrConfig
+    )
+    if (accountInfo == null) {
+      throw new Error(`Unable to find VaultMetadata account at ${address}`)
+    }
+    return VaultMetadata.fromAccountInfo(accountInfo, 0)[0]
+  }
+
+  /**
+   * Provides a {@link web3.Connection.getProgramAccounts} config builder,
+   * to fetch accounts matching filters that can be specified via that builder.
+   *
+   * @param programId - the program that owns the accounts we are filtering
+   */
+  static gpaBuilder(
+    programId: web3.PublicKey = new web3.PublicKey(
+      'GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A'
+    )
+  ) {
+    return beetSolana.GpaBuilder.fromStruct(programId, vaultMetadataBeet)
+  }
+
+  /**
+   * Deserializes the {@link VaultMetadata} from the provided data Buffer.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static deserialize(buf: Buffer, offset = 0): [VaultMetadata, number] {
+    return vaultMetadataBeet.deserialize(buf, offset)
+  }
+
+  /**
+   * Serializes the {@link VaultMetadata} into a Buffer.
+   * @returns a tuple of the created Buffer and the offset up to which the buffer was written to store it.
+   */
+  serialize(): [Buffer, number] {
+    return vaultMetadataBeet.serialize({
+      accountDiscriminator: vaultMetadataDiscriminator,
+      ...this,
+    })
+  }
+
+  /**
+   * Returns the byteSize of a {@link Buffer} holding the serialized data of
+   * {@link VaultMetadata} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
+   */
+  static byteSize(args: VaultMetadataArgs) {
+    const instance = VaultMetadata.fromArgs(args)
+    return vaultMetadataBeet.toFixedFromValue({
+      accountDiscriminator: vaultMetadataDiscriminator,
+      ...instance,
+    }).byteSize
+  }
+
+  /**
+   * Fetches the minimum balance needed to exempt an account holding
+   * {@link VaultMetadata} data from rent
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
+   * @param connection used to retrieve the rent exemption information
+   */
+  static async getMinimumBalanceForRentExemption(
+    args: VaultMetadataArgs,
+    connection: web3.Connection,
+    commitment?: web3.Commitment
+  ): Promise<number> {
+    return connection.getMinimumBalanceForRentExemption(
+      VaultMetadata.byteSize(args),
+      commitment
+    )
+  }
+
+  /**
+   * Returns a readable version of {@link VaultMetadata} properties
+   * and can be used to convert to JSON and/or logging
+   */
+  pretty() {
+    return {
+      vault: this.vault.toBase58(),
+      bump: this.bump,
+      name: this.name,
+      description: this.description,
+      imageUri: this.imageUri,
+      website: this.website,
+      tags: this.tags,
+    }
+  }
+}
+
+/**
+ * @category Accounts
+ * @category generated
+ */
+export const vaultMetadataBeet = new beet.FixableBeetStruct<
+  VaultMetadata,
+  VaultMetadataArgs & {
+    accountDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['vault', beetSolana.publicKey],
+    ['bump', beet.u8],
+    ['name', beet.utf8String],
+    ['description', beet.utf8String],
+    ['imageUri', beet.utf8String],
+    ['website', beet.utf8String],
+    ['tags', beet.array(beet.utf8String)],
+  ],
+  VaultMetadata.fromArgs,
+  'VaultMetadata'
+)
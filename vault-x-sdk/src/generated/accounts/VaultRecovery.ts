@@ -0,0 +1,203 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as web3 from '@solana/web3.js'
+import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+import {
+  VaultRecoveryProposal,
+  vaultRecoveryProposalBeet,
+} from '../types/VaultRecoveryProposal'
+
+/**
+ * Arguments used to create {@link VaultRecovery}
+ * @category Accounts
+ * @category generated
+ */
+export type VaultRecoveryArgs = {
+  vault: web3.PublicKey
+  bump: number
+  guardians: web3.PublicKey[]
+  guardianThreshold: number
+  inactivityPeriodSeconds: number
+  timeLockSeconds: number
+  proposal: beet.COption<VaultRecoveryProposal>
+}
+
+export const vaultRecoveryDiscriminator = [119, 60, 25, 214, 253, 54, 140, 197]
+/**
+ * Holds the data for the {@link VaultRecovery} Account and provides de/serialization
+ * functionality for that data
+ *
+ * @category Accounts
+ * @category generated
+ */
+export class VaultRecovery implements VaultRecoveryArgs {
+  private constructor(
+    readonly vault: web3.PublicKey,
+    readonly bump: number,
+    readonly guardians: web3.PublicKey[],
+    readonly guardianThreshold: number,
+    readonly inactivityPeriodSeconds: number,
+    readonly timeLockSeconds: number,
+    readonly proposal: beet.COption<VaultRecoveryProposal>
+  ) {}
+
+  /**
+   * Creates a {@link VaultRecovery} instance from the provided args.
+   */
+  static fromArgs(args: VaultRecoveryArgs) {
+    return new VaultRecovery(
+      args.vault,
+      args.bump,
+      args.guardians,
+      args.guardianThreshold,
+      args.inactivityPeriodSeconds,
+      args.timeLockSeconds,
+      args.proposal
+    )
+  }
+
+  /**
+   * Deserializes the {@link VaultRecovery} from the data of the provided {@link web3.AccountInfo}.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static fromAccountInfo(
+    accountInfo: web3.AccountInfo<Buffer>,
+    offset = 0
+  ): [VaultRecovery, number] {
+    return VaultRecovery.deserialize(accountInfo.data, offset)
+  }
+
+  /**
+   * Retrieves the account info from the provided address and deserializes
+   * the {@link VaultRecovery} from its data.
+   *
+   * @throws Error if no account info is found at the address or if deserialization fails
+   */
+  static async fromAccountAddress(
+    connection: web3.Connection,
+    address: web3.PublicKey,
+    commitmentOrConfig?: web3.Commitment | web3.GetAccountInfoConfig
+  ): Promise<VaultRecovery> {
+    const accountInfo = await connection.getAccountInfo(
+      address,
+      commitmentOrConfig
+    )
+    if (accountInfo == null) {
+      throw new Error(`Unable to find VaultRecovery account at ${address}`)
+    }
+    return VaultRecovery.fromAccountInfo(accountInfo, 0)[0]
+  }
+
+  /**
+   * Provides a {@link web3.Connection.getProgramAccounts} config builder,
+   * to fetch accounts matching filters that can be specified via that builder.
+   *
+   * @param programId - the program that owns the accounts we are filtering
+   */
+  static gpaBuilder(
+    programId: web3.PublicKey = new web3.PublicKey(
+      'GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A'
+    )
+  ) {
+    return beetSolana.GpaBuilder.fromStruct(programId, vaultRecoveryBeet)
+  }
+
+  /**
+   * Deserializes the {@link VaultRecovery} from the provided data Buffer.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static deserialize(buf: Buffer, offset = 0): [VaultRecovery, number] {
+    return vaultRecoveryBeet.deserialize(buf, offset)
+  }
+
+  /**
+   * Serializes the {@link VaultRecovery} into a Buffer.
+   * @returns a tuple of the created Buffer and the offset up to which the buffer was written to store it.
+   */
+  serialize(): [Buffer, number] {
+    return vaultRecoveryBeet.serialize({
+      accountDiscriminator: vaultRecoveryDiscriminator,
+      ...this,
+    })
+  }
+
+  /**
+   * Returns the byteSize of a {@link Buffer} holding the serialized data of
+   * {@link VaultRecovery} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
+   */
+  static byteSize(args: VaultRecoveryArgs) {
+    const instance = VaultRecovery.fromArgs(args)
+    return vaultRecoveryBeet.toFixedFromValue({
+      accountDiscriminator: vaultRecoveryDiscriminator,
+      ...instance,
+    }).byteSize
+  }
+
+  /**
+   * Fetches the minimum balance needed to exempt an account holding
+   * {@link VaultRecovery} data from rent
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
+   * @param connection used to retrieve the rent exemption information
+   */
+  static async getMinimumBalanceForRentExemption(
+    args: VaultRecoveryArgs,
+    connection: web3.Connection,
+    commitment?: web3.Commitment
+  ): Promise<number> {
+    return connection.getMinimumBalanceForRentExemption(
+      VaultRecovery.byteSize(args),
+      commitment
+    )
+  }
+
+  /**
+   * Returns a readable version of {@link VaultRecovery} properties
+   * and can be used to convert to JSON and/or logging
+   */
+  pretty() {
+    return {
+      vault: this.vault.toBase58(),
+      bump: this.bump,
+      guardians: this.guardians,
+      guardianThreshold: this.guardianThreshold,
+      inactivityPeriodSeconds: this.inactivityPeriodSeconds,
+      timeLockSeconds: this.timeLockSeconds,
+      proposal: this.proposal,
+    }
+  }
+}
+
+/**
+ * @category Accounts
+ * @category generated
+ */
+export const vaultRecoveryBeet = new beet.FixableBeetStruct<
+  VaultRecovery,
+  VaultRecoveryArgs & {
+    accountDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['vault', beetSolana.publicKey],
+    ['bump', beet.u8],
+    ['guardians', beet.array(beetSolana.publicKey)],
+    ['guardianThreshold', beet.u16],
+    ['inactivityPeriodSeconds', beet.u32],
+    ['timeLockSeconds', beet.u32],
+    ['proposal', beet.coption(vaultRecoveryProposalBeet)],
+  ],
+  VaultRecovery.fromArgs,
+  'VaultRecovery'
+)
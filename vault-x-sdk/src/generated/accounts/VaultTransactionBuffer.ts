@@ -0,0 +1,209 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as web3 from '@solana/web3.js'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+import * as beet from '@metaplex-foundation/beet'
+
+/**
+ * Arguments used to create {@link VaultTransactionBuffer}
+ * @category Accounts
+ * @category generated
+ */
+export type VaultTransactionBufferArgs = {
+  vault: web3.PublicKey
+  creator: web3.PublicKey
+  bufferIndex: number
+  bump: number
+  finalBufferHash: number[] /* size: 32 */
+  finalBufferSize: number
+  buffer: Uint8Array
+}
+
+export const vaultTransactionBufferDiscriminator = [
+  136, 207, 236, 16, 249, 197, 252, 112,
+]
+/**
+ * Holds the data for the {@link VaultTransactionBuffer} Account and provides de/serialization
+ * functionality for that data
+ *
+ * @category Accounts
+ * @category generated
+ */
+export class VaultTransactionBuffer implements VaultTransactionBufferArgs {
+  private constructor(
+    readonly vault: web3.PublicKey,
+    readonly creator: web3.PublicKey,
+    readonly bufferIndex: number,
+    readonly bump: number,
+    readonly finalBufferHash: number[] /* size: 32 */,
+    readonly finalBufferSize: number,
+    readonly buffer: Uint8Array
+  ) {}
+
+  /**
+   * Creates a {@link VaultTransactionBuffer} instance from the provided args.
+   */
+  static fromArgs(args: VaultTransactionBufferArgs) {
+    return new VaultTransactionBuffer(
+      args.vault,
+      args.creator,
+      args.bufferIndex,
+      args.bump,
+      args.finalBufferHash,
+      args.finalBufferSize,
+      args.buffer
+    )
+  }
+
+  /**
+   * Deserializes the {@link VaultTransactionBuffer} from the data of the provided {@link web3.AccountInfo}.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static fromAccountInfo(
+    accountInfo: web3.AccountInfo<Buffer>,
+    offset = 0
+  ): [VaultTransactionBuffer, number] {
+    return VaultTransactionBuffer.deserialize(accountInfo.data, offset)
+  }
+
+  /**
+   * Retrieves the account info from the provided address and deserializes
+   * the {@link VaultTransactionBuffer} from its data.
+   *
+   * @throws Error if no account info is found at the address or if deserialization fails
+   */
+  static async fromAccountAddress(
+    connection: web3.Connection,
+    address: web3.PublicKey,
+    commitmentOrConfig?: web3.Commitment | web3.GetAccountInfoConfig
+  ): Promise<VaultTransactionBuffer> {
+    const accountInfo = await connection.getAccountInfo(
+      address,
+      commitmentOrConfig
+    )
+    if (accountInfo == null) {
+      throw new Error(
+        `Unable to find VaultTransactionBuffer account at ${address}`
+      )
+    }
+    return VaultTransactionBuffer.fromAccountInfo(accountInfo, 0)[0]
+  }
+
+  /**
+   * Provides a {@link web3.Connection.getProgramAccounts} config builder,
+   * to fetch accounts matching filters that can be specified via that builder.
+   *
+   * @param programId - the program that owns the accounts we are filtering
+   */
+  static gpaBuilder(
+    programId: web3.PublicKey = new web3.PublicKey(
+      'GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A'
+    )
+  ) {
+    return beetSolana.GpaBuilder.fromStruct(
+      programId,
+      vaultTransactionBufferBeet
+    )
+  }
+
+  /**
+   * Deserializes the {@link VaultTransactionBuffer} from the provided data Buffer.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static deserialize(
+    buf: Buffer,
+    offset = 0
+  ): [VaultTransactionBuffer, number] {
+    return vaultTransactionBufferBeet.deserialize(buf, offset)
+  }
+
+  /**
+   * Serializes the {@link VaultTransactionBuffer} into a Buffer.
+   * @returns a tuple of the created Buffer and the offset up to which the buffer was written to store it.
+   */
+  serialize(): [Buffer, number] {
+    return vaultTransactionBufferBeet.serialize({
+      accountDiscriminator: vaultTransactionBufferDiscriminator,
+      ...this,
+    })
+  }
+
+  /**
+   * Returns the byteSize of a {@link Buffer} holding the serialized data of
+   * {@link VaultTransactionBuffer} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
+   */
+  static byteSize(args: VaultTransactionBufferArgs) {
+    const instance = VaultTransactionBuffer.fromArgs(args)
+    return vaultTransactionBufferBeet.toFixedFromValue({
+      accountDiscriminator: vaultTransactionBufferDiscriminator,
+      ...instance,
+    }).byteSize
+  }
+
+  /**
+   * Fetches the minimum balance needed to exempt an account holding
+   * {@link VaultTransactionBuffer} data from rent
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
+   * @param connection used to retrieve the rent exemption information
+   */
+  static async getMinimumBalanceForRentExemption(
+    args: VaultTransactionBufferArgs,
+    connection: web3.Connection,
+    commitment?: web3.Commitment
+  ): Promise<number> {
+    return connection.getMinimumBalanceForRentExemption(
+      VaultTransactionBuffer.byteSize(args),
+      commitment
+    )
+  }
+
+  /**
+   * Returns a readable version of {@link VaultTransactionBuffer} properties
+   * and can be used to convert to JSON and/or logging
+   */
+  pretty() {
+    return {
+      vault: this.vault.toBase58(),
+      creator: this.creator.toBase58(),
+      bufferIndex: this.bufferIndex,
+      bump: this.bump,
+      finalBufferHash: this.finalBufferHash,
+      finalBufferSize: this.finalBufferSize,
+      buffer: this.buffer,
+    }
+  }
+}
+
+/**
+ * @category Accounts
+ * @category generated
+ */
+export const vaultTransactionBufferBeet = new beet.FixableBeetStruct<
+  VaultTransactionBuffer,
+  VaultTransactionBufferArgs & {
+    accountDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['vault', beetSolana.publicKey],
+    ['creator', beetSolana.publicKey],
+    ['bufferIndex', beet.u8],
+    ['bump', beet.u8],
+    ['finalBufferHash', beet.uniformFixedSizeArray(beet.u8, 32)],
+    ['finalBufferSize', beet.u16],
+    ['buffer', beet.bytes],
+  ],
+  VaultTransactionBuffer.fromArgs,
+  'VaultTransactionBuffer'
+)
@@ -0,0 +1,186 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as web3 from '@solana/web3.js'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+import * as beet from '@metaplex-foundation/beet'
+
+/**
+ * Arguments used to create {@link VaultVetoCouncil}
+ * @category Accounts
+ * @category generated
+ */
+export type VaultVetoCouncilArgs = {
+  vault: web3.PublicKey
+  bump: number
+  guardians: web3.PublicKey[]
+  guardianThreshold: number
+}
+
+export const vaultVetoCouncilDiscriminator = [
+  144, 29, 34, 59, 142, 151, 213, 38,
+]
+/**
+ * Holds the data for the {@link VaultVetoCouncil} Account and provides de/serialization
+ * functionality for that data
+ *
+ * @category Accounts
+ * @category generated
+ */
+export class VaultVetoCouncil implements VaultVetoCouncilArgs {
+  private constructor(
+    readonly vault: web3.PublicKey,
+    readonly bump: number,
+    readonly guardians: web3.PublicKey[],
+    readonly guardianThreshold: number
+  ) {}
+
+  /**
+   * Creates a {@link VaultVetoCouncil} instance from the provided args.
+   */
+  static fromArgs(args: VaultVetoCouncilArgs) {
+    return new VaultVetoCouncil(
+      args.vault,
+      args.bump,
+      args.guardians,
+      args.guardianThreshold
+    )
+  }
+
+  /**
+   * Deserializes the {@link VaultVetoCouncil} from the data of the provided {@link web3.AccountInfo}.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static fromAccountInfo(
+    accountInfo: web3.AccountInfo<Buffer>,
+    offset = 0
+  ): [VaultVetoCouncil, number] {
+    return VaultVetoCouncil.deserialize(accountInfo.data, offset)
+  }
+
+  /**
+   * Retrieves the account info from the provided address and deserializes
+   * the {@link VaultVetoCouncil} from its data.
+   *
+   * @throws Error if no account info is found at the address or if deserialization fails
+   */
+  static async fromAccountAddress(
+    connection: web3.Connection,
+    address: web3.PublicKey,
+    commitmentOrConfig?: web3.Commitment | web3.GetAccountInfoConfig
+  ): Promise<VaultVetoCouncil> {
+    const accountInfo = await connection.getAccountInfo(
+      address,
+      commitmentOrConfig
+    )
+    if (accountInfo == null) {
+      throw new Error(`Unable to find VaultVetoCouncil account at ${address}`)
+    }
+    return VaultVetoCouncil.fromAccountInfo(accountInfo, 0)[0]
+  }
+
+  /**
+   * Provides a {@link web3.Connection.getProgramAccounts} config builder,
+   * to fetch accounts matching filters that can be specified via that builder.
+   *
+   * @param programId - the program that owns the accounts we are filtering
+   */
+  static gpaBuilder(
+    programId: web3.PublicKey = new web3.PublicKey(
+      'GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A'
+    )
+  ) {
+    return beetSolana.GpaBuilder.fromStruct(programId, vaultVetoCouncilBeet)
+  }
+
+  /**
+   * Deserializes the {@link VaultVetoCouncil} from the provided data Buffer.
+   * @returns a tuple of the account data and the offset up to which the buffer was read to obtain it.
+   */
+  static deserialize(buf: Buffer, offset = 0): [VaultVetoCouncil, number] {
+    return vaultVetoCouncilBeet.deserialize(buf, offset)
+  }
+
+  /**
+   * Serializes the {@link VaultVetoCouncil} into a Buffer.
+   * @returns a tuple of the created Buffer and the offset up to which the buffer was written to store it.
+   */
+  serialize(): [Buffer, number] {
+    return vaultVetoCouncilBeet.serialize({
+      accountDiscriminator: vaultVetoCouncilDiscriminator,
+      ...this,
+    })
+  }
+
+  /**
+   * Returns the byteSize of a {@link Buffer} holding the serialized data of
+   * {@link VaultVetoCouncil} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
+   */
+  static byteSize(args: VaultVetoCouncilArgs) {
+    const instance = VaultVetoCouncil.fromArgs(args)
+    return vaultVetoCouncilBeet.toFixedFromValue({
+      accountDiscriminator: vaultVetoCouncilDiscriminator,
+      ...instance,
+    }).byteSize
+  }
+
+  /**
+   * Fetches the minimum balance needed to exempt an account holding
+   * {@link VaultVetoCouncil} data from rent
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
+   * @param connection used to retrieve the rent exemption information
+   */
+  static async getMinimumBalanceForRentExemption(
+    args: VaultVetoCouncilArgs,
+    connection: web3.Connection,
+    commitment?: web3.Commitment
+  ): Promise<number> {
+    return connection.getMinimumBalanceForRentExemption(
+      VaultVetoCouncil.byteSize(args),
+      commitment
+    )
+  }
+
+  /**
+   * Returns a readable version of {@link VaultVetoCouncil} properties
+   * and can be used to convert to JSON and/or logging
+   */
+  pretty() {
+    return {
+      vault: this.vault.toBase58(),
+      bump: this.bump,
+      guardians: this.guardians,
+      guardianThreshold: this.guardianThreshold,
+    }
+  }
+}
+
+/**
+ * @category Accounts
+ * @category generated
+ */
+export const vaultVetoCouncilBeet = new beet.FixableBeetStruct<
+  VaultVetoCouncil,
+  VaultVetoCouncilArgs & {
+    accountDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['vault', beetSolana.publicKey],
+    ['bump', beet.u8],
+    ['guardians', beet.array(beetSolana.publicKey)],
+    ['guardianThreshold', beet.u16],
+  ],
+  VaultVetoCouncil.fromArgs,
+  'VaultVetoCouncil'
+)
@@ -5,18 +5,48 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 export * from './Vault'
+export * from './VaultBatch'
+export * from './VaultBatchTransaction'
+export * from './VaultCommittee'
+export * from './VaultCommitteeTransaction'
 export * from './VaultConfig'
 export * from './VaultFounderTransaction'
+export * from './VaultInvitation'
+export * from './VaultMemberRateLimit'
 export * from './VaultMemberTransaction'
+export * from './VaultMetadata'
+export * from './VaultRecovery'
+export * from './VaultTransactionBuffer'
+export * from './VaultVetoCouncil'
 
+import { VaultBatch } from './VaultBatch'
+import { VaultBatchTransaction } from './VaultBatchTransaction'
+import { VaultCommitteeTransaction } from './VaultCommitteeTransaction'
+import { VaultCommittee } from './VaultCommittee'
 import { VaultFounderTransaction } from './VaultFounderTransaction'
+import { VaultInvitation } from './VaultInvitation'
+import { VaultMemberRateLimit } from './VaultMemberRateLimit'
 import { VaultMemberTransaction } from './VaultMemberTransaction'
+import { VaultRecovery } from './VaultRecovery'
+import { VaultTransactionBuffer } from './VaultTransactionBuffer'
 import { VaultConfig } from './VaultConfig'
+import { VaultMetadata } from './VaultMetadata'
 import { Vault } from './Vault'
+import { VaultVetoCouncil } from './VaultVetoCouncil'
 
 export const accountProviders = {
+  VaultBatch,
+  VaultBatchTransaction,
+  VaultCommitteeTransaction,
+  VaultCommittee,
   VaultFounderTransaction,
+  VaultInvitation,
+  VaultMemberRateLimit,
   VaultMemberTransaction,
+  VaultRecovery,
+  VaultTransactionBuffer,
   VaultConfig,
+  VaultMetadata,
   Vault,
+  VaultVetoCouncil,
 }
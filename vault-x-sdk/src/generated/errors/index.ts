@@ -513,6 +513,800 @@ createErrorFromNameLookup.set(
   () => new AdminCannotBeMemberError()
 )
 
+/**
+ * InvalidMetadata: 'Vault metadata exceeds the allowed length'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidMetadataError extends Error {
+  readonly code: number = 0x1786
+  readonly name: string = 'InvalidMetadata'
+  constructor() {
+    super('Vault metadata exceeds the allowed length')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidMetadataError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1786, () => new InvalidMetadataError())
+createErrorFromNameLookup.set(
+  'InvalidMetadata',
+  () => new InvalidMetadataError()
+)
+
+/**
+ * InvalidCommitteeThreshold: 'Invalid committee threshold'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidCommitteeThresholdError extends Error {
+  readonly code: number = 0x1787
+  readonly name: string = 'InvalidCommitteeThreshold'
+  constructor() {
+    super('Invalid committee threshold')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidCommitteeThresholdError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x1787,
+  () => new InvalidCommitteeThresholdError()
+)
+createErrorFromNameLookup.set(
+  'InvalidCommitteeThreshold',
+  () => new InvalidCommitteeThresholdError()
+)
+
+/**
+ * CommitteeMemberNotFound: 'Committee member does not exist'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CommitteeMemberNotFoundError extends Error {
+  readonly code: number = 0x1788
+  readonly name: string = 'CommitteeMemberNotFound'
+  constructor() {
+    super('Committee member does not exist')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CommitteeMemberNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1788, () => new CommitteeMemberNotFoundError())
+createErrorFromNameLookup.set(
+  'CommitteeMemberNotFound',
+  () => new CommitteeMemberNotFoundError()
+)
+
+/**
+ * InvalidFounderWeight: 'Invalid founder vote weight'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidFounderWeightError extends Error {
+  readonly code: number = 0x1789
+  readonly name: string = 'InvalidFounderWeight'
+  constructor() {
+    super('Invalid founder vote weight')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidFounderWeightError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1789, () => new InvalidFounderWeightError())
+createErrorFromNameLookup.set(
+  'InvalidFounderWeight',
+  () => new InvalidFounderWeightError()
+)
+
+/**
+ * TimeLockNotElapsed: 'Time lock has not elapsed since the transaction was approved'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TimeLockNotElapsedError extends Error {
+  readonly code: number = 0x178a
+  readonly name: string = 'TimeLockNotElapsed'
+  constructor() {
+    super('Time lock has not elapsed since the transaction was approved')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TimeLockNotElapsedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178a, () => new TimeLockNotElapsedError())
+createErrorFromNameLookup.set(
+  'TimeLockNotElapsed',
+  () => new TimeLockNotElapsedError()
+)
+
+/**
+ * InvalidTransactionExpiry: 'Transaction expiry is in the past or exceeds the vault's maximum'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTransactionExpiryError extends Error {
+  readonly code: number = 0x178b
+  readonly name: string = 'InvalidTransactionExpiry'
+  constructor() {
+    super("Transaction expiry is in the past or exceeds the vault's maximum")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTransactionExpiryError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178b, () => new InvalidTransactionExpiryError())
+createErrorFromNameLookup.set(
+  'InvalidTransactionExpiry',
+  () => new InvalidTransactionExpiryError()
+)
+
+/**
+ * InvitationExpired: 'Invitation has expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvitationExpiredError extends Error {
+  readonly code: number = 0x178c
+  readonly name: string = 'InvitationExpired'
+  constructor() {
+    super('Invitation has expired')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvitationExpiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178c, () => new InvitationExpiredError())
+createErrorFromNameLookup.set(
+  'InvitationExpired',
+  () => new InvitationExpiredError()
+)
+
+/**
+ * InvalidGuardianThreshold: 'Invalid guardian threshold'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidGuardianThresholdError extends Error {
+  readonly code: number = 0x178d
+  readonly name: string = 'InvalidGuardianThreshold'
+  constructor() {
+    super('Invalid guardian threshold')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidGuardianThresholdError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178d, () => new InvalidGuardianThresholdError())
+createErrorFromNameLookup.set(
+  'InvalidGuardianThreshold',
+  () => new InvalidGuardianThresholdError()
+)
+
+/**
+ * InvalidInactivityPeriod: 'Invalid inactivity period'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidInactivityPeriodError extends Error {
+  readonly code: number = 0x178e
+  readonly name: string = 'InvalidInactivityPeriod'
+  constructor() {
+    super('Invalid inactivity period')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidInactivityPeriodError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178e, () => new InvalidInactivityPeriodError())
+createErrorFromNameLookup.set(
+  'InvalidInactivityPeriod',
+  () => new InvalidInactivityPeriodError()
+)
+
+/**
+ * GuardianNotFound: 'Guardian does not exist'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class GuardianNotFoundError extends Error {
+  readonly code: number = 0x178f
+  readonly name: string = 'GuardianNotFound'
+  constructor() {
+    super('Guardian does not exist')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, GuardianNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178f, () => new GuardianNotFoundError())
+createErrorFromNameLookup.set(
+  'GuardianNotFound',
+  () => new GuardianNotFoundError()
+)
+
+/**
+ * FoundersStillActive: 'Vault founders have not been inactive for long enough'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FoundersStillActiveError extends Error {
+  readonly code: number = 0x1790
+  readonly name: string = 'FoundersStillActive'
+  constructor() {
+    super('Vault founders have not been inactive for long enough')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FoundersStillActiveError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1790, () => new FoundersStillActiveError())
+createErrorFromNameLookup.set(
+  'FoundersStillActive',
+  () => new FoundersStillActiveError()
+)
+
+/**
+ * RecoveryAlreadyProposed: 'A recovery is already pending'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RecoveryAlreadyProposedError extends Error {
+  readonly code: number = 0x1791
+  readonly name: string = 'RecoveryAlreadyProposed'
+  constructor() {
+    super('A recovery is already pending')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RecoveryAlreadyProposedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1791, () => new RecoveryAlreadyProposedError())
+createErrorFromNameLookup.set(
+  'RecoveryAlreadyProposed',
+  () => new RecoveryAlreadyProposedError()
+)
+
+/**
+ * RecoveryNotProposed: 'No recovery is pending'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RecoveryNotProposedError extends Error {
+  readonly code: number = 0x1792
+  readonly name: string = 'RecoveryNotProposed'
+  constructor() {
+    super('No recovery is pending')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RecoveryNotProposedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1792, () => new RecoveryNotProposedError())
+createErrorFromNameLookup.set(
+  'RecoveryNotProposed',
+  () => new RecoveryNotProposedError()
+)
+
+/**
+ * RecoveryAlreadyApproved: 'Guardian has already approved the recovery'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RecoveryAlreadyApprovedError extends Error {
+  readonly code: number = 0x1793
+  readonly name: string = 'RecoveryAlreadyApproved'
+  constructor() {
+    super('Guardian has already approved the recovery')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RecoveryAlreadyApprovedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1793, () => new RecoveryAlreadyApprovedError())
+createErrorFromNameLookup.set(
+  'RecoveryAlreadyApproved',
+  () => new RecoveryAlreadyApprovedError()
+)
+
+/**
+ * RecoveryNotApproved: 'Recovery has not reached the guardian threshold'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RecoveryNotApprovedError extends Error {
+  readonly code: number = 0x1794
+  readonly name: string = 'RecoveryNotApproved'
+  constructor() {
+    super('Recovery has not reached the guardian threshold')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RecoveryNotApprovedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1794, () => new RecoveryNotApprovedError())
+createErrorFromNameLookup.set(
+  'RecoveryNotApproved',
+  () => new RecoveryNotApprovedError()
+)
+
+/**
+ * VetoWindowElapsed: 'Time lock has elapsed, the transaction can no longer be vetoed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VetoWindowElapsedError extends Error {
+  readonly code: number = 0x1795
+  readonly name: string = 'VetoWindowElapsed'
+  constructor() {
+    super('Time lock has elapsed, the transaction can no longer be vetoed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VetoWindowElapsedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1795, () => new VetoWindowElapsedError())
+createErrorFromNameLookup.set(
+  'VetoWindowElapsed',
+  () => new VetoWindowElapsedError()
+)
+
+/**
+ * VetoThresholdNotReached: 'Not enough guardians signed the veto'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VetoThresholdNotReachedError extends Error {
+  readonly code: number = 0x1796
+  readonly name: string = 'VetoThresholdNotReached'
+  constructor() {
+    super('Not enough guardians signed the veto')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VetoThresholdNotReachedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1796, () => new VetoThresholdNotReachedError())
+createErrorFromNameLookup.set(
+  'VetoThresholdNotReached',
+  () => new VetoThresholdNotReachedError()
+)
+
+/**
+ * VetoCouncilReplacement: 'Transactions replacing the veto council can't be vetoed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VetoCouncilReplacementError extends Error {
+  readonly code: number = 0x1797
+  readonly name: string = 'VetoCouncilReplacement'
+  constructor() {
+    super("Transactions replacing the veto council can't be vetoed")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VetoCouncilReplacementError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1797, () => new VetoCouncilReplacementError())
+createErrorFromNameLookup.set(
+  'VetoCouncilReplacement',
+  () => new VetoCouncilReplacementError()
+)
+
+/**
+ * InvalidMemberThreshold: 'Invalid member threshold'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidMemberThresholdError extends Error {
+  readonly code: number = 0x1798
+  readonly name: string = 'InvalidMemberThreshold'
+  constructor() {
+    super('Invalid member threshold')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidMemberThresholdError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1798, () => new InvalidMemberThresholdError())
+createErrorFromNameLookup.set(
+  'InvalidMemberThreshold',
+  () => new InvalidMemberThresholdError()
+)
+
+/**
+ * MemberVotingDisabled: 'Member voting is disabled for this vault'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MemberVotingDisabledError extends Error {
+  readonly code: number = 0x1799
+  readonly name: string = 'MemberVotingDisabled'
+  constructor() {
+    super('Member voting is disabled for this vault')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MemberVotingDisabledError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1799, () => new MemberVotingDisabledError())
+createErrorFromNameLookup.set(
+  'MemberVotingDisabled',
+  () => new MemberVotingDisabledError()
+)
+
+/**
+ * CooldownNotElapsed: 'Cooldown has not elapsed since the transaction was last executed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CooldownNotElapsedError extends Error {
+  readonly code: number = 0x179a
+  readonly name: string = 'CooldownNotElapsed'
+  constructor() {
+    super('Cooldown has not elapsed since the transaction was last executed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CooldownNotElapsedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179a, () => new CooldownNotElapsedError())
+createErrorFromNameLookup.set(
+  'CooldownNotElapsed',
+  () => new CooldownNotElapsedError()
+)
+
+/**
+ * MemberVotingEnabled: 'Member voting is enabled, member transactions must be approved'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MemberVotingEnabledError extends Error {
+  readonly code: number = 0x179b
+  readonly name: string = 'MemberVotingEnabled'
+  constructor() {
+    super('Member voting is enabled, member transactions must be approved')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MemberVotingEnabledError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179b, () => new MemberVotingEnabledError())
+createErrorFromNameLookup.set(
+  'MemberVotingEnabled',
+  () => new MemberVotingEnabledError()
+)
+
+/**
+ * InvalidRateLimit: 'Invalid rate limit'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRateLimitError extends Error {
+  readonly code: number = 0x179c
+  readonly name: string = 'InvalidRateLimit'
+  constructor() {
+    super('Invalid rate limit')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRateLimitError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179c, () => new InvalidRateLimitError())
+createErrorFromNameLookup.set(
+  'InvalidRateLimit',
+  () => new InvalidRateLimitError()
+)
+
+/**
+ * RateLimitExceeded: 'Member has reached its transaction rate limit'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RateLimitExceededError extends Error {
+  readonly code: number = 0x179d
+  readonly name: string = 'RateLimitExceeded'
+  constructor() {
+    super('Member has reached its transaction rate limit')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RateLimitExceededError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new RateLimitExceededError())
+createErrorFromNameLookup.set(
+  'RateLimitExceeded',
+  () => new RateLimitExceededError()
+)
+
+/**
+ * EmptyBatch: 'Batch has no transactions'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EmptyBatchError extends Error {
+  readonly code: number = 0x179e
+  readonly name: string = 'EmptyBatch'
+  constructor() {
+    super('Batch has no transactions')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EmptyBatchError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new EmptyBatchError())
+createErrorFromNameLookup.set('EmptyBatch', () => new EmptyBatchError())
+
+/**
+ * InvalidTransactionBufferSize: 'Invalid transaction buffer size'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTransactionBufferSizeError extends Error {
+  readonly code: number = 0x179f
+  readonly name: string = 'InvalidTransactionBufferSize'
+  constructor() {
+    super('Invalid transaction buffer size')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTransactionBufferSizeError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x179f,
+  () => new InvalidTransactionBufferSizeError()
+)
+createErrorFromNameLookup.set(
+  'InvalidTransactionBufferSize',
+  () => new InvalidTransactionBufferSizeError()
+)
+
+/**
+ * InvalidTransactionBufferHash: 'Transaction buffer does not match its hash'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTransactionBufferHashError extends Error {
+  readonly code: number = 0x17a0
+  readonly name: string = 'InvalidTransactionBufferHash'
+  constructor() {
+    super('Transaction buffer does not match its hash')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTransactionBufferHashError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a0,
+  () => new InvalidTransactionBufferHashError()
+)
+createErrorFromNameLookup.set(
+  'InvalidTransactionBufferHash',
+  () => new InvalidTransactionBufferHashError()
+)
+
+/**
+ * InvalidAddressLookupTable: 'Address lookup table does not match the transaction message'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidAddressLookupTableError extends Error {
+  readonly code: number = 0x17a1
+  readonly name: string = 'InvalidAddressLookupTable'
+  constructor() {
+    super('Address lookup table does not match the transaction message')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidAddressLookupTableError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a1,
+  () => new InvalidAddressLookupTableError()
+)
+createErrorFromNameLookup.set(
+  'InvalidAddressLookupTable',
+  () => new InvalidAddressLookupTableError()
+)
+
+/**
+ * InvalidInstructionCount: 'Invalid number of instructions to execute'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidInstructionCountError extends Error {
+  readonly code: number = 0x17a2
+  readonly name: string = 'InvalidInstructionCount'
+  constructor() {
+    super('Invalid number of instructions to execute')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidInstructionCountError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a2, () => new InvalidInstructionCountError())
+createErrorFromNameLookup.set(
+  'InvalidInstructionCount',
+  () => new InvalidInstructionCountError()
+)
+
+/**
+ * FundIntegrityViolated: 'Execution changed the owner of the fund or of its token accounts'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FundIntegrityViolatedError extends Error {
+  readonly code: number = 0x17a3
+  readonly name: string = 'FundIntegrityViolated'
+  constructor() {
+    super('Execution changed the owner of the fund or of its token accounts')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FundIntegrityViolatedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a3, () => new FundIntegrityViolatedError())
+createErrorFromNameLookup.set(
+  'FundIntegrityViolated',
+  () => new FundIntegrityViolatedError()
+)
+
+/**
+ * VaultNotMigrated: 'Vault uses a legacy layout and must be migrated'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VaultNotMigratedError extends Error {
+  readonly code: number = 0x17a4
+  readonly name: string = 'VaultNotMigrated'
+  constructor() {
+    super('Vault uses a legacy layout and must be migrated')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VaultNotMigratedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a4, () => new VaultNotMigratedError())
+createErrorFromNameLookup.set(
+  'VaultNotMigrated',
+  () => new VaultNotMigratedError()
+)
+
+/**
+ * VaultAlreadyMigrated: 'Vault already uses the current layout'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VaultAlreadyMigratedError extends Error {
+  readonly code: number = 0x17a5
+  readonly name: string = 'VaultAlreadyMigrated'
+  constructor() {
+    super('Vault already uses the current layout')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VaultAlreadyMigratedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a5, () => new VaultAlreadyMigratedError())
+createErrorFromNameLookup.set(
+  'VaultAlreadyMigrated',
+  () => new VaultAlreadyMigratedError()
+)
+
+/**
+ * InvalidTransactionAccount: 'Account is not a founder or member transaction'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTransactionAccountError extends Error {
+  readonly code: number = 0x17a6
+  readonly name: string = 'InvalidTransactionAccount'
+  constructor() {
+    super('Account is not a founder or member transaction')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTransactionAccountError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a6,
+  () => new InvalidTransactionAccountError()
+)
+createErrorFromNameLookup.set(
+  'InvalidTransactionAccount',
+  () => new InvalidTransactionAccountError()
+)
+
+/**
+ * TransactionNotLegacy: 'Transaction does not use the legacy layout'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransactionNotLegacyError extends Error {
+  readonly code: number = 0x17a7
+  readonly name: string = 'TransactionNotLegacy'
+  constructor() {
+    super('Transaction does not use the legacy layout')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransactionNotLegacyError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a7, () => new TransactionNotLegacyError())
+createErrorFromNameLookup.set(
+  'TransactionNotLegacy',
+  () => new TransactionNotLegacyError()
+)
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
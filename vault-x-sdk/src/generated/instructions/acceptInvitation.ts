@@ -0,0 +1,115 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category AcceptInvitation
+ * @category generated
+ */
+export const acceptInvitationStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'AcceptInvitationInstructionArgs'
+)
+/**
+ * Accounts required by the _acceptInvitation_ instruction
+ *
+ * @property [_writable_] invitation
+ * @property [_writable_] vault
+ * @property [_writable_, **signer**] invitee
+ * @property [_writable_] inviter
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category AcceptInvitation
+ * @category generated
+ */
+export type AcceptInvitationInstructionAccounts = {
+  invitation: web3.PublicKey
+  vault: web3.PublicKey
+  invitee: web3.PublicKey
+  inviter: web3.PublicKey
+  systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const acceptInvitationInstructionDiscriminator = [
+  114, 70, 62, 248, 204, 49, 98, 239,
+]
+
+/**
+ * Creates a _AcceptInvitation_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category AcceptInvitation
+ * @category generated
+ */
+export function createAcceptInvitationInstruction(
+  accounts: AcceptInvitationInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = acceptInvitationStruct.serialize({
+    instructionDiscriminator: acceptInvitationInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.invitation,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.invitee,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.inviter,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
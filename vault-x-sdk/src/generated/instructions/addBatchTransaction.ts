@@ -0,0 +1,136 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultAddBatchTransactionArgs,
+  vaultAddBatchTransactionArgsBeet,
+} from '../types/VaultAddBatchTransactionArgs'
+
+/**
+ * @category Instructions
+ * @category AddBatchTransaction
+ * @category generated
+ */
+export type AddBatchTransactionInstructionArgs = {
+  args: VaultAddBatchTransactionArgs
+}
+/**
+ * @category Instructions
+ * @category AddBatchTransaction
+ * @category generated
+ */
+export const addBatchTransactionStruct = new beet.FixableBeetArgsStruct<
+  AddBatchTransactionInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultAddBatchTransactionArgsBeet],
+  ],
+  'AddBatchTransactionInstructionArgs'
+)
+/**
+ * Accounts required by the _addBatchTransaction_ instruction
+ *
+ * @property [_writable_] batch
+ * @property [_writable_] transaction
+ * @property [] vault
+ * @property [_writable_, **signer**] creator
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category AddBatchTransaction
+ * @category generated
+ */
+export type AddBatchTransactionInstructionAccounts = {
+  batch: web3.PublicKey
+  transaction: web3.PublicKey
+  vault: web3.PublicKey
+  creator: web3.PublicKey
+  systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const addBatchTransactionInstructionDiscriminator = [
+  220, 95, 163, 196, 211, 26, 55, 236,
+]
+
+/**
+ * Creates a _AddBatchTransaction_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category AddBatchTransaction
+ * @category generated
+ */
+export function createAddBatchTransactionInstruction(
+  accounts: AddBatchTransactionInstructionAccounts,
+  args: AddBatchTransactionInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = addBatchTransactionStruct.serialize({
+    instructionDiscriminator: addBatchTransactionInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.batch,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.transaction,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.creator,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
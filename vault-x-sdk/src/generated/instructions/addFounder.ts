@@ -25,7 +25,7 @@ export type AddFounderInstructionArgs = {
  * @category AddFounder
  * @category generated
  */
-export const addFounderStruct = new beet.BeetArgsStruct<
+export const addFounderStruct = new beet.FixableBeetArgsStruct<
   AddFounderInstructionArgs & {
     instructionDiscriminator: number[] /* size: 8 */
   }
@@ -42,6 +42,8 @@ export const addFounderStruct = new beet.BeetArgsStruct<
  * @property [_writable_] vault
  * @property [**signer**] administrator
  * @property [_writable_, **signer**] rentPayer (optional)
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category AddFounder
  * @category generated
@@ -51,6 +53,8 @@ export type AddFounderInstructionAccounts = {
   administrator: web3.PublicKey
   rentPayer?: web3.PublicKey
   systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -101,6 +105,16 @@ export function createAddFounderInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
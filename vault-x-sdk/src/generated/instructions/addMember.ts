@@ -42,6 +42,8 @@ export const addMemberStruct = new beet.BeetArgsStruct<
  * @property [_writable_] vault
  * @property [**signer**] founder
  * @property [_writable_, **signer**] rentPayer (optional)
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category AddMember
  * @category generated
@@ -51,6 +53,8 @@ export type AddMemberInstructionAccounts = {
   founder: web3.PublicKey
   rentPayer?: web3.PublicKey
   systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -101,6 +105,16 @@ export function createAddMemberInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -0,0 +1,102 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category ApproveBatch
+ * @category generated
+ */
+export const approveBatchStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'ApproveBatchInstructionArgs'
+)
+/**
+ * Accounts required by the _approveBatch_ instruction
+ *
+ * @property [_writable_] batch
+ * @property [_writable_] vault
+ * @property [_writable_, **signer**] founder
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category ApproveBatch
+ * @category generated
+ */
+export type ApproveBatchInstructionAccounts = {
+  batch: web3.PublicKey
+  vault: web3.PublicKey
+  founder: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const approveBatchInstructionDiscriminator = [
+  177, 112, 123, 143, 22, 175, 196, 136,
+]
+
+/**
+ * Creates a _ApproveBatch_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category ApproveBatch
+ * @category generated
+ */
+export function createApproveBatchInstruction(
+  accounts: ApproveBatchInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = approveBatchStruct.serialize({
+    instructionDiscriminator: approveBatchInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.batch,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.founder,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
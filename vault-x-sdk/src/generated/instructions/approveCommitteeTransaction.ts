@@ -0,0 +1,110 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category ApproveCommitteeTransaction
+ * @category generated
+ */
+export const approveCommitteeTransactionStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'ApproveCommitteeTransactionInstructionArgs'
+)
+/**
+ * Accounts required by the _approveCommitteeTransaction_ instruction
+ *
+ * @property [_writable_] transaction
+ * @property [] committee
+ * @property [] vault
+ * @property [_writable_, **signer**] member
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category ApproveCommitteeTransaction
+ * @category generated
+ */
+export type ApproveCommitteeTransactionInstructionAccounts = {
+  transaction: web3.PublicKey
+  committee: web3.PublicKey
+  vault: web3.PublicKey
+  member: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const approveCommitteeTransactionInstructionDiscriminator = [
+  199, 27, 126, 179, 23, 117, 205, 246,
+]
+
+/**
+ * Creates a _ApproveCommitteeTransaction_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category ApproveCommitteeTransaction
+ * @category generated
+ */
+export function createApproveCommitteeTransactionInstruction(
+  accounts: ApproveCommitteeTransactionInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = approveCommitteeTransactionStruct.serialize({
+    instructionDiscriminator:
+      approveCommitteeTransactionInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.transaction,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.committee,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.member,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
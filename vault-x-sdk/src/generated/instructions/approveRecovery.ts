@@ -0,0 +1,102 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category ApproveRecovery
+ * @category generated
+ */
+export const approveRecoveryStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'ApproveRecoveryInstructionArgs'
+)
+/**
+ * Accounts required by the _approveRecovery_ instruction
+ *
+ * @property [] vault
+ * @property [_writable_] vaultRecovery
+ * @property [**signer**] guardian
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category ApproveRecovery
+ * @category generated
+ */
+export type ApproveRecoveryInstructionAccounts = {
+  vault: web3.PublicKey
+  vaultRecovery: web3.PublicKey
+  guardian: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const approveRecoveryInstructionDiscriminator = [
+  148, 96, 41, 38, 108, 189, 129, 214,
+]
+
+/**
+ * Creates a _ApproveRecovery_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category ApproveRecovery
+ * @category generated
+ */
+export function createApproveRecoveryInstruction(
+  accounts: ApproveRecoveryInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = approveRecoveryStruct.serialize({
+    instructionDiscriminator: approveRecoveryInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vaultRecovery,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.guardian,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
@@ -0,0 +1,102 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category CancelBatch
+ * @category generated
+ */
+export const cancelBatchStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'CancelBatchInstructionArgs'
+)
+/**
+ * Accounts required by the _cancelBatch_ instruction
+ *
+ * @property [_writable_] batch
+ * @property [_writable_] vault
+ * @property [_writable_, **signer**] founder
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category CancelBatch
+ * @category generated
+ */
+export type CancelBatchInstructionAccounts = {
+  batch: web3.PublicKey
+  vault: web3.PublicKey
+  founder: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const cancelBatchInstructionDiscriminator = [
+  234, 119, 48, 250, 255, 221, 77, 45,
+]
+
+/**
+ * Creates a _CancelBatch_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category CancelBatch
+ * @category generated
+ */
+export function createCancelBatchInstruction(
+  accounts: CancelBatchInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = cancelBatchStruct.serialize({
+    instructionDiscriminator: cancelBatchInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.batch,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.founder,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
@@ -0,0 +1,102 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category CloseCommitteeTransaction
+ * @category generated
+ */
+export const closeCommitteeTransactionStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'CloseCommitteeTransactionInstructionArgs'
+)
+/**
+ * Accounts required by the _closeCommitteeTransaction_ instruction
+ *
+ * @property [_writable_] transaction
+ * @property [] committee
+ * @property [_writable_] creator
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category CloseCommitteeTransaction
+ * @category generated
+ */
+export type CloseCommitteeTransactionInstructionAccounts = {
+  transaction: web3.PublicKey
+  committee: web3.PublicKey
+  creator: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const closeCommitteeTransactionInstructionDiscriminator = [
+  90, 236, 47, 98, 173, 18, 206, 216,
+]
+
+/**
+ * Creates a _CloseCommitteeTransaction_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category CloseCommitteeTransaction
+ * @category generated
+ */
+export function createCloseCommitteeTransactionInstruction(
+  accounts: CloseCommitteeTransactionInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = closeCommitteeTransactionStruct.serialize({
+    instructionDiscriminator: closeCommitteeTransactionInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.transaction,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.committee,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.creator,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
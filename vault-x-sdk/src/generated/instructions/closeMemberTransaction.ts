@@ -0,0 +1,109 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category CloseMemberTransaction
+ * @category generated
+ */
+export const closeMemberTransactionStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'CloseMemberTransactionInstructionArgs'
+)
+/**
+ * Accounts required by the _closeMemberTransaction_ instruction
+ *
+ * @property [_writable_] transaction
+ * @property [] vault
+ * @property [_writable_] creator
+ * @property [**signer**] closer
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category CloseMemberTransaction
+ * @category generated
+ */
+export type CloseMemberTransactionInstructionAccounts = {
+  transaction: web3.PublicKey
+  vault: web3.PublicKey
+  creator: web3.PublicKey
+  closer: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const closeMemberTransactionInstructionDiscriminator = [
+  94, 22, 183, 175, 126, 194, 137, 65,
+]
+
+/**
+ * Creates a _CloseMemberTransaction_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category CloseMemberTransaction
+ * @category generated
+ */
+export function createCloseMemberTransactionInstruction(
+  accounts: CloseMemberTransactionInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = closeMemberTransactionStruct.serialize({
+    instructionDiscriminator: closeMemberTransactionInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.transaction,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.creator,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.closer,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
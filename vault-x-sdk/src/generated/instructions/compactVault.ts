@@ -0,0 +1,95 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category CompactVault
+ * @category generated
+ */
+export const compactVaultStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'CompactVaultInstructionArgs'
+)
+/**
+ * Accounts required by the _compactVault_ instruction
+ *
+ * @property [_writable_] vault
+ * @property [_writable_] rentCollector
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category CompactVault
+ * @category generated
+ */
+export type CompactVaultInstructionAccounts = {
+  vault: web3.PublicKey
+  rentCollector: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const compactVaultInstructionDiscriminator = [
+  220, 28, 229, 250, 117, 88, 177, 44,
+]
+
+/**
+ * Creates a _CompactVault_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category CompactVault
+ * @category generated
+ */
+export function createCompactVaultInstruction(
+  accounts: CompactVaultInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = compactVaultStruct.serialize({
+    instructionDiscriminator: compactVaultInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.rentCollector,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
@@ -0,0 +1,129 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultCreateCommitteeArgs,
+  vaultCreateCommitteeArgsBeet,
+} from '../types/VaultCreateCommitteeArgs'
+
+/**
+ * @category Instructions
+ * @category CreateCommittee
+ * @category generated
+ */
+export type CreateCommitteeInstructionArgs = {
+  args: VaultCreateCommitteeArgs
+}
+/**
+ * @category Instructions
+ * @category CreateCommittee
+ * @category generated
+ */
+export const createCommitteeStruct = new beet.FixableBeetArgsStruct<
+  CreateCommitteeInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultCreateCommitteeArgsBeet],
+  ],
+  'CreateCommitteeInstructionArgs'
+)
+/**
+ * Accounts required by the _createCommittee_ instruction
+ *
+ * @property [_writable_] committee
+ * @property [_writable_] vault
+ * @property [_writable_, **signer**] founder
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category CreateCommittee
+ * @category generated
+ */
+export type CreateCommitteeInstructionAccounts = {
+  committee: web3.PublicKey
+  vault: web3.PublicKey
+  founder: web3.PublicKey
+  systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const createCommitteeInstructionDiscriminator = [
+  216, 137, 130, 24, 113, 11, 204, 15,
+]
+
+/**
+ * Creates a _CreateCommittee_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category CreateCommittee
+ * @category generated
+ */
+export function createCreateCommitteeInstruction(
+  accounts: CreateCommitteeInstructionAccounts,
+  args: CreateCommitteeInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = createCommitteeStruct.serialize({
+    instructionDiscriminator: createCommitteeInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.committee,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.founder,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
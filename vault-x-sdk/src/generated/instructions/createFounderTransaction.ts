@@ -42,6 +42,9 @@ export const createFounderTransactionStruct = new beet.FixableBeetArgsStruct<
  * @property [_writable_] transaction
  * @property [_writable_] vault
  * @property [_writable_, **signer**] creator
+ * @property [_writable_] transactionBuffer (optional)
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category CreateFounderTransaction
  * @category generated
@@ -50,7 +53,10 @@ export type CreateFounderTransactionInstructionAccounts = {
   transaction: web3.PublicKey
   vault: web3.PublicKey
   creator: web3.PublicKey
+  transactionBuffer?: web3.PublicKey
   systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -61,6 +67,9 @@ export const createFounderTransactionInstructionDiscriminator = [
 /**
  * Creates a _CreateFounderTransaction_ instruction.
  *
+ * Optional accounts that are not provided default to the program ID since
+ * this was indicated in the IDL from which this instruction was generated.
+ *
  * @param accounts that will be accessed while the instruction is processed
  * @param args to provide as instruction data to the program
  *
@@ -93,11 +102,26 @@ export function createCreateFounderTransactionInstruction(
       isWritable: true,
       isSigner: true,
     },
+    {
+      pubkey: accounts.transactionBuffer ?? programId,
+      isWritable: accounts.transactionBuffer != null,
+      isSigner: false,
+    },
     {
       pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
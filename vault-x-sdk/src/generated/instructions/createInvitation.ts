@@ -0,0 +1,129 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultCreateInvitationArgs,
+  vaultCreateInvitationArgsBeet,
+} from '../types/VaultCreateInvitationArgs'
+
+/**
+ * @category Instructions
+ * @category CreateInvitation
+ * @category generated
+ */
+export type CreateInvitationInstructionArgs = {
+  args: VaultCreateInvitationArgs
+}
+/**
+ * @category Instructions
+ * @category CreateInvitation
+ * @category generated
+ */
+export const createInvitationStruct = new beet.FixableBeetArgsStruct<
+  CreateInvitationInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultCreateInvitationArgsBeet],
+  ],
+  'CreateInvitationInstructionArgs'
+)
+/**
+ * Accounts required by the _createInvitation_ instruction
+ *
+ * @property [_writable_] invitation
+ * @property [] vault
+ * @property [_writable_, **signer**] inviter
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category CreateInvitation
+ * @category generated
+ */
+export type CreateInvitationInstructionAccounts = {
+  invitation: web3.PublicKey
+  vault: web3.PublicKey
+  inviter: web3.PublicKey
+  systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const createInvitationInstructionDiscriminator = [
+  52, 19, 21, 13, 54, 156, 27, 172,
+]
+
+/**
+ * Creates a _CreateInvitation_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category CreateInvitation
+ * @category generated
+ */
+export function createCreateInvitationInstruction(
+  accounts: CreateInvitationInstructionAccounts,
+  args: CreateInvitationInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = createInvitationStruct.serialize({
+    instructionDiscriminator: createInvitationInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.invitation,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.inviter,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
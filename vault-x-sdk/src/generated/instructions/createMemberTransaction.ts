@@ -42,8 +42,12 @@ export const createMemberTransactionStruct = new beet.FixableBeetArgsStruct<
  * @property [_writable_] transaction
  * @property [_writable_] vault
  * @property [_writable_, **signer**] creator
+ * @property [_writable_] rateLimit
+ * @property [_writable_] transactionBuffer (optional)
  * @property [] merkleTree
  * @property [] accountCompressionProgram
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category CreateMemberTransaction
  * @category generated
@@ -52,9 +56,13 @@ export type CreateMemberTransactionInstructionAccounts = {
   transaction: web3.PublicKey
   vault: web3.PublicKey
   creator: web3.PublicKey
+  rateLimit: web3.PublicKey
+  transactionBuffer?: web3.PublicKey
   merkleTree: web3.PublicKey
   accountCompressionProgram: web3.PublicKey
   systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -65,6 +73,9 @@ export const createMemberTransactionInstructionDiscriminator = [
 /**
  * Creates a _CreateMemberTransaction_ instruction.
  *
+ * Optional accounts that are not provided default to the program ID since
+ * this was indicated in the IDL from which this instruction was generated.
+ *
  * @param accounts that will be accessed while the instruction is processed
  * @param args to provide as instruction data to the program
  *
@@ -97,6 +108,16 @@ export function createCreateMemberTransactionInstruction(
       isWritable: true,
       isSigner: true,
     },
+    {
+      pubkey: accounts.rateLimit,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.transactionBuffer ?? programId,
+      isWritable: accounts.transactionBuffer != null,
+      isSigner: false,
+    },
     {
       pubkey: accounts.merkleTree,
       isWritable: false,
@@ -112,6 +133,16 @@ export function createCreateMemberTransactionInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
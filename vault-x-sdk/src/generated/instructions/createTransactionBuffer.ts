@@ -0,0 +1,129 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultCreateTransactionBufferArgs,
+  vaultCreateTransactionBufferArgsBeet,
+} from '../types/VaultCreateTransactionBufferArgs'
+
+/**
+ * @category Instructions
+ * @category CreateTransactionBuffer
+ * @category generated
+ */
+export type CreateTransactionBufferInstructionArgs = {
+  args: VaultCreateTransactionBufferArgs
+}
+/**
+ * @category Instructions
+ * @category CreateTransactionBuffer
+ * @category generated
+ */
+export const createTransactionBufferStruct = new beet.FixableBeetArgsStruct<
+  CreateTransactionBufferInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultCreateTransactionBufferArgsBeet],
+  ],
+  'CreateTransactionBufferInstructionArgs'
+)
+/**
+ * Accounts required by the _createTransactionBuffer_ instruction
+ *
+ * @property [_writable_] transactionBuffer
+ * @property [] vault
+ * @property [_writable_, **signer**] creator
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category CreateTransactionBuffer
+ * @category generated
+ */
+export type CreateTransactionBufferInstructionAccounts = {
+  transactionBuffer: web3.PublicKey
+  vault: web3.PublicKey
+  creator: web3.PublicKey
+  systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const createTransactionBufferInstructionDiscriminator = [
+  57, 97, 250, 156, 59, 211, 32, 208,
+]
+
+/**
+ * Creates a _CreateTransactionBuffer_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category CreateTransactionBuffer
+ * @category generated
+ */
+export function createCreateTransactionBufferInstruction(
+  accounts: CreateTransactionBufferInstructionAccounts,
+  args: CreateTransactionBufferInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = createTransactionBufferStruct.serialize({
+    instructionDiscriminator: createTransactionBufferInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.transactionBuffer,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.creator,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
@@ -42,6 +42,9 @@ export const createVaultStruct = new beet.FixableBeetArgsStruct<
  * @property [_writable_] vault
  * @property [**signer**] createKey
  * @property [_writable_, **signer**] administrator
+ * @property [_writable_] vaultMetadata (optional)
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category CreateVault
  * @category generated
@@ -54,6 +57,9 @@ export type CreateVaultInstructionAccounts = {
   createKey: web3.PublicKey
   administrator: web3.PublicKey
   systemProgram?: web3.PublicKey
+  vaultMetadata?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -64,6 +70,9 @@ export const createVaultInstructionDiscriminator = [
 /**
  * Creates a _CreateVault_ instruction.
  *
+ * Optional accounts that are not provided default to the program ID since
+ * this was indicated in the IDL from which this instruction was generated.
+ *
  * @param accounts that will be accessed while the instruction is processed
  * @param args to provide as instruction data to the program
  *
@@ -116,6 +125,21 @@ export function createCreateVaultInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.vaultMetadata ?? programId,
+      isWritable: accounts.vaultMetadata != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -0,0 +1,138 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category DissolveCommittee
+ * @category generated
+ */
+export const dissolveCommitteeStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'DissolveCommitteeInstructionArgs'
+)
+/**
+ * Accounts required by the _dissolveCommittee_ instruction
+ *
+ * @property [_writable_] committee
+ * @property [] vault
+ * @property [**signer**] founder
+ * @property [_writable_] creator
+ * @property [_writable_] committeeFund
+ * @property [_writable_] fund
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category DissolveCommittee
+ * @category generated
+ */
+export type DissolveCommitteeInstructionAccounts = {
+  committee: web3.PublicKey
+  vault: web3.PublicKey
+  founder: web3.PublicKey
+  creator: web3.PublicKey
+  committeeFund: web3.PublicKey
+  fund: web3.PublicKey
+  systemProgram?: web3.PublicKey
+  tokenProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const dissolveCommitteeInstructionDiscriminator = [
+  93, 130, 10, 7, 53, 8, 248, 200,
+]
+
+/**
+ * Creates a _DissolveCommittee_ instruction.
+ *
+ * Optional accounts that are not provided default to the program ID since
+ * this was indicated in the IDL from which this instruction was generated.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category DissolveCommittee
+ * @category generated
+ */
+export function createDissolveCommitteeInstruction(
+  accounts: DissolveCommitteeInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = dissolveCommitteeStruct.serialize({
+    instructionDiscriminator: dissolveCommitteeInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.committee,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.founder,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.creator,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.committeeFund,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.fund,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.tokenProgram ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
@@ -0,0 +1,115 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category EscalateMemberTransaction
+ * @category generated
+ */
+export const escalateMemberTransactionStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'EscalateMemberTransactionInstructionArgs'
+)
+/**
+ * Accounts required by the _escalateMemberTransaction_ instruction
+ *
+ * @property [_writable_] memberTransaction
+ * @property [_writable_] founderTransaction
+ * @property [_writable_] vault
+ * @property [_writable_, **signer**] creator
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category EscalateMemberTransaction
+ * @category generated
+ */
+export type EscalateMemberTransactionInstructionAccounts = {
+  memberTransaction: web3.PublicKey
+  founderTransaction: web3.PublicKey
+  vault: web3.PublicKey
+  creator: web3.PublicKey
+  systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const escalateMemberTransactionInstructionDiscriminator = [
+  145, 87, 221, 31, 103, 179, 105, 255,
+]
+
+/**
+ * Creates a _EscalateMemberTransaction_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category EscalateMemberTransaction
+ * @category generated
+ */
+export function createEscalateMemberTransactionInstruction(
+  accounts: EscalateMemberTransactionInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = escalateMemberTransactionStruct.serialize({
+    instructionDiscriminator: escalateMemberTransactionInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.memberTransaction,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.founderTransaction,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.creator,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
@@ -0,0 +1,109 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category ExecuteBatchTransaction
+ * @category generated
+ */
+export const executeBatchTransactionStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'ExecuteBatchTransactionInstructionArgs'
+)
+/**
+ * Accounts required by the _executeBatchTransaction_ instruction
+ *
+ * @property [_writable_] batch
+ * @property [_writable_] transaction
+ * @property [_writable_] vault
+ * @property [_writable_, **signer**] founder
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category ExecuteBatchTransaction
+ * @category generated
+ */
+export type ExecuteBatchTransactionInstructionAccounts = {
+  batch: web3.PublicKey
+  transaction: web3.PublicKey
+  vault: web3.PublicKey
+  founder: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const executeBatchTransactionInstructionDiscriminator = [
+  237, 67, 201, 173, 33, 130, 88, 134,
+]
+
+/**
+ * Creates a _ExecuteBatchTransaction_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category ExecuteBatchTransaction
+ * @category generated
+ */
+export function createExecuteBatchTransactionInstruction(
+  accounts: ExecuteBatchTransactionInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = executeBatchTransactionStruct.serialize({
+    instructionDiscriminator: executeBatchTransactionInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.batch,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.transaction,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.founder,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
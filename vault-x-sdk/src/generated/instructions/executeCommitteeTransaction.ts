@@ -0,0 +1,110 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category ExecuteCommitteeTransaction
+ * @category generated
+ */
+export const executeCommitteeTransactionStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'ExecuteCommitteeTransactionInstructionArgs'
+)
+/**
+ * Accounts required by the _executeCommitteeTransaction_ instruction
+ *
+ * @property [_writable_] transaction
+ * @property [] committee
+ * @property [] vault
+ * @property [_writable_, **signer**] member
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category ExecuteCommitteeTransaction
+ * @category generated
+ */
+export type ExecuteCommitteeTransactionInstructionAccounts = {
+  transaction: web3.PublicKey
+  committee: web3.PublicKey
+  vault: web3.PublicKey
+  member: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const executeCommitteeTransactionInstructionDiscriminator = [
+  184, 70, 19, 1, 110, 200, 186, 166,
+]
+
+/**
+ * Creates a _ExecuteCommitteeTransaction_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category ExecuteCommitteeTransaction
+ * @category generated
+ */
+export function createExecuteCommitteeTransactionInstruction(
+  accounts: ExecuteCommitteeTransactionInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = executeCommitteeTransactionStruct.serialize({
+    instructionDiscriminator:
+      executeCommitteeTransactionInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.transaction,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.committee,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.member,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
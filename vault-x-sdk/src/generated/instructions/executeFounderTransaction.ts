@@ -23,8 +23,10 @@ export const executeFounderTransactionStruct = new beet.BeetArgsStruct<{
  * Accounts required by the _executeFounderTransaction_ instruction
  *
  * @property [_writable_] transaction
- * @property [] vault
+ * @property [_writable_] vault
  * @property [_writable_, **signer**] founder
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category ExecuteFounderTransaction
  * @category generated
@@ -33,6 +35,8 @@ export type ExecuteFounderTransactionInstructionAccounts = {
   transaction: web3.PublicKey
   vault: web3.PublicKey
   founder: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -63,7 +67,7 @@ export function createExecuteFounderTransactionInstruction(
     },
     {
       pubkey: accounts.vault,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
@@ -71,6 +75,16 @@ export function createExecuteFounderTransactionInstruction(
       isWritable: true,
       isSigner: true,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -0,0 +1,124 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultFounderExecutePartialArgs,
+  vaultFounderExecutePartialArgsBeet,
+} from '../types/VaultFounderExecutePartialArgs'
+
+/**
+ * @category Instructions
+ * @category ExecuteFounderTransactionPartial
+ * @category generated
+ */
+export type ExecuteFounderTransactionPartialInstructionArgs = {
+  args: VaultFounderExecutePartialArgs
+}
+/**
+ * @category Instructions
+ * @category ExecuteFounderTransactionPartial
+ * @category generated
+ */
+export const executeFounderTransactionPartialStruct = new beet.BeetArgsStruct<
+  ExecuteFounderTransactionPartialInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultFounderExecutePartialArgsBeet],
+  ],
+  'ExecuteFounderTransactionPartialInstructionArgs'
+)
+/**
+ * Accounts required by the _executeFounderTransactionPartial_ instruction
+ *
+ * @property [_writable_] transaction
+ * @property [_writable_] vault
+ * @property [_writable_, **signer**] founder
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category ExecuteFounderTransactionPartial
+ * @category generated
+ */
+export type ExecuteFounderTransactionPartialInstructionAccounts = {
+  transaction: web3.PublicKey
+  vault: web3.PublicKey
+  founder: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const executeFounderTransactionPartialInstructionDiscriminator = [
+  87, 55, 86, 32, 106, 96, 24, 204,
+]
+
+/**
+ * Creates a _ExecuteFounderTransactionPartial_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category ExecuteFounderTransactionPartial
+ * @category generated
+ */
+export function createExecuteFounderTransactionPartialInstruction(
+  accounts: ExecuteFounderTransactionPartialInstructionAccounts,
+  args: ExecuteFounderTransactionPartialInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = executeFounderTransactionPartialStruct.serialize({
+    instructionDiscriminator:
+      executeFounderTransactionPartialInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.transaction,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.founder,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
@@ -0,0 +1,137 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultMemberExecuteMessageArgs,
+  vaultMemberExecuteMessageArgsBeet,
+} from '../types/VaultMemberExecuteMessageArgs'
+
+/**
+ * @category Instructions
+ * @category ExecuteMemberMessage
+ * @category generated
+ */
+export type ExecuteMemberMessageInstructionArgs = {
+  args: VaultMemberExecuteMessageArgs
+}
+/**
+ * @category Instructions
+ * @category ExecuteMemberMessage
+ * @category generated
+ */
+export const executeMemberMessageStruct = new beet.FixableBeetArgsStruct<
+  ExecuteMemberMessageInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultMemberExecuteMessageArgsBeet],
+  ],
+  'ExecuteMemberMessageInstructionArgs'
+)
+/**
+ * Accounts required by the _executeMemberMessage_ instruction
+ *
+ * @property [] vault
+ * @property [_writable_, **signer**] member
+ * @property [_writable_] rateLimit
+ * @property [] merkleTree
+ * @property [] accountCompressionProgram
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category ExecuteMemberMessage
+ * @category generated
+ */
+export type ExecuteMemberMessageInstructionAccounts = {
+  vault: web3.PublicKey
+  member: web3.PublicKey
+  rateLimit: web3.PublicKey
+  merkleTree: web3.PublicKey
+  accountCompressionProgram: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const executeMemberMessageInstructionDiscriminator = [
+  199, 231, 172, 163, 89, 65, 124, 96,
+]
+
+/**
+ * Creates a _ExecuteMemberMessage_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category ExecuteMemberMessage
+ * @category generated
+ */
+export function createExecuteMemberMessageInstruction(
+  accounts: ExecuteMemberMessageInstructionAccounts,
+  args: ExecuteMemberMessageInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = executeMemberMessageStruct.serialize({
+    instructionDiscriminator: executeMemberMessageInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.member,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.rateLimit,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.merkleTree,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.accountCompressionProgram,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
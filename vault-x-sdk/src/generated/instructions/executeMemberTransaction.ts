@@ -25,6 +25,9 @@ export const executeMemberTransactionStruct = new beet.BeetArgsStruct<{
  * @property [_writable_] transaction
  * @property [] vault
  * @property [_writable_, **signer**] member
+ * @property [_writable_] rateLimit
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category ExecuteMemberTransaction
  * @category generated
@@ -33,6 +36,9 @@ export type ExecuteMemberTransactionInstructionAccounts = {
   transaction: web3.PublicKey
   vault: web3.PublicKey
   member: web3.PublicKey
+  rateLimit: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -71,6 +77,21 @@ export function createExecuteMemberTransactionInstruction(
       isWritable: true,
       isSigner: true,
     },
+    {
+      pubkey: accounts.rateLimit,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -0,0 +1,115 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category ExecuteRecovery
+ * @category generated
+ */
+export const executeRecoveryStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'ExecuteRecoveryInstructionArgs'
+)
+/**
+ * Accounts required by the _executeRecovery_ instruction
+ *
+ * @property [_writable_] vault
+ * @property [_writable_] vaultRecovery
+ * @property [_writable_, **signer**] guardian
+ * @property [_writable_] proposer
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category ExecuteRecovery
+ * @category generated
+ */
+export type ExecuteRecoveryInstructionAccounts = {
+  vault: web3.PublicKey
+  vaultRecovery: web3.PublicKey
+  guardian: web3.PublicKey
+  proposer: web3.PublicKey
+  systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const executeRecoveryInstructionDiscriminator = [
+  203, 133, 133, 228, 153, 121, 182, 237,
+]
+
+/**
+ * Creates a _ExecuteRecovery_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category ExecuteRecovery
+ * @category generated
+ */
+export function createExecuteRecoveryInstruction(
+  accounts: ExecuteRecoveryInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = executeRecoveryStruct.serialize({
+    instructionDiscriminator: executeRecoveryInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vaultRecovery,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.guardian,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.proposer,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
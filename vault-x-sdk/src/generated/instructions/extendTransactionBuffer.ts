@@ -0,0 +1,123 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultExtendTransactionBufferArgs,
+  vaultExtendTransactionBufferArgsBeet,
+} from '../types/VaultExtendTransactionBufferArgs'
+
+/**
+ * @category Instructions
+ * @category ExtendTransactionBuffer
+ * @category generated
+ */
+export type ExtendTransactionBufferInstructionArgs = {
+  args: VaultExtendTransactionBufferArgs
+}
+/**
+ * @category Instructions
+ * @category ExtendTransactionBuffer
+ * @category generated
+ */
+export const extendTransactionBufferStruct = new beet.FixableBeetArgsStruct<
+  ExtendTransactionBufferInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultExtendTransactionBufferArgsBeet],
+  ],
+  'ExtendTransactionBufferInstructionArgs'
+)
+/**
+ * Accounts required by the _extendTransactionBuffer_ instruction
+ *
+ * @property [_writable_] transactionBuffer
+ * @property [] vault
+ * @property [**signer**] creator
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category ExtendTransactionBuffer
+ * @category generated
+ */
+export type ExtendTransactionBufferInstructionAccounts = {
+  transactionBuffer: web3.PublicKey
+  vault: web3.PublicKey
+  creator: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const extendTransactionBufferInstructionDiscriminator = [
+  190, 86, 246, 95, 231, 154, 229, 91,
+]
+
+/**
+ * Creates a _ExtendTransactionBuffer_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category ExtendTransactionBuffer
+ * @category generated
+ */
+export function createExtendTransactionBufferInstruction(
+  accounts: ExtendTransactionBufferInstructionAccounts,
+  args: ExtendTransactionBufferInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = extendTransactionBufferStruct.serialize({
+    instructionDiscriminator: extendTransactionBufferInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.transactionBuffer,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.creator,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
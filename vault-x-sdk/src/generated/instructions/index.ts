@@ -4,20 +4,77 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
+export * from './acceptInvitation'
+export * from './activateBatch'
+export * from './addBatchTransaction'
 export * from './addFounder'
 export * from './addMember'
+export * from './addMembers'
+export * from './approveBatch'
+export * from './approveCommitteeTransaction'
 export * from './approveFounderTransaction'
+export * from './approveMemberTransaction'
+export * from './approveRecovery'
+export * from './cancelBatch'
+export * from './cancelCommitteeTransaction'
 export * from './cancelFounderTransaction'
+export * from './cancelMemberTransaction'
+export * from './cancelRecovery'
+export * from './closeBatch'
+export * from './closeBatchTransaction'
+export * from './closeCommitteeTransaction'
+export * from './closeFounderTransaction'
+export * from './closeLegacyTransaction'
+export * from './closeMemberTransaction'
+export * from './closeTransactionBuffer'
+export * from './closeTransactions'
+export * from './compactVault'
+export * from './createBatch'
+export * from './createCommittee'
+export * from './createCommitteeTransaction'
 export * from './createFounderTransaction'
+export * from './createInvitation'
 export * from './createMemberTransaction'
+export * from './createTransactionBuffer'
 export * from './createVault'
+export * from './dissolveCommittee'
+export * from './escalateMemberTransaction'
+export * from './executeBatchTransaction'
+export * from './executeCommitteeTransaction'
 export * from './executeFounderTransaction'
+export * from './executeFounderTransactionPartial'
+export * from './executeMemberMessage'
 export * from './executeMemberTransaction'
+export * from './executeRecovery'
+export * from './expireFounderTransaction'
+export * from './extendTransactionBuffer'
+export * from './leaveVault'
+export * from './migrateVault'
+export * from './proposeRecovery'
+export * from './rejectBatch'
+export * from './rejectCommitteeTransaction'
 export * from './rejectFounderTransaction'
+export * from './rejectMemberTransaction'
 export * from './removeFounder'
 export * from './removeMember'
+export * from './removeMembers'
+export * from './resignFounder'
+export * from './revokeInvitation'
+export * from './setMemberRateLimit'
+export * from './setVaultMetadata'
+export * from './setVaultRecovery'
+export * from './setVetoCouncil'
+export * from './sweepCommitteeFund'
 export * from './updateFounderThreshold'
+export * from './updateFounderWeight'
+export * from './updateMaxExpiry'
+export * from './updateMemberThreshold'
+export * from './updateRentCollector'
+export * from './updateTimeLock'
+export * from './validateTransaction'
 export * from './vaultConfigInit'
 export * from './vaultConfigUpdateAuthority'
 export * from './vaultConfigUpdateCreationFee'
 export * from './vaultConfigUpdateTreasury'
+export * from './vetoBatch'
+export * from './vetoFounderTransaction'
@@ -0,0 +1,105 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category LeaveVault
+ * @category generated
+ */
+export const leaveVaultStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'LeaveVaultInstructionArgs'
+)
+/**
+ * Accounts required by the _leaveVault_ instruction
+ *
+ * @property [_writable_] vault
+ * @property [**signer**] member
+ * @property [_writable_] rentCollector (optional)
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category LeaveVault
+ * @category generated
+ */
+export type LeaveVaultInstructionAccounts = {
+  vault: web3.PublicKey
+  member: web3.PublicKey
+  rentCollector?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const leaveVaultInstructionDiscriminator = [
+  89, 198, 97, 6, 231, 152, 118, 242,
+]
+
+/**
+ * Creates a _LeaveVault_ instruction.
+ *
+ * Optional accounts that are not provided default to the program ID since
+ * this was indicated in the IDL from which this instruction was generated.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category LeaveVault
+ * @category generated
+ */
+export function createLeaveVaultInstruction(
+  accounts: LeaveVaultInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = leaveVaultStruct.serialize({
+    instructionDiscriminator: leaveVaultInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.member,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.rentCollector ?? programId,
+      isWritable: accounts.rentCollector != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
@@ -0,0 +1,129 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultProposeRecoveryArgs,
+  vaultProposeRecoveryArgsBeet,
+} from '../types/VaultProposeRecoveryArgs'
+
+/**
+ * @category Instructions
+ * @category ProposeRecovery
+ * @category generated
+ */
+export type ProposeRecoveryInstructionArgs = {
+  args: VaultProposeRecoveryArgs
+}
+/**
+ * @category Instructions
+ * @category ProposeRecovery
+ * @category generated
+ */
+export const proposeRecoveryStruct = new beet.FixableBeetArgsStruct<
+  ProposeRecoveryInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultProposeRecoveryArgsBeet],
+  ],
+  'ProposeRecoveryInstructionArgs'
+)
+/**
+ * Accounts required by the _proposeRecovery_ instruction
+ *
+ * @property [] vault
+ * @property [_writable_] vaultRecovery
+ * @property [_writable_, **signer**] guardian
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category ProposeRecovery
+ * @category generated
+ */
+export type ProposeRecoveryInstructionAccounts = {
+  vault: web3.PublicKey
+  vaultRecovery: web3.PublicKey
+  guardian: web3.PublicKey
+  systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const proposeRecoveryInstructionDiscriminator = [
+  15, 85, 115, 138, 219, 199, 133, 144,
+]
+
+/**
+ * Creates a _ProposeRecovery_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category ProposeRecovery
+ * @category generated
+ */
+export function createProposeRecoveryInstruction(
+  accounts: ProposeRecoveryInstructionAccounts,
+  args: ProposeRecoveryInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = proposeRecoveryStruct.serialize({
+    instructionDiscriminator: proposeRecoveryInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vaultRecovery,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.guardian,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
@@ -23,8 +23,10 @@ export const rejectFounderTransactionStruct = new beet.BeetArgsStruct<{
  * Accounts required by the _rejectFounderTransaction_ instruction
  *
  * @property [_writable_] transaction
- * @property [] vault
+ * @property [_writable_] vault
  * @property [_writable_, **signer**] founder
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category RejectFounderTransaction
  * @category generated
@@ -33,6 +35,8 @@ export type RejectFounderTransactionInstructionAccounts = {
   transaction: web3.PublicKey
   vault: web3.PublicKey
   founder: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -63,7 +67,7 @@ export function createRejectFounderTransactionInstruction(
     },
     {
       pubkey: accounts.vault,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
@@ -71,6 +75,16 @@ export function createRejectFounderTransactionInstruction(
       isWritable: true,
       isSigner: true,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
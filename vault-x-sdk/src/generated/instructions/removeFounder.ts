@@ -41,6 +41,9 @@ export const removeFounderStruct = new beet.FixableBeetArgsStruct<
  *
  * @property [_writable_] vault
  * @property [_writable_, **signer**] administrator
+ * @property [_writable_] rentCollector (optional)
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category RemoveFounder
  * @category generated
@@ -48,6 +51,9 @@ export const removeFounderStruct = new beet.FixableBeetArgsStruct<
 export type RemoveFounderInstructionAccounts = {
   vault: web3.PublicKey
   administrator: web3.PublicKey
+  rentCollector?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -58,6 +64,9 @@ export const removeFounderInstructionDiscriminator = [
 /**
  * Creates a _RemoveFounder_ instruction.
  *
+ * Optional accounts that are not provided default to the program ID since
+ * this was indicated in the IDL from which this instruction was generated.
+ *
  * @param accounts that will be accessed while the instruction is processed
  * @param args to provide as instruction data to the program
  *
@@ -85,6 +94,21 @@ export function createRemoveFounderInstruction(
       isWritable: true,
       isSigner: true,
     },
+    {
+      pubkey: accounts.rentCollector ?? programId,
+      isWritable: accounts.rentCollector != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
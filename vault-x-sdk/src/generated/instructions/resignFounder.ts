@@ -0,0 +1,126 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultResignFounderArgs,
+  vaultResignFounderArgsBeet,
+} from '../types/VaultResignFounderArgs'
+
+/**
+ * @category Instructions
+ * @category ResignFounder
+ * @category generated
+ */
+export type ResignFounderInstructionArgs = {
+  args: VaultResignFounderArgs
+}
+/**
+ * @category Instructions
+ * @category ResignFounder
+ * @category generated
+ */
+export const resignFounderStruct = new beet.FixableBeetArgsStruct<
+  ResignFounderInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultResignFounderArgsBeet],
+  ],
+  'ResignFounderInstructionArgs'
+)
+/**
+ * Accounts required by the _resignFounder_ instruction
+ *
+ * @property [_writable_] vault
+ * @property [**signer**] founder
+ * @property [_writable_] rentCollector (optional)
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category ResignFounder
+ * @category generated
+ */
+export type ResignFounderInstructionAccounts = {
+  vault: web3.PublicKey
+  founder: web3.PublicKey
+  rentCollector?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const resignFounderInstructionDiscriminator = [
+  74, 42, 251, 19, 8, 46, 7, 59,
+]
+
+/**
+ * Creates a _ResignFounder_ instruction.
+ *
+ * Optional accounts that are not provided default to the program ID since
+ * this was indicated in the IDL from which this instruction was generated.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category ResignFounder
+ * @category generated
+ */
+export function createResignFounderInstruction(
+  accounts: ResignFounderInstructionAccounts,
+  args: ResignFounderInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = resignFounderStruct.serialize({
+    instructionDiscriminator: resignFounderInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.founder,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.rentCollector ?? programId,
+      isWritable: accounts.rentCollector != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
@@ -0,0 +1,109 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category RevokeInvitation
+ * @category generated
+ */
+export const revokeInvitationStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'RevokeInvitationInstructionArgs'
+)
+/**
+ * Accounts required by the _revokeInvitation_ instruction
+ *
+ * @property [_writable_] invitation
+ * @property [] vault
+ * @property [_writable_] inviter
+ * @property [**signer**] authority
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category RevokeInvitation
+ * @category generated
+ */
+export type RevokeInvitationInstructionAccounts = {
+  invitation: web3.PublicKey
+  vault: web3.PublicKey
+  inviter: web3.PublicKey
+  authority: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const revokeInvitationInstructionDiscriminator = [
+  4, 93, 237, 56, 212, 211, 159, 243,
+]
+
+/**
+ * Creates a _RevokeInvitation_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category RevokeInvitation
+ * @category generated
+ */
+export function createRevokeInvitationInstruction(
+  accounts: RevokeInvitationInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = revokeInvitationStruct.serialize({
+    instructionDiscriminator: revokeInvitationInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.invitation,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.inviter,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.authority,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
@@ -0,0 +1,129 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultSetMemberRateLimitArgs,
+  vaultSetMemberRateLimitArgsBeet,
+} from '../types/VaultSetMemberRateLimitArgs'
+
+/**
+ * @category Instructions
+ * @category SetMemberRateLimit
+ * @category generated
+ */
+export type SetMemberRateLimitInstructionArgs = {
+  args: VaultSetMemberRateLimitArgs
+}
+/**
+ * @category Instructions
+ * @category SetMemberRateLimit
+ * @category generated
+ */
+export const setMemberRateLimitStruct = new beet.BeetArgsStruct<
+  SetMemberRateLimitInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultSetMemberRateLimitArgsBeet],
+  ],
+  'SetMemberRateLimitInstructionArgs'
+)
+/**
+ * Accounts required by the _setMemberRateLimit_ instruction
+ *
+ * @property [] vault
+ * @property [_writable_] rateLimit
+ * @property [_writable_, **signer**] founder
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category SetMemberRateLimit
+ * @category generated
+ */
+export type SetMemberRateLimitInstructionAccounts = {
+  vault: web3.PublicKey
+  rateLimit: web3.PublicKey
+  founder: web3.PublicKey
+  systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const setMemberRateLimitInstructionDiscriminator = [
+  40, 34, 131, 248, 221, 170, 72, 233,
+]
+
+/**
+ * Creates a _SetMemberRateLimit_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category SetMemberRateLimit
+ * @category generated
+ */
+export function createSetMemberRateLimitInstruction(
+  accounts: SetMemberRateLimitInstructionAccounts,
+  args: SetMemberRateLimitInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = setMemberRateLimitStruct.serialize({
+    instructionDiscriminator: setMemberRateLimitInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.rateLimit,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.founder,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
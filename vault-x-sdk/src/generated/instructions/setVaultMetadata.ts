@@ -0,0 +1,143 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultSetMetadataArgs,
+  vaultSetMetadataArgsBeet,
+} from '../types/VaultSetMetadataArgs'
+
+/**
+ * @category Instructions
+ * @category SetVaultMetadata
+ * @category generated
+ */
+export type SetVaultMetadataInstructionArgs = {
+  args: VaultSetMetadataArgs
+}
+/**
+ * @category Instructions
+ * @category SetVaultMetadata
+ * @category generated
+ */
+export const setVaultMetadataStruct = new beet.FixableBeetArgsStruct<
+  SetVaultMetadataInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultSetMetadataArgsBeet],
+  ],
+  'SetVaultMetadataInstructionArgs'
+)
+/**
+ * Accounts required by the _setVaultMetadata_ instruction
+ *
+ * @property [] vault
+ * @property [_writable_] vaultMetadata
+ * @property [**signer**] founder
+ * @property [_writable_, **signer**] rentPayer
+ * @property [_writable_] rentCollector
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category SetVaultMetadata
+ * @category generated
+ */
+export type SetVaultMetadataInstructionAccounts = {
+  vault: web3.PublicKey
+  vaultMetadata: web3.PublicKey
+  founder: web3.PublicKey
+  rentPayer: web3.PublicKey
+  rentCollector: web3.PublicKey
+  systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const setVaultMetadataInstructionDiscriminator = [
+  156, 248, 108, 69, 108, 176, 94, 18,
+]
+
+/**
+ * Creates a _SetVaultMetadata_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category SetVaultMetadata
+ * @category generated
+ */
+export function createSetVaultMetadataInstruction(
+  accounts: SetVaultMetadataInstructionAccounts,
+  args: SetVaultMetadataInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = setVaultMetadataStruct.serialize({
+    instructionDiscriminator: setVaultMetadataInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vaultMetadata,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.founder,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.rentPayer,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.rentCollector,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
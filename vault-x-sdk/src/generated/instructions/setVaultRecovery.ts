@@ -0,0 +1,129 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultSetRecoveryArgs,
+  vaultSetRecoveryArgsBeet,
+} from '../types/VaultSetRecoveryArgs'
+
+/**
+ * @category Instructions
+ * @category SetVaultRecovery
+ * @category generated
+ */
+export type SetVaultRecoveryInstructionArgs = {
+  args: VaultSetRecoveryArgs
+}
+/**
+ * @category Instructions
+ * @category SetVaultRecovery
+ * @category generated
+ */
+export const setVaultRecoveryStruct = new beet.FixableBeetArgsStruct<
+  SetVaultRecoveryInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultSetRecoveryArgsBeet],
+  ],
+  'SetVaultRecoveryInstructionArgs'
+)
+/**
+ * Accounts required by the _setVaultRecovery_ instruction
+ *
+ * @property [] vault
+ * @property [_writable_] vaultRecovery
+ * @property [_writable_, **signer**] administrator
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category SetVaultRecovery
+ * @category generated
+ */
+export type SetVaultRecoveryInstructionAccounts = {
+  vault: web3.PublicKey
+  vaultRecovery: web3.PublicKey
+  administrator: web3.PublicKey
+  systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const setVaultRecoveryInstructionDiscriminator = [
+  182, 31, 172, 245, 104, 144, 175, 34,
+]
+
+/**
+ * Creates a _SetVaultRecovery_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category SetVaultRecovery
+ * @category generated
+ */
+export function createSetVaultRecoveryInstruction(
+  accounts: SetVaultRecoveryInstructionAccounts,
+  args: SetVaultRecoveryInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = setVaultRecoveryStruct.serialize({
+    instructionDiscriminator: setVaultRecoveryInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vaultRecovery,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.administrator,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
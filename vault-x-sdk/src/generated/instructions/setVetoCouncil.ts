@@ -0,0 +1,143 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultSetVetoCouncilArgs,
+  vaultSetVetoCouncilArgsBeet,
+} from '../types/VaultSetVetoCouncilArgs'
+
+/**
+ * @category Instructions
+ * @category SetVetoCouncil
+ * @category generated
+ */
+export type SetVetoCouncilInstructionArgs = {
+  args: VaultSetVetoCouncilArgs
+}
+/**
+ * @category Instructions
+ * @category SetVetoCouncil
+ * @category generated
+ */
+export const setVetoCouncilStruct = new beet.FixableBeetArgsStruct<
+  SetVetoCouncilInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultSetVetoCouncilArgsBeet],
+  ],
+  'SetVetoCouncilInstructionArgs'
+)
+/**
+ * Accounts required by the _setVetoCouncil_ instruction
+ *
+ * @property [] vault
+ * @property [_writable_] vetoCouncil
+ * @property [**signer**] founderAuthority
+ * @property [_writable_, **signer**] rentPayer
+ * @property [_writable_] rentCollector
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category SetVetoCouncil
+ * @category generated
+ */
+export type SetVetoCouncilInstructionAccounts = {
+  vault: web3.PublicKey
+  vetoCouncil: web3.PublicKey
+  founderAuthority: web3.PublicKey
+  rentPayer: web3.PublicKey
+  rentCollector: web3.PublicKey
+  systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const setVetoCouncilInstructionDiscriminator = [
+  116, 82, 174, 69, 208, 27, 190, 244,
+]
+
+/**
+ * Creates a _SetVetoCouncil_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category SetVetoCouncil
+ * @category generated
+ */
+export function createSetVetoCouncilInstruction(
+  accounts: SetVetoCouncilInstructionAccounts,
+  args: SetVetoCouncilInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = setVetoCouncilStruct.serialize({
+    instructionDiscriminator: setVetoCouncilInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vetoCouncil,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.founderAuthority,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.rentPayer,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.rentCollector,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
@@ -0,0 +1,152 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultSweepCommitteeFundArgs,
+  vaultSweepCommitteeFundArgsBeet,
+} from '../types/VaultSweepCommitteeFundArgs'
+
+/**
+ * @category Instructions
+ * @category SweepCommitteeFund
+ * @category generated
+ */
+export type SweepCommitteeFundInstructionArgs = {
+  args: VaultSweepCommitteeFundArgs
+}
+/**
+ * @category Instructions
+ * @category SweepCommitteeFund
+ * @category generated
+ */
+export const sweepCommitteeFundStruct = new beet.BeetArgsStruct<
+  SweepCommitteeFundInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultSweepCommitteeFundArgsBeet],
+  ],
+  'SweepCommitteeFundInstructionArgs'
+)
+/**
+ * Accounts required by the _sweepCommitteeFund_ instruction
+ *
+ * @property [] vault
+ * @property [**signer**] founder
+ * @property [] committee
+ * @property [_writable_] committeeFund
+ * @property [_writable_] fund
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category SweepCommitteeFund
+ * @category generated
+ */
+export type SweepCommitteeFundInstructionAccounts = {
+  vault: web3.PublicKey
+  founder: web3.PublicKey
+  committee: web3.PublicKey
+  committeeFund: web3.PublicKey
+  fund: web3.PublicKey
+  systemProgram?: web3.PublicKey
+  tokenProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const sweepCommitteeFundInstructionDiscriminator = [
+  233, 227, 42, 115, 210, 45, 32, 228,
+]
+
+/**
+ * Creates a _SweepCommitteeFund_ instruction.
+ *
+ * Optional accounts that are not provided default to the program ID since
+ * this was indicated in the IDL from which this instruction was generated.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category SweepCommitteeFund
+ * @category generated
+ */
+export function createSweepCommitteeFundInstruction(
+  accounts: SweepCommitteeFundInstructionAccounts,
+  args: SweepCommitteeFundInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = sweepCommitteeFundStruct.serialize({
+    instructionDiscriminator: sweepCommitteeFundInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.founder,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.committee,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.committeeFund,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.fund,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.tokenProgram ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
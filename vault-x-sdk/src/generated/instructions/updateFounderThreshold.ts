@@ -41,6 +41,8 @@ export const updateFounderThresholdStruct = new beet.BeetArgsStruct<
  *
  * @property [_writable_] vault
  * @property [_writable_, **signer**] administrator
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category UpdateFounderThreshold
  * @category generated
@@ -48,6 +50,8 @@ export const updateFounderThresholdStruct = new beet.BeetArgsStruct<
 export type UpdateFounderThresholdInstructionAccounts = {
   vault: web3.PublicKey
   administrator: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -85,6 +89,16 @@ export function createUpdateFounderThresholdInstruction(
       isWritable: true,
       isSigner: true,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -0,0 +1,116 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultUpdateFounderWeightArgs,
+  vaultUpdateFounderWeightArgsBeet,
+} from '../types/VaultUpdateFounderWeightArgs'
+
+/**
+ * @category Instructions
+ * @category UpdateFounderWeight
+ * @category generated
+ */
+export type UpdateFounderWeightInstructionArgs = {
+  args: VaultUpdateFounderWeightArgs
+}
+/**
+ * @category Instructions
+ * @category UpdateFounderWeight
+ * @category generated
+ */
+export const updateFounderWeightStruct = new beet.FixableBeetArgsStruct<
+  UpdateFounderWeightInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultUpdateFounderWeightArgsBeet],
+  ],
+  'UpdateFounderWeightInstructionArgs'
+)
+/**
+ * Accounts required by the _updateFounderWeight_ instruction
+ *
+ * @property [_writable_] vault
+ * @property [_writable_, **signer**] administrator
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category UpdateFounderWeight
+ * @category generated
+ */
+export type UpdateFounderWeightInstructionAccounts = {
+  vault: web3.PublicKey
+  administrator: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const updateFounderWeightInstructionDiscriminator = [
+  192, 139, 26, 227, 149, 145, 195, 98,
+]
+
+/**
+ * Creates a _UpdateFounderWeight_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category UpdateFounderWeight
+ * @category generated
+ */
+export function createUpdateFounderWeightInstruction(
+  accounts: UpdateFounderWeightInstructionAccounts,
+  args: UpdateFounderWeightInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = updateFounderWeightStruct.serialize({
+    instructionDiscriminator: updateFounderWeightInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.administrator,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
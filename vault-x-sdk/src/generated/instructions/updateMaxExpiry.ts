@@ -0,0 +1,116 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultUpdateMaxExpiryArgs,
+  vaultUpdateMaxExpiryArgsBeet,
+} from '../types/VaultUpdateMaxExpiryArgs'
+
+/**
+ * @category Instructions
+ * @category UpdateMaxExpiry
+ * @category generated
+ */
+export type UpdateMaxExpiryInstructionArgs = {
+  args: VaultUpdateMaxExpiryArgs
+}
+/**
+ * @category Instructions
+ * @category UpdateMaxExpiry
+ * @category generated
+ */
+export const updateMaxExpiryStruct = new beet.BeetArgsStruct<
+  UpdateMaxExpiryInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultUpdateMaxExpiryArgsBeet],
+  ],
+  'UpdateMaxExpiryInstructionArgs'
+)
+/**
+ * Accounts required by the _updateMaxExpiry_ instruction
+ *
+ * @property [_writable_] vault
+ * @property [_writable_, **signer**] administrator
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category UpdateMaxExpiry
+ * @category generated
+ */
+export type UpdateMaxExpiryInstructionAccounts = {
+  vault: web3.PublicKey
+  administrator: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const updateMaxExpiryInstructionDiscriminator = [
+  45, 198, 15, 151, 175, 166, 129, 222,
+]
+
+/**
+ * Creates a _UpdateMaxExpiry_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category UpdateMaxExpiry
+ * @category generated
+ */
+export function createUpdateMaxExpiryInstruction(
+  accounts: UpdateMaxExpiryInstructionAccounts,
+  args: UpdateMaxExpiryInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = updateMaxExpiryStruct.serialize({
+    instructionDiscriminator: updateMaxExpiryInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.administrator,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
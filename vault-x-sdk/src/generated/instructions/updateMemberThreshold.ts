@@ -0,0 +1,116 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultUpdateMemberThresholdArgs,
+  vaultUpdateMemberThresholdArgsBeet,
+} from '../types/VaultUpdateMemberThresholdArgs'
+
+/**
+ * @category Instructions
+ * @category UpdateMemberThreshold
+ * @category generated
+ */
+export type UpdateMemberThresholdInstructionArgs = {
+  args: VaultUpdateMemberThresholdArgs
+}
+/**
+ * @category Instructions
+ * @category UpdateMemberThreshold
+ * @category generated
+ */
+export const updateMemberThresholdStruct = new beet.BeetArgsStruct<
+  UpdateMemberThresholdInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultUpdateMemberThresholdArgsBeet],
+  ],
+  'UpdateMemberThresholdInstructionArgs'
+)
+/**
+ * Accounts required by the _updateMemberThreshold_ instruction
+ *
+ * @property [_writable_] vault
+ * @property [_writable_, **signer**] administrator
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category UpdateMemberThreshold
+ * @category generated
+ */
+export type UpdateMemberThresholdInstructionAccounts = {
+  vault: web3.PublicKey
+  administrator: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const updateMemberThresholdInstructionDiscriminator = [
+  7, 75, 197, 14, 93, 21, 91, 224,
+]
+
+/**
+ * Creates a _UpdateMemberThreshold_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category UpdateMemberThreshold
+ * @category generated
+ */
+export function createUpdateMemberThresholdInstruction(
+  accounts: UpdateMemberThresholdInstructionAccounts,
+  args: UpdateMemberThresholdInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = updateMemberThresholdStruct.serialize({
+    instructionDiscriminator: updateMemberThresholdInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.administrator,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
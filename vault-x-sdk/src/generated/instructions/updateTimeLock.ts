@@ -0,0 +1,116 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+import {
+  VaultUpdateTimeLockArgs,
+  vaultUpdateTimeLockArgsBeet,
+} from '../types/VaultUpdateTimeLockArgs'
+
+/**
+ * @category Instructions
+ * @category UpdateTimeLock
+ * @category generated
+ */
+export type UpdateTimeLockInstructionArgs = {
+  args: VaultUpdateTimeLockArgs
+}
+/**
+ * @category Instructions
+ * @category UpdateTimeLock
+ * @category generated
+ */
+export const updateTimeLockStruct = new beet.BeetArgsStruct<
+  UpdateTimeLockInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['args', vaultUpdateTimeLockArgsBeet],
+  ],
+  'UpdateTimeLockInstructionArgs'
+)
+/**
+ * Accounts required by the _updateTimeLock_ instruction
+ *
+ * @property [_writable_] vault
+ * @property [_writable_, **signer**] administrator
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category UpdateTimeLock
+ * @category generated
+ */
+export type UpdateTimeLockInstructionAccounts = {
+  vault: web3.PublicKey
+  administrator: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const updateTimeLockInstructionDiscriminator = [
+  178, 158, 208, 60, 138, 59, 27, 166,
+]
+
+/**
+ * Creates a _UpdateTimeLock_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
+ * @category Instructions
+ * @category UpdateTimeLock
+ * @category generated
+ */
+export function createUpdateTimeLockInstruction(
+  accounts: UpdateTimeLockInstructionAccounts,
+  args: UpdateTimeLockInstructionArgs,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = updateTimeLockStruct.serialize({
+    instructionDiscriminator: updateTimeLockInstructionDiscriminator,
+    ...args,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.administrator,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
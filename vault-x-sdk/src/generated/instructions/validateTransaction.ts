@@ -0,0 +1,81 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category ValidateTransaction
+ * @category generated
+ */
+export const validateTransactionStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'ValidateTransactionInstructionArgs'
+)
+/**
+ * Accounts required by the _validateTransaction_ instruction
+ *
+ * @property [] vault
+ * @property [] transaction
+ * @category Instructions
+ * @category ValidateTransaction
+ * @category generated
+ */
+export type ValidateTransactionInstructionAccounts = {
+  vault: web3.PublicKey
+  transaction: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const validateTransactionInstructionDiscriminator = [
+  184, 227, 178, 99, 77, 97, 24, 75,
+]
+
+/**
+ * Creates a _ValidateTransaction_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category ValidateTransaction
+ * @category generated
+ */
+export function createValidateTransactionInstruction(
+  accounts: ValidateTransactionInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = validateTransactionStruct.serialize({
+    instructionDiscriminator: validateTransactionInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.transaction,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
@@ -39,6 +39,8 @@ export const vaultConfigInitStruct = new beet.BeetArgsStruct<
  * @property [_writable_, **signer**] initializer
  * @property [] authority
  * @property [] treasury
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category VaultConfigInit
  * @category generated
@@ -49,6 +51,8 @@ export type VaultConfigInitInstructionAccounts = {
   authority: web3.PublicKey
   treasury: web3.PublicKey
   systemProgram?: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -101,6 +105,16 @@ export function createVaultConfigInitInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -38,6 +38,8 @@ export const vaultConfigUpdateAuthorityStruct = new beet.BeetArgsStruct<
  *
  * @property [_writable_] vaultConfig
  * @property [_writable_, **signer**] authority
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category VaultConfigUpdateAuthority
  * @category generated
@@ -45,6 +47,8 @@ export const vaultConfigUpdateAuthorityStruct = new beet.BeetArgsStruct<
 export type VaultConfigUpdateAuthorityInstructionAccounts = {
   vaultConfig: web3.PublicKey
   authority: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -83,6 +87,16 @@ export function createVaultConfigUpdateAuthorityInstruction(
       isWritable: true,
       isSigner: true,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -37,6 +37,8 @@ export const vaultConfigUpdateCreationFeeStruct = new beet.BeetArgsStruct<
  *
  * @property [_writable_] vaultConfig
  * @property [_writable_, **signer**] authority
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category VaultConfigUpdateCreationFee
  * @category generated
@@ -44,6 +46,8 @@ export const vaultConfigUpdateCreationFeeStruct = new beet.BeetArgsStruct<
 export type VaultConfigUpdateCreationFeeInstructionAccounts = {
   vaultConfig: web3.PublicKey
   authority: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -82,6 +86,16 @@ export function createVaultConfigUpdateCreationFeeInstruction(
       isWritable: true,
       isSigner: true,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -0,0 +1,109 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import * as web3 from '@solana/web3.js'
+
+/**
+ * @category Instructions
+ * @category VetoBatch
+ * @category generated
+ */
+export const vetoBatchStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[] /* size: 8 */
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'VetoBatchInstructionArgs'
+)
+/**
+ * Accounts required by the _vetoBatch_ instruction
+ *
+ * @property [_writable_] batch
+ * @property [] vault
+ * @property [] vetoCouncil
+ * @property [**signer**] guardian
+ * @property [] eventAuthority
+ * @property [] program
+ * @category Instructions
+ * @category VetoBatch
+ * @category generated
+ */
+export type VetoBatchInstructionAccounts = {
+  batch: web3.PublicKey
+  vault: web3.PublicKey
+  vetoCouncil: web3.PublicKey
+  guardian: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
+  anchorRemainingAccounts?: web3.AccountMeta[]
+}
+
+export const vetoBatchInstructionDiscriminator = [
+  73, 106, 66, 224, 89, 4, 126, 93,
+]
+
+/**
+ * Creates a _VetoBatch_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @category Instructions
+ * @category VetoBatch
+ * @category generated
+ */
+export function createVetoBatchInstruction(
+  accounts: VetoBatchInstructionAccounts,
+  programId = new web3.PublicKey('GLdveVwYn2cSsuj5DTARPC8RLrTkCDRq484e8C91Zd7A')
+) {
+  const [data] = vetoBatchStruct.serialize({
+    instructionDiscriminator: vetoBatchInstructionDiscriminator,
+  })
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.batch,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vault,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.vetoCouncil,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.guardian,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
+  ]
+
+  if (accounts.anchorRemainingAccounts != null) {
+    for (const acc of accounts.anchorRemainingAccounts) {
+      keys.push(acc)
+    }
+  }
+
+  const ix = new web3.TransactionInstruction({
+    programId,
+    keys,
+    data,
+  })
+  return ix
+}
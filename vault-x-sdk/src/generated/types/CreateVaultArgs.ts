@@ -8,9 +8,16 @@
 import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
+import {
+  VaultSetMetadataArgs,
+  vaultSetMetadataArgsBeet,
+} from './VaultSetMetadataArgs'
 export type CreateVaultArgs = {
   founderThreshold: number
+  timeLockSeconds: number
   initialFounders: web3.PublicKey[]
+  initialFounderWeights: beet.COption<number[]>
+  metadata: beet.COption<VaultSetMetadataArgs>
 }
 
 /**
@@ -21,7 +28,10 @@ export const createVaultArgsBeet =
   new beet.FixableBeetArgsStruct<CreateVaultArgs>(
     [
       ['founderThreshold', beet.u16],
+      ['timeLockSeconds', beet.u32],
       ['initialFounders', beet.array(beetSolana.publicKey)],
+      ['initialFounderWeights', beet.coption(beet.array(beet.u16))],
+      ['metadata', beet.coption(vaultSetMetadataArgsBeet)],
     ],
     'CreateVaultArgs'
   )
@@ -0,0 +1,31 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+import {
+  InstructionReturnData,
+  instructionReturnDataBeet,
+} from './InstructionReturnData'
+export type ExecutionReceipt = {
+  fundLamportsBefore: beet.COption<beet.bignum>
+  fundLamportsAfter: beet.COption<beet.bignum>
+  returnData: InstructionReturnData[]
+}
+
+/**
+ * @category userTypes
+ * @category generated
+ */
+export const executionReceiptBeet =
+  new beet.FixableBeetArgsStruct<ExecutionReceipt>(
+    [
+      ['fundLamportsBefore', beet.coption(beet.u64)],
+      ['fundLamportsAfter', beet.coption(beet.u64)],
+      ['returnData', beet.array(instructionReturnDataBeet)],
+    ],
+    'ExecutionReceipt'
+  )
@@ -6,10 +6,11 @@
  */
 
 import * as web3 from '@solana/web3.js'
-import * as beetSolana from '@metaplex-foundation/beet-solana'
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
 export type VaultAddFounderArgs = {
   newFounder: web3.PublicKey
+  weight: beet.COption<number>
 }
 
 /**
@@ -17,7 +18,10 @@ export type VaultAddFounderArgs = {
  * @category generated
  */
 export const vaultAddFounderArgsBeet =
-  new beet.BeetArgsStruct<VaultAddFounderArgs>(
-    [['newFounder', beetSolana.publicKey]],
+  new beet.FixableBeetArgsStruct<VaultAddFounderArgs>(
+    [
+      ['newFounder', beetSolana.publicKey],
+      ['weight', beet.coption(beet.u16)],
+    ],
     'VaultAddFounderArgs'
   )
@@ -0,0 +1,32 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as web3 from '@solana/web3.js'
+import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+import { VaultRole, vaultRoleBeet } from './VaultRole'
+export type VaultCreateInvitationArgs = {
+  invitee: web3.PublicKey
+  role: VaultRole
+  founderWeight: beet.COption<number>
+  expiresAt: beet.bignum
+}
+
+/**
+ * @category userTypes
+ * @category generated
+ */
+export const vaultCreateInvitationArgsBeet =
+  new beet.FixableBeetArgsStruct<VaultCreateInvitationArgs>(
+    [
+      ['invitee', beetSolana.publicKey],
+      ['role', vaultRoleBeet],
+      ['founderWeight', beet.coption(beet.u16)],
+      ['expiresAt', beet.i64],
+    ],
+    'VaultCreateInvitationArgs'
+  )
@@ -0,0 +1,29 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+export type VaultCreateTransactionBufferArgs = {
+  bufferIndex: number
+  finalBufferHash: number[] /* size: 32 */
+  finalBufferSize: number
+  buffer: Uint8Array
+}
+
+/**
+ * @category userTypes
+ * @category generated
+ */
+export const vaultCreateTransactionBufferArgsBeet =
+  new beet.FixableBeetArgsStruct<VaultCreateTransactionBufferArgs>(
+    [
+      ['bufferIndex', beet.u8],
+      ['finalBufferHash', beet.uniformFixedSizeArray(beet.u8, 32)],
+      ['finalBufferSize', beet.u16],
+      ['buffer', beet.bytes],
+    ],
+    'VaultCreateTransactionBufferArgs'
+  )
@@ -6,9 +6,14 @@
  */
 
 import * as beet from '@metaplex-foundation/beet'
+import {
+  VaultTransactionExpiry,
+  vaultTransactionExpiryBeet,
+} from './VaultTransactionExpiry'
 export type VaultFounderCreateTransactionArgs = {
   ephemeralSigners: number
   transactionMessage: Uint8Array
+  expiry: beet.COption<VaultTransactionExpiry>
 }
 
 /**
@@ -20,6 +25,7 @@ export const vaultFounderCreateTransactionArgsBeet =
     [
       ['ephemeralSigners', beet.u8],
       ['transactionMessage', beet.bytes],
+      ['expiry', beet.coption(vaultTransactionExpiryBeet)],
     ],
     'VaultFounderCreateTransactionArgs'
   )
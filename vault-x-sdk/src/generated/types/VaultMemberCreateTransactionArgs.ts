@@ -12,6 +12,8 @@ export type VaultMemberCreateTransactionArgs = {
   allowListRoot: number[] /* size: 32 */
   allowListLeaf: number[] /* size: 32 */
   allowListLeafIndex: number
+  maxExecutions: beet.COption<number>
+  cooldownSeconds: beet.COption<number>
 }
 
 /**
@@ -26,6 +28,8 @@ export const vaultMemberCreateTransactionArgsBeet =
       ['allowListRoot', beet.uniformFixedSizeArray(beet.u8, 32)],
       ['allowListLeaf', beet.uniformFixedSizeArray(beet.u8, 32)],
       ['allowListLeafIndex', beet.u32],
+      ['maxExecutions', beet.coption(beet.u32)],
+      ['cooldownSeconds', beet.coption(beet.u32)],
     ],
     'VaultMemberCreateTransactionArgs'
   )
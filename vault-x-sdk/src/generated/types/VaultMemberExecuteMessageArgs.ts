@@ -0,0 +1,31 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+export type VaultMemberExecuteMessageArgs = {
+  transactionMessage: Uint8Array
+  allowListRoot: number[] /* size: 32 */
+  allowListLeaf: number[] /* size: 32 */
+  allowListLeafIndex: number
+  allowListProofLength: number
+}
+
+/**
+ * @category userTypes
+ * @category generated
+ */
+export const vaultMemberExecuteMessageArgsBeet =
+  new beet.FixableBeetArgsStruct<VaultMemberExecuteMessageArgs>(
+    [
+      ['transactionMessage', beet.bytes],
+      ['allowListRoot', beet.uniformFixedSizeArray(beet.u8, 32)],
+      ['allowListLeaf', beet.uniformFixedSizeArray(beet.u8, 32)],
+      ['allowListLeafIndex', beet.u32],
+      ['allowListProofLength', beet.u8],
+    ],
+    'VaultMemberExecuteMessageArgs'
+  )
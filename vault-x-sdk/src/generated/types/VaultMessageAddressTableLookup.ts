@@ -0,0 +1,29 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as web3 from '@solana/web3.js'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+import * as beet from '@metaplex-foundation/beet'
+export type VaultMessageAddressTableLookup = {
+  accountKey: web3.PublicKey
+  writableIndexes: Uint8Array
+  readonlyIndexes: Uint8Array
+}
+
+/**
+ * @category userTypes
+ * @category generated
+ */
+export const vaultMessageAddressTableLookupBeet =
+  new beet.FixableBeetArgsStruct<VaultMessageAddressTableLookup>(
+    [
+      ['accountKey', beetSolana.publicKey],
+      ['writableIndexes', beet.bytes],
+      ['readonlyIndexes', beet.bytes],
+    ],
+    'VaultMessageAddressTableLookup'
+  )
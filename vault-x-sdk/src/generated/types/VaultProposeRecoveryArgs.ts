@@ -0,0 +1,29 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as web3 from '@solana/web3.js'
+import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+export type VaultProposeRecoveryArgs = {
+  newFounders: web3.PublicKey[]
+  newFounderWeights: beet.COption<number[]>
+  newFounderThreshold: number
+}
+
+/**
+ * @category userTypes
+ * @category generated
+ */
+export const vaultProposeRecoveryArgsBeet =
+  new beet.FixableBeetArgsStruct<VaultProposeRecoveryArgs>(
+    [
+      ['newFounders', beet.array(beetSolana.publicKey)],
+      ['newFounderWeights', beet.coption(beet.array(beet.u16))],
+      ['newFounderThreshold', beet.u16],
+    ],
+    'VaultProposeRecoveryArgs'
+  )
@@ -0,0 +1,37 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as web3 from '@solana/web3.js'
+import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+export type VaultRecoveryProposal = {
+  proposer: web3.PublicKey
+  newFounders: web3.PublicKey[]
+  newFounderWeights: number[]
+  newFounderThreshold: number
+  approved: web3.PublicKey[]
+  proposedAt: beet.bignum
+  approvedAt: beet.bignum
+}
+
+/**
+ * @category userTypes
+ * @category generated
+ */
+export const vaultRecoveryProposalBeet =
+  new beet.FixableBeetArgsStruct<VaultRecoveryProposal>(
+    [
+      ['proposer', beetSolana.publicKey],
+      ['newFounders', beet.array(beetSolana.publicKey)],
+      ['newFounderWeights', beet.array(beet.u16)],
+      ['newFounderThreshold', beet.u16],
+      ['approved', beet.array(beetSolana.publicKey)],
+      ['proposedAt', beet.i64],
+      ['approvedAt', beet.i64],
+    ],
+    'VaultRecoveryProposal'
+  )
@@ -0,0 +1,31 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+export type VaultSetMetadataArgs = {
+  name: string
+  description: string
+  imageUri: string
+  website: string
+  tags: string[]
+}
+
+/**
+ * @category userTypes
+ * @category generated
+ */
+export const vaultSetMetadataArgsBeet =
+  new beet.FixableBeetArgsStruct<VaultSetMetadataArgs>(
+    [
+      ['name', beet.utf8String],
+      ['description', beet.utf8String],
+      ['imageUri', beet.utf8String],
+      ['website', beet.utf8String],
+      ['tags', beet.array(beet.utf8String)],
+    ],
+    'VaultSetMetadataArgs'
+  )
@@ -0,0 +1,31 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as web3 from '@solana/web3.js'
+import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+export type VaultSetRecoveryArgs = {
+  guardians: web3.PublicKey[]
+  guardianThreshold: number
+  inactivityPeriodSeconds: number
+  timeLockSeconds: number
+}
+
+/**
+ * @category userTypes
+ * @category generated
+ */
+export const vaultSetRecoveryArgsBeet =
+  new beet.FixableBeetArgsStruct<VaultSetRecoveryArgs>(
+    [
+      ['guardians', beet.array(beetSolana.publicKey)],
+      ['guardianThreshold', beet.u16],
+      ['inactivityPeriodSeconds', beet.u32],
+      ['timeLockSeconds', beet.u32],
+    ],
+    'VaultSetRecoveryArgs'
+  )
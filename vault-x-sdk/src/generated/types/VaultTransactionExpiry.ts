@@ -0,0 +1,64 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+/**
+ * This type is used to derive the {@link VaultTransactionExpiry} type as well as the de/serializer.
+ * However don't refer to it in your code but use the {@link VaultTransactionExpiry} type instead.
+ *
+ * @category userTypes
+ * @category enums
+ * @category generated
+ * @private
+ */
+export type VaultTransactionExpiryRecord = {
+  Timestamp: { fields: [beet.bignum] }
+  Slot: { fields: [beet.bignum] }
+}
+
+/**
+ * Union type respresenting the VaultTransactionExpiry data enum defined in Rust.
+ *
+ * NOTE: that it includes a `__kind` property which allows to narrow types in
+ * switch/if statements.
+ * Additionally `isVaultTransactionExpiry*` type guards are exposed below to narrow to a specific variant.
+ *
+ * @category userTypes
+ * @category enums
+ * @category generated
+ */
+export type VaultTransactionExpiry = beet.DataEnumKeyAsKind<VaultTransactionExpiryRecord>
+
+export const isVaultTransactionExpiryTimestamp = (
+  x: VaultTransactionExpiry
+): x is VaultTransactionExpiry & { __kind: 'Timestamp' } =>
+  x.__kind === 'Timestamp'
+export const isVaultTransactionExpirySlot = (
+  x: VaultTransactionExpiry
+): x is VaultTransactionExpiry & { __kind: 'Slot' } => x.__kind === 'Slot'
+
+/**
+ * @category userTypes
+ * @category generated
+ */
+export const vaultTransactionExpiryBeet =
+  beet.dataEnum<VaultTransactionExpiryRecord>([
+    [
+      'Timestamp',
+      new beet.BeetArgsStruct<VaultTransactionExpiryRecord['Timestamp']>(
+        [['fields', beet.fixedSizeTuple([beet.i64])]],
+        'VaultTransactionExpiryRecord["Timestamp"]'
+      ),
+    ],
+    [
+      'Slot',
+      new beet.BeetArgsStruct<VaultTransactionExpiryRecord['Slot']>(
+        [['fields', beet.fixedSizeTuple([beet.u64])]],
+        'VaultTransactionExpiryRecord["Slot"]'
+      ),
+    ],
+  ]) as beet.FixableBeet<VaultTransactionExpiry, VaultTransactionExpiry>
@@ -9,12 +9,17 @@ import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
 import { VaultInstruction, vaultInstructionBeet } from './VaultInstruction'
+import {
+  VaultMessageAddressTableLookup,
+  vaultMessageAddressTableLookupBeet,
+} from './VaultMessageAddressTableLookup'
 export type VaultTransactionMessage = {
   numSigners: number
   numWritableSigners: number
   numWritableNonSigners: number
   accountKeys: web3.PublicKey[]
   instructions: VaultInstruction[]
+  addressTableLookups: VaultMessageAddressTableLookup[]
 }
 
 /**
@@ -29,6 +34,7 @@ export const vaultTransactionMessageBeet =
       ['numWritableNonSigners', beet.u8],
       ['accountKeys', beet.array(beetSolana.publicKey)],
       ['instructions', beet.array(vaultInstructionBeet)],
+      ['addressTableLookups', beet.array(vaultMessageAddressTableLookupBeet)],
     ],
     'VaultTransactionMessage'
   )
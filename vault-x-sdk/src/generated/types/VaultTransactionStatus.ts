@@ -16,6 +16,9 @@ export enum VaultTransactionStatus {
   Rejected,
   Cancelled,
   Approved,
+  Expired,
+  Vetoed,
+  ExecutingPartial,
 }
 
 /**
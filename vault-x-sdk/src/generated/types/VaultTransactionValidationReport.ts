@@ -0,0 +1,31 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+export type VaultTransactionValidationReport = {
+  transactionIndex: number
+  numAccountKeys: number
+  numAccountInfos: number
+  numInstructions: number
+  errorCode: beet.COption<beet.bignum>
+}
+
+/**
+ * @category userTypes
+ * @category generated
+ */
+export const vaultTransactionValidationReportBeet =
+  new beet.FixableBeetArgsStruct<VaultTransactionValidationReport>(
+    [
+      ['transactionIndex', beet.u32],
+      ['numAccountKeys', beet.u16],
+      ['numAccountInfos', beet.u16],
+      ['numInstructions', beet.u16],
+      ['errorCode', beet.coption(beet.u64)],
+    ],
+    'VaultTransactionValidationReport'
+  )
@@ -0,0 +1,199 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as web3 from '@solana/web3.js'
+import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+/**
+ * This type is used to derive the {@link VaultUpdate} type as well as the de/serializer.
+ * However don't refer to it in your code but use the {@link VaultUpdate} type instead.
+ *
+ * @category userTypes
+ * @category enums
+ * @category generated
+ * @private
+ */
+export type VaultUpdateRecord = {
+  FounderWeight: {
+    founder: web3.PublicKey
+    weight: number
+  }
+  MemberThreshold: {
+    memberThreshold: number
+  }
+  TimeLock: {
+    timeLockSeconds: number
+  }
+  MaxExpiry: {
+    maxExpirySeconds: number
+    maxExpirySlots: beet.bignum
+  }
+  MemberRateLimit: {
+    member: web3.PublicKey
+    maxTransactions: number
+    windowSeconds: number
+  }
+  RentCollector: {
+    rentCollector: web3.PublicKey
+  }
+  Compacted: void
+  Migrated: {
+    version: number
+  }
+  Metadata: void
+  VetoCouncil: {
+    guardians: web3.PublicKey[]
+    guardianThreshold: number
+  }
+  Recovery: {
+    guardians: web3.PublicKey[]
+    guardianThreshold: number
+    inactivityPeriodSeconds: number
+    timeLockSeconds: number
+  }
+}
+
+/**
+ * Union type respresenting the VaultUpdate data enum defined in Rust.
+ *
+ * NOTE: that it includes a `__kind` property which allows to narrow types in
+ * switch/if statements.
+ * Additionally `isVaultUpdate*` type guards are exposed below to narrow to a specific variant.
+ *
+ * @category userTypes
+ * @category enums
+ * @category generated
+ */
+export type VaultUpdate = beet.DataEnumKeyAsKind<VaultUpdateRecord>
+
+export const isVaultUpdateFounderWeight = (
+  x: VaultUpdate
+): x is VaultUpdate & { __kind: 'FounderWeight' } =>
+  x.__kind === 'FounderWeight'
+export const isVaultUpdateMemberThreshold = (
+  x: VaultUpdate
+): x is VaultUpdate & { __kind: 'MemberThreshold' } =>
+  x.__kind === 'MemberThreshold'
+export const isVaultUpdateTimeLock = (
+  x: VaultUpdate
+): x is VaultUpdate & { __kind: 'TimeLock' } => x.__kind === 'TimeLock'
+export const isVaultUpdateMaxExpiry = (
+  x: VaultUpdate
+): x is VaultUpdate & { __kind: 'MaxExpiry' } => x.__kind === 'MaxExpiry'
+export const isVaultUpdateMemberRateLimit = (
+  x: VaultUpdate
+): x is VaultUpdate & { __kind: 'MemberRateLimit' } =>
+  x.__kind === 'MemberRateLimit'
+export const isVaultUpdateRentCollector = (
+  x: VaultUpdate
+): x is VaultUpdate & { __kind: 'RentCollector' } =>
+  x.__kind === 'RentCollector'
+export const isVaultUpdateCompacted = (
+  x: VaultUpdate
+): x is VaultUpdate & { __kind: 'Compacted' } => x.__kind === 'Compacted'
+export const isVaultUpdateMigrated = (
+  x: VaultUpdate
+): x is VaultUpdate & { __kind: 'Migrated' } => x.__kind === 'Migrated'
+export const isVaultUpdateMetadata = (
+  x: VaultUpdate
+): x is VaultUpdate & { __kind: 'Metadata' } => x.__kind === 'Metadata'
+export const isVaultUpdateVetoCouncil = (
+  x: VaultUpdate
+): x is VaultUpdate & { __kind: 'VetoCouncil' } => x.__kind === 'VetoCouncil'
+export const isVaultUpdateRecovery = (
+  x: VaultUpdate
+): x is VaultUpdate & { __kind: 'Recovery' } => x.__kind === 'Recovery'
+
+/**
+ * @category userTypes
+ * @category generated
+ */
+export const vaultUpdateBeet = beet.dataEnum<VaultUpdateRecord>([
+  [
+    'FounderWeight',
+    new beet.BeetArgsStruct<VaultUpdateRecord['FounderWeight']>(
+      [
+        ['founder', beetSolana.publicKey],
+        ['weight', beet.u16],
+      ],
+      'VaultUpdateRecord["FounderWeight"]'
+    ),
+  ],
+  [
+    'MemberThreshold',
+    new beet.BeetArgsStruct<VaultUpdateRecord['MemberThreshold']>(
+      [['memberThreshold', beet.u16]],
+      'VaultUpdateRecord["MemberThreshold"]'
+    ),
+  ],
+  [
+    'TimeLock',
+    new beet.BeetArgsStruct<VaultUpdateRecord['TimeLock']>(
+      [['timeLockSeconds', beet.u32]],
+      'VaultUpdateRecord["TimeLock"]'
+    ),
+  ],
+  [
+    'MaxExpiry',
+    new beet.BeetArgsStruct<VaultUpdateRecord['MaxExpiry']>(
+      [
+        ['maxExpirySeconds', beet.u32],
+        ['maxExpirySlots', beet.u64],
+      ],
+      'VaultUpdateRecord["MaxExpiry"]'
+    ),
+  ],
+  [
+    'MemberRateLimit',
+    new beet.BeetArgsStruct<VaultUpdateRecord['MemberRateLimit']>(
+      [
+        ['member', beetSolana.publicKey],
+        ['maxTransactions', beet.u32],
+        ['windowSeconds', beet.u32],
+      ],
+      'VaultUpdateRecord["MemberRateLimit"]'
+    ),
+  ],
+  [
+    'RentCollector',
+    new beet.BeetArgsStruct<VaultUpdateRecord['RentCollector']>(
+      [['rentCollector', beetSolana.publicKey]],
+      'VaultUpdateRecord["RentCollector"]'
+    ),
+  ],
+  ['Compacted', beet.unit],
+  [
+    'Migrated',
+    new beet.BeetArgsStruct<VaultUpdateRecord['Migrated']>(
+      [['version', beet.u8]],
+      'VaultUpdateRecord["Migrated"]'
+    ),
+  ],
+  ['Metadata', beet.unit],
+  [
+    'VetoCouncil',
+    new beet.FixableBeetArgsStruct<VaultUpdateRecord['VetoCouncil']>(
+      [
+        ['guardians', beet.array(beetSolana.publicKey)],
+        ['guardianThreshold', beet.u16],
+      ],
+      'VaultUpdateRecord["VetoCouncil"]'
+    ),
+  ],
+  [
+    'Recovery',
+    new beet.FixableBeetArgsStruct<VaultUpdateRecord['Recovery']>(
+      [
+        ['guardians', beet.array(beetSolana.publicKey)],
+        ['guardianThreshold', beet.u16],
+        ['inactivityPeriodSeconds', beet.u32],
+        ['timeLockSeconds', beet.u32],
+      ],
+      'VaultUpdateRecord["Recovery"]'
+    ),
+  ],
+]) as beet.FixableBeet<VaultUpdate, VaultUpdate>
@@ -0,0 +1,25 @@
+/**
+ * © 2024 Blockpal LLC
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import * as beet from '@metaplex-foundation/beet'
+export type VaultUpdateMaxExpiryArgs = {
+  newMaxExpirySeconds: number
+  newMaxExpirySlots: beet.bignum
+}
+
+/**
+ * @category userTypes
+ * @category generated
+ */
+export const vaultUpdateMaxExpiryArgsBeet =
+  new beet.BeetArgsStruct<VaultUpdateMaxExpiryArgs>(
+    [
+      ['newMaxExpirySeconds', beet.u32],
+      ['newMaxExpirySlots', beet.u64],
+    ],
+    'VaultUpdateMaxExpiryArgs'
+  )
@@ -5,13 +5,47 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 export * from './CreateVaultArgs'
+export * from './ExecutionReceipt'
+export * from './InstructionReturnData'
+export * from './TransactionKind'
+export * from './TransactionVote'
+export * from './VaultAddBatchTransactionArgs'
 export * from './VaultAddFounderArgs'
 export * from './VaultAddMemberArgs'
+export * from './VaultAddMembersArgs'
+export * from './VaultBatchStatus'
+export * from './VaultBatchTransactionStatus'
+export * from './VaultCommitteeCreateTransactionArgs'
+export * from './VaultCreateCommitteeArgs'
+export * from './VaultCreateInvitationArgs'
+export * from './VaultCreateTransactionBufferArgs'
+export * from './VaultExtendTransactionBufferArgs'
 export * from './VaultFounderCreateTransactionArgs'
+export * from './VaultFounderExecutePartialArgs'
 export * from './VaultInstruction'
 export * from './VaultMemberCreateTransactionArgs'
+export * from './VaultMemberExecuteMessageArgs'
+export * from './VaultMessageAddressTableLookup'
+export * from './VaultProposeRecoveryArgs'
+export * from './VaultRecoveryProposal'
 export * from './VaultRemoveFounderArgs'
 export * from './VaultRemoveMemberArgs'
+export * from './VaultRemoveMembersArgs'
+export * from './VaultResignFounderArgs'
+export * from './VaultRole'
+export * from './VaultSetMemberRateLimitArgs'
+export * from './VaultSetMetadataArgs'
+export * from './VaultSetRecoveryArgs'
+export * from './VaultSetVetoCouncilArgs'
+export * from './VaultSweepCommitteeFundArgs'
+export * from './VaultTransactionExpiry'
 export * from './VaultTransactionMessage'
 export * from './VaultTransactionStatus'
+export * from './VaultTransactionValidationReport'
+export * from './VaultUpdate'
 export * from './VaultUpdateFounderThresholdArgs'
+export * from './VaultUpdateFounderWeightArgs'
+export * from './VaultUpdateMaxExpiryArgs'
+export * from './VaultUpdateMemberThresholdArgs'
+export * from './VaultUpdateRentCollectorArgs'
+export * from './VaultUpdateTimeLockArgs'
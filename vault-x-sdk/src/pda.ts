@@ -17,6 +17,20 @@ const SEED_FOUNDER_TRANSACTION = new TextEncoder().encode(
 const SEED_MEMBER_TRANSACTION = new TextEncoder().encode('member_transaction');
 const SEED_FUND = new TextEncoder().encode('fund');
 const SEED_EPHEMERAL_SIGNER = new TextEncoder().encode('ephemeral_signer');
+const SEED_FOUNDER_AUTHORITY = new TextEncoder().encode('founder_authority');
+const SEED_VAULT_METADATA = new TextEncoder().encode('vault_metadata');
+const SEED_COMMITTEE = new TextEncoder().encode('committee');
+const SEED_COMMITTEE_TRANSACTION = new TextEncoder().encode(
+  'committee_transaction'
+);
+const SEED_INVITATION = new TextEncoder().encode('invitation');
+const SEED_RECOVERY = new TextEncoder().encode('recovery');
+const SEED_VETO_COUNCIL = new TextEncoder().encode('veto_council');
+const SEED_MEMBER_RATE_LIMIT = new TextEncoder().encode('member_rate_limit');
+const SEED_BATCH = new TextEncoder().encode('batch');
+const SEED_BATCH_TRANSACTION = new TextEncoder().encode('batch_transaction');
+const SEED_TRANSACTION_BUFFER = new TextEncoder().encode('transaction_buffer');
+const SEED_EVENT_AUTHORITY = new TextEncoder().encode('__event_authority');
 
 export function getVaultConfigPda({
   programId = PROGRAM_ID,
@@ -116,3 +130,203 @@ export function getFounderTransactionPda({
     programId
   );
 }
+
+export function getFounderAuthorityPda({
+  vaultPda,
+  programId = PROGRAM_ID,
+}: {
+  vaultPda: PublicKey;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEED_PREFIX, vaultPda.toBytes(), SEED_FOUNDER_AUTHORITY],
+    programId
+  );
+}
+
+export function getEventAuthorityPda({
+  programId = PROGRAM_ID,
+}: {
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([SEED_EVENT_AUTHORITY], programId);
+}
+
+export function getVaultMetadataPda({
+  vaultPda,
+  programId = PROGRAM_ID,
+}: {
+  vaultPda: PublicKey;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEED_PREFIX, vaultPda.toBytes(), SEED_VAULT_METADATA],
+    programId
+  );
+}
+
+export function getCommitteePda({
+  vaultPda,
+  index,
+  programId = PROGRAM_ID,
+}: {
+  vaultPda: PublicKey;
+  /** Committee index. */
+  index: number;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEED_PREFIX, vaultPda.toBytes(), SEED_COMMITTEE, toU32Bytes(index)],
+    programId
+  );
+}
+
+export function getCommitteeFundPda({
+  committeePda,
+  programId = PROGRAM_ID,
+}: {
+  committeePda: PublicKey;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEED_PREFIX, committeePda.toBytes(), SEED_FUND],
+    programId
+  );
+}
+
+export function getCommitteeTransactionPda({
+  committeePda,
+  index,
+  programId = PROGRAM_ID,
+}: {
+  committeePda: PublicKey;
+  /** Transaction index. */
+  index: number;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      SEED_PREFIX,
+      committeePda.toBytes(),
+      SEED_COMMITTEE_TRANSACTION,
+      toU32Bytes(index),
+    ],
+    programId
+  );
+}
+
+export function getInvitationPda({
+  vaultPda,
+  invitee,
+  programId = PROGRAM_ID,
+}: {
+  vaultPda: PublicKey;
+  invitee: PublicKey;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEED_PREFIX, vaultPda.toBytes(), SEED_INVITATION, invitee.toBytes()],
+    programId
+  );
+}
+
+export function getRecoveryPda({
+  vaultPda,
+  programId = PROGRAM_ID,
+}: {
+  vaultPda: PublicKey;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEED_PREFIX, vaultPda.toBytes(), SEED_RECOVERY],
+    programId
+  );
+}
+
+export function getVetoCouncilPda({
+  vaultPda,
+  programId = PROGRAM_ID,
+}: {
+  vaultPda: PublicKey;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEED_PREFIX, vaultPda.toBytes(), SEED_VETO_COUNCIL],
+    programId
+  );
+}
+
+export function getMemberRateLimitPda({
+  vaultPda,
+  member,
+  programId = PROGRAM_ID,
+}: {
+  vaultPda: PublicKey;
+  member: PublicKey;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEED_PREFIX, vaultPda.toBytes(), SEED_MEMBER_RATE_LIMIT, member.toBytes()],
+    programId
+  );
+}
+
+export function getBatchPda({
+  vaultPda,
+  index,
+  programId = PROGRAM_ID,
+}: {
+  vaultPda: PublicKey;
+  /** Transaction index. */
+  index: number;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEED_PREFIX, vaultPda.toBytes(), SEED_BATCH, toU32Bytes(index)],
+    programId
+  );
+}
+
+export function getBatchTransactionPda({
+  batchPda,
+  index,
+  programId = PROGRAM_ID,
+}: {
+  batchPda: PublicKey;
+  /** Index of the transaction in the batch, starting at 1. */
+  index: number;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      SEED_PREFIX,
+      batchPda.toBytes(),
+      SEED_BATCH_TRANSACTION,
+      toU32Bytes(index),
+    ],
+    programId
+  );
+}
+
+export function getTransactionBufferPda({
+  vaultPda,
+  creator,
+  bufferIndex,
+  programId = PROGRAM_ID,
+}: {
+  vaultPda: PublicKey;
+  creator: PublicKey;
+  bufferIndex: number;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      SEED_PREFIX,
+      vaultPda.toBytes(),
+      SEED_TRANSACTION_BUFFER,
+      creator.toBytes(),
+      toU8Bytes(bufferIndex),
+    ],
+    programId
+  );
+}
@@ -5,13 +5,24 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 import { u8, u64, bignum, u32 } from '@metaplex-foundation/beet';
-import { AccountMeta, MessageV0, PublicKey } from '@solana/web3.js';
+import {
+  AccountMeta,
+  AddressLookupTableAccount,
+  MessageV0,
+  PublicKey,
+} from '@solana/web3.js';
 import {
   VaultTransactionMessage,
   vaultTransactionMessageBeet,
 } from './generated';
 import { getEphemeralSignerPda } from './pda';
 
+/**
+ * Prefix of the transaction messages carrying address table lookups.
+ * Messages without it are decoded by the program with the legacy layout, which has no lookups.
+ */
+export const VAULT_TRANSACTION_MESSAGE_V0_PREFIX = 0x80;
+
 export function toU8Bytes(num: number): Uint8Array {
   const bytes = Buffer.alloc(1);
   u8.write(bytes, 0, num);
@@ -42,6 +53,7 @@ export function serializeVaultTransactionMessage(message: MessageV0) {
       numReadonlyUnsignedAccounts,
     },
     compiledInstructions,
+    addressTableLookups,
   } = message;
 
   const staticAccountKeys = message.getAccountKeys().staticAccountKeys;
@@ -63,16 +75,30 @@ export function serializeVaultTransactionMessage(message: MessageV0) {
         };
       }
     ),
+    addressTableLookups: addressTableLookups.map(
+      ({ accountKey, writableIndexes, readonlyIndexes }) => {
+        return {
+          accountKey,
+          writableIndexes: Uint8Array.from(writableIndexes),
+          readonlyIndexes: Uint8Array.from(readonlyIndexes),
+        };
+      }
+    ),
   });
 
-  return transactionMessage;
+  return Buffer.concat([
+    Uint8Array.of(VAULT_TRANSACTION_MESSAGE_V0_PREFIX),
+    transactionMessage,
+  ]);
 }
 
 export function populateVaultTransactionExecuteRemainingAccounts(
   message: VaultTransactionMessage,
   ephemeralSignerBumps: number[],
   fundPda: PublicKey,
-  transactionPda: PublicKey
+  transactionPda: PublicKey,
+  founderAuthorityPda?: PublicKey,
+  addressLookupTableAccounts: AddressLookupTableAccount[] = []
 ): AccountMeta[] {
   // 1. Getting the signer public keys
   const ephemeralSignerPdas = ephemeralSignerBumps.map(
@@ -83,8 +109,30 @@ export function populateVaultTransactionExecuteRemainingAccounts(
       })[0]
   );
 
-  // 2. Generating the account keys required for the transaction that's executed in the smart contract
-  return message.accountKeys.map((accountKey, accountKeyIndex) => {
+  // Founder transactions also sign as the founder authority, which is a PDA too
+  if (founderAuthorityPda) {
+    ephemeralSignerPdas.push(founderAuthorityPda);
+  }
+
+  // 2. Resolving the address lookup tables, which come first in the remaining accounts
+  const lookupTableAccounts = message.addressTableLookups.map(
+    ({ accountKey }) => {
+      const lookupTableAccount = addressLookupTableAccounts.find(account =>
+        account.key.equals(accountKey)
+      );
+
+      if (!lookupTableAccount) {
+        throw new Error(
+          `Address lookup table ${accountKey.toBase58()} was not provided`
+        );
+      }
+
+      return lookupTableAccount;
+    }
+  );
+
+  // 3. Generating the account keys required for the transaction that's executed in the smart contract
+  const accountMetas = message.accountKeys.map((accountKey, accountKeyIndex) => {
     const isSignerIndex = accountKeyIndex < message.numSigners;
     const isAccountVault = accountKey.equals(fundPda);
     const isAccountEphemeralSigner = ephemeralSignerPdas.find(pda =>
@@ -97,6 +145,39 @@ export function populateVaultTransactionExecuteRemainingAccounts(
       isWritable: isWritableIndex(message, accountKeyIndex),
     };
   });
+
+  // 4. Appending the keys loaded from the lookup tables, the writable ones of every table first
+  const loadedWritableKeys = message.addressTableLookups.flatMap(
+    ({ writableIndexes }, lookupIndex) =>
+      Array.from(writableIndexes).map(
+        index => lookupTableAccounts[lookupIndex].state.addresses[index]
+      )
+  );
+  const loadedReadonlyKeys = message.addressTableLookups.flatMap(
+    ({ readonlyIndexes }, lookupIndex) =>
+      Array.from(readonlyIndexes).map(
+        index => lookupTableAccounts[lookupIndex].state.addresses[index]
+      )
+  );
+
+  return [
+    ...lookupTableAccounts.map(({ key }) => ({
+      pubkey: key,
+      isSigner: false,
+      isWritable: false,
+    })),
+    ...accountMetas,
+    ...loadedWritableKeys.map(pubkey => ({
+      pubkey,
+      isSigner: false,
+      isWritable: true,
+    })),
+    ...loadedReadonlyKeys.map(pubkey => ({
+      pubkey,
+      isSigner: false,
+      isWritable: false,
+    })),
+  ];
 }
 
 export function isWritableIndex(